@@ -6,8 +6,8 @@
  */
 
 import * as web3 from '@solana/web3.js'
-import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 
 /**
  * Arguments used to create {@link PeerReview}
@@ -18,11 +18,19 @@ export type PeerReviewArgs = {
   address: web3.PublicKey
   reviewerPubkey: web3.PublicKey
   paperPubkey: web3.PublicKey
-  qualityOfResearch: number
-  potentialForRealWorldUseCase: number
-  domainKnowledge: number
-  practicalityOfResultObtained: number
+  scoreCount: number
+  scores: number[] /* size: 8 */
   metaDataMerkleRoot: number[] /* size: 64 */
+  isApproval: boolean
+  rewardClaimed: beet.bignum
+  consensusWeight: number
+  isRevealed: boolean
+  commitment: number[] /* size: 32 */
+  isAmended: boolean
+  isInvalidated: boolean
+  paperVersion: number
+  isTallied: boolean
+  isWeighted: boolean
   bump: number
 }
 /**
@@ -37,11 +45,19 @@ export class PeerReview implements PeerReviewArgs {
     readonly address: web3.PublicKey,
     readonly reviewerPubkey: web3.PublicKey,
     readonly paperPubkey: web3.PublicKey,
-    readonly qualityOfResearch: number,
-    readonly potentialForRealWorldUseCase: number,
-    readonly domainKnowledge: number,
-    readonly practicalityOfResultObtained: number,
+    readonly scoreCount: number,
+    readonly scores: number[] /* size: 8 */,
     readonly metaDataMerkleRoot: number[] /* size: 64 */,
+    readonly isApproval: boolean,
+    readonly rewardClaimed: beet.bignum,
+    readonly consensusWeight: number,
+    readonly isRevealed: boolean,
+    readonly commitment: number[] /* size: 32 */,
+    readonly isAmended: boolean,
+    readonly isInvalidated: boolean,
+    readonly paperVersion: number,
+    readonly isTallied: boolean,
+    readonly isWeighted: boolean,
     readonly bump: number
   ) {}
 
@@ -53,11 +69,19 @@ export class PeerReview implements PeerReviewArgs {
       args.address,
       args.reviewerPubkey,
       args.paperPubkey,
-      args.qualityOfResearch,
-      args.potentialForRealWorldUseCase,
-      args.domainKnowledge,
-      args.practicalityOfResultObtained,
+      args.scoreCount,
+      args.scores,
       args.metaDataMerkleRoot,
+      args.isApproval,
+      args.rewardClaimed,
+      args.consensusWeight,
+      args.isRevealed,
+      args.commitment,
+      args.isAmended,
+      args.isInvalidated,
+      args.paperVersion,
+      args.isTallied,
+      args.isWeighted,
       args.bump
     )
   }
@@ -165,11 +189,29 @@ export class PeerReview implements PeerReviewArgs {
       address: this.address.toBase58(),
       reviewerPubkey: this.reviewerPubkey.toBase58(),
       paperPubkey: this.paperPubkey.toBase58(),
-      qualityOfResearch: this.qualityOfResearch,
-      potentialForRealWorldUseCase: this.potentialForRealWorldUseCase,
-      domainKnowledge: this.domainKnowledge,
-      practicalityOfResultObtained: this.practicalityOfResultObtained,
+      scoreCount: this.scoreCount,
+      scores: this.scores,
       metaDataMerkleRoot: this.metaDataMerkleRoot,
+      isApproval: this.isApproval,
+      rewardClaimed: (() => {
+        const x = <{ toNumber: () => number }>this.rewardClaimed
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      consensusWeight: this.consensusWeight,
+      isRevealed: this.isRevealed,
+      commitment: this.commitment,
+      isAmended: this.isAmended,
+      isInvalidated: this.isInvalidated,
+      paperVersion: this.paperVersion,
+      isTallied: this.isTallied,
+      isWeighted: this.isWeighted,
       bump: this.bump,
     }
   }
@@ -184,11 +226,19 @@ export const peerReviewBeet = new beet.BeetStruct<PeerReview, PeerReviewArgs>(
     ['address', beetSolana.publicKey],
     ['reviewerPubkey', beetSolana.publicKey],
     ['paperPubkey', beetSolana.publicKey],
-    ['qualityOfResearch', beet.u8],
-    ['potentialForRealWorldUseCase', beet.u8],
-    ['domainKnowledge', beet.u8],
-    ['practicalityOfResultObtained', beet.u8],
+    ['scoreCount', beet.u8],
+    ['scores', beet.uniformFixedSizeArray(beet.u8, 8)],
     ['metaDataMerkleRoot', beet.uniformFixedSizeArray(beet.u8, 64)],
+    ['isApproval', beet.bool],
+    ['rewardClaimed', beet.u64],
+    ['consensusWeight', beet.u8],
+    ['isRevealed', beet.bool],
+    ['commitment', beet.uniformFixedSizeArray(beet.u8, 32)],
+    ['isAmended', beet.bool],
+    ['isInvalidated', beet.bool],
+    ['paperVersion', beet.u8],
+    ['isTallied', beet.bool],
+    ['isWeighted', beet.bool],
     ['bump', beet.u8],
   ],
   PeerReview.fromArgs,
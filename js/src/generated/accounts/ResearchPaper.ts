@@ -9,6 +9,8 @@ import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { PaperState, paperStateBeet } from '../types/PaperState'
+import { FeeDecay, feeDecayBeet } from '../types/FeeDecay'
+import { PricingMode, pricingModeBeet } from '../types/PricingMode'
 
 /**
  * Arguments used to create {@link ResearchPaper}
@@ -23,9 +25,48 @@ export type ResearchPaperArgs = {
   version: number
   paperContentHash: number[] /* size: 64 */
   totalApprovals: number
+  totalReviews: number
   totalCitations: beet.bignum
   totalMints: beet.bignum
   metaDataMerkleRoot: number[] /* size: 64 */
+  reviewerShareBps: number
+  blindReview: boolean
+  submittedAt: beet.bignum
+  reviewWindowSecs: beet.bignum
+  venuePubkey: web3.PublicKey
+  rejectionReason: number
+  minApprovals: number
+  rubricPubkey: web3.PublicKey
+  rubricDimensionCount: number
+  rubricWeights: number[] /* size: 8 */
+  approvalThreshold: number
+  scoredReviews: number
+  scoreSums: number[] /* size: 8 */
+  scoreSquareSums: number[] /* size: 8 */
+  weightedScoreSum: number
+  weightedScoreSquareSum: number
+  excludedReviewers: web3.PublicKey[] /* size: 4 */
+  reasonHash: number[] /* size: 64 */
+  totalErrata: number
+  latestContentHash: number[] /* size: 64 */
+  totalDuplicateFlags: number
+  publishAt: beet.bignum
+  feeDecay: FeeDecay
+  feeDecayStepSecs: beet.bignum
+  feeDecayStepBps: number
+  openAccessAfterSecs: beet.bignum
+  pricingMode: PricingMode
+  priceSlope: number
+  tierMintThresholds: number[] /* size: 4 */
+  tierFees: number[] /* size: 4 */
+  maxMints: beet.bignum
+  reservedMints: beet.bignum
+  giftedMints: beet.bignum
+  rentalFee: number
+  rentalPeriodSecs: beet.bignum
+  refundWindowSecs: beet.bignum
+  pendingCommitments: number
+  awaitingVenue: boolean
   bump: number
 }
 /**
@@ -44,9 +85,48 @@ export class ResearchPaper implements ResearchPaperArgs {
     readonly version: number,
     readonly paperContentHash: number[] /* size: 64 */,
     readonly totalApprovals: number,
+    readonly totalReviews: number,
     readonly totalCitations: beet.bignum,
     readonly totalMints: beet.bignum,
     readonly metaDataMerkleRoot: number[] /* size: 64 */,
+    readonly reviewerShareBps: number,
+    readonly blindReview: boolean,
+    readonly submittedAt: beet.bignum,
+    readonly reviewWindowSecs: beet.bignum,
+    readonly venuePubkey: web3.PublicKey,
+    readonly rejectionReason: number,
+    readonly minApprovals: number,
+    readonly rubricPubkey: web3.PublicKey,
+    readonly rubricDimensionCount: number,
+    readonly rubricWeights: number[] /* size: 8 */,
+    readonly approvalThreshold: number,
+    readonly scoredReviews: number,
+    readonly scoreSums: number[] /* size: 8 */,
+    readonly scoreSquareSums: number[] /* size: 8 */,
+    readonly weightedScoreSum: number,
+    readonly weightedScoreSquareSum: number,
+    readonly excludedReviewers: web3.PublicKey[] /* size: 4 */,
+    readonly reasonHash: number[] /* size: 64 */,
+    readonly totalErrata: number,
+    readonly latestContentHash: number[] /* size: 64 */,
+    readonly totalDuplicateFlags: number,
+    readonly publishAt: beet.bignum,
+    readonly feeDecay: FeeDecay,
+    readonly feeDecayStepSecs: beet.bignum,
+    readonly feeDecayStepBps: number,
+    readonly openAccessAfterSecs: beet.bignum,
+    readonly pricingMode: PricingMode,
+    readonly priceSlope: number,
+    readonly tierMintThresholds: number[] /* size: 4 */,
+    readonly tierFees: number[] /* size: 4 */,
+    readonly maxMints: beet.bignum,
+    readonly reservedMints: beet.bignum,
+    readonly giftedMints: beet.bignum,
+    readonly rentalFee: number,
+    readonly rentalPeriodSecs: beet.bignum,
+    readonly refundWindowSecs: beet.bignum,
+    readonly pendingCommitments: number,
+    readonly awaitingVenue: boolean,
     readonly bump: number
   ) {}
 
@@ -62,9 +142,48 @@ export class ResearchPaper implements ResearchPaperArgs {
       args.version,
       args.paperContentHash,
       args.totalApprovals,
+      args.totalReviews,
       args.totalCitations,
       args.totalMints,
       args.metaDataMerkleRoot,
+      args.reviewerShareBps,
+      args.blindReview,
+      args.submittedAt,
+      args.reviewWindowSecs,
+      args.venuePubkey,
+      args.rejectionReason,
+      args.minApprovals,
+      args.rubricPubkey,
+      args.rubricDimensionCount,
+      args.rubricWeights,
+      args.approvalThreshold,
+      args.scoredReviews,
+      args.scoreSums,
+      args.scoreSquareSums,
+      args.weightedScoreSum,
+      args.weightedScoreSquareSum,
+      args.excludedReviewers,
+      args.reasonHash,
+      args.totalErrata,
+      args.latestContentHash,
+      args.totalDuplicateFlags,
+      args.publishAt,
+      args.feeDecay,
+      args.feeDecayStepSecs,
+      args.feeDecayStepBps,
+      args.openAccessAfterSecs,
+      args.pricingMode,
+      args.priceSlope,
+      args.tierMintThresholds,
+      args.tierFees,
+      args.maxMints,
+      args.reservedMints,
+      args.giftedMints,
+      args.rentalFee,
+      args.rentalPeriodSecs,
+      args.refundWindowSecs,
+      args.pendingCommitments,
+      args.awaitingVenue,
       args.bump
     )
   }
@@ -176,6 +295,7 @@ export class ResearchPaper implements ResearchPaperArgs {
       version: this.version,
       paperContentHash: this.paperContentHash,
       totalApprovals: this.totalApprovals,
+      totalReviews: this.totalReviews,
       totalCitations: (() => {
         const x = <{ toNumber: () => number }>this.totalCitations
         if (typeof x.toNumber === 'function') {
@@ -199,6 +319,144 @@ export class ResearchPaper implements ResearchPaperArgs {
         return x
       })(),
       metaDataMerkleRoot: this.metaDataMerkleRoot,
+      reviewerShareBps: this.reviewerShareBps,
+      blindReview: this.blindReview,
+      submittedAt: (() => {
+        const x = <{ toNumber: () => number }>this.submittedAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      reviewWindowSecs: (() => {
+        const x = <{ toNumber: () => number }>this.reviewWindowSecs
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      venuePubkey: this.venuePubkey.toBase58(),
+      rejectionReason: this.rejectionReason,
+      minApprovals: this.minApprovals,
+      rubricPubkey: this.rubricPubkey.toBase58(),
+      rubricDimensionCount: this.rubricDimensionCount,
+      rubricWeights: this.rubricWeights,
+      approvalThreshold: this.approvalThreshold,
+      scoredReviews: this.scoredReviews,
+      scoreSums: this.scoreSums,
+      scoreSquareSums: this.scoreSquareSums,
+      weightedScoreSum: this.weightedScoreSum,
+      weightedScoreSquareSum: this.weightedScoreSquareSum,
+      excludedReviewers: this.excludedReviewers,
+      reasonHash: this.reasonHash,
+      totalErrata: this.totalErrata,
+      latestContentHash: this.latestContentHash,
+      totalDuplicateFlags: this.totalDuplicateFlags,
+      publishAt: (() => {
+        const x = <{ toNumber: () => number }>this.publishAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      feeDecay: 'FeeDecay.' + FeeDecay[this.feeDecay],
+      feeDecayStepSecs: (() => {
+        const x = <{ toNumber: () => number }>this.feeDecayStepSecs
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      feeDecayStepBps: this.feeDecayStepBps,
+      openAccessAfterSecs: (() => {
+        const x = <{ toNumber: () => number }>this.openAccessAfterSecs
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      pricingMode: 'PricingMode.' + PricingMode[this.pricingMode],
+      priceSlope: this.priceSlope,
+      tierMintThresholds: this.tierMintThresholds,
+      tierFees: this.tierFees,
+      maxMints: (() => {
+        const x = <{ toNumber: () => number }>this.maxMints
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      reservedMints: (() => {
+        const x = <{ toNumber: () => number }>this.reservedMints
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      giftedMints: (() => {
+        const x = <{ toNumber: () => number }>this.giftedMints
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      rentalFee: this.rentalFee,
+      rentalPeriodSecs: (() => {
+        const x = <{ toNumber: () => number }>this.rentalPeriodSecs
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      refundWindowSecs: (() => {
+        const x = <{ toNumber: () => number }>this.refundWindowSecs
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      pendingCommitments: this.pendingCommitments,
+      awaitingVenue: this.awaitingVenue,
       bump: this.bump,
     }
   }
@@ -220,9 +478,48 @@ export const researchPaperBeet = new beet.BeetStruct<
     ['version', beet.u8],
     ['paperContentHash', beet.uniformFixedSizeArray(beet.u8, 64)],
     ['totalApprovals', beet.u8],
+    ['totalReviews', beet.u8],
     ['totalCitations', beet.u64],
     ['totalMints', beet.u64],
     ['metaDataMerkleRoot', beet.uniformFixedSizeArray(beet.u8, 64)],
+    ['reviewerShareBps', beet.u16],
+    ['blindReview', beet.bool],
+    ['submittedAt', beet.i64],
+    ['reviewWindowSecs', beet.i64],
+    ['venuePubkey', beetSolana.publicKey],
+    ['rejectionReason', beet.u8],
+    ['minApprovals', beet.u8],
+    ['rubricPubkey', beetSolana.publicKey],
+    ['rubricDimensionCount', beet.u8],
+    ['rubricWeights', beet.uniformFixedSizeArray(beet.u16, 8)],
+    ['approvalThreshold', beet.u8],
+    ['scoredReviews', beet.u8],
+    ['scoreSums', beet.uniformFixedSizeArray(beet.u32, 8)],
+    ['scoreSquareSums', beet.uniformFixedSizeArray(beet.u32, 8)],
+    ['weightedScoreSum', beet.u32],
+    ['weightedScoreSquareSum', beet.u32],
+    ['excludedReviewers', beet.uniformFixedSizeArray(beetSolana.publicKey, 4)],
+    ['reasonHash', beet.uniformFixedSizeArray(beet.u8, 64)],
+    ['totalErrata', beet.u32],
+    ['latestContentHash', beet.uniformFixedSizeArray(beet.u8, 64)],
+    ['totalDuplicateFlags', beet.u32],
+    ['publishAt', beet.i64],
+    ['feeDecay', feeDecayBeet],
+    ['feeDecayStepSecs', beet.i64],
+    ['feeDecayStepBps', beet.u16],
+    ['openAccessAfterSecs', beet.i64],
+    ['pricingMode', pricingModeBeet],
+    ['priceSlope', beet.u32],
+    ['tierMintThresholds', beet.uniformFixedSizeArray(beet.u32, 4)],
+    ['tierFees', beet.uniformFixedSizeArray(beet.u32, 4)],
+    ['maxMints', beet.u64],
+    ['reservedMints', beet.u64],
+    ['giftedMints', beet.u64],
+    ['rentalFee', beet.u32],
+    ['rentalPeriodSecs', beet.i64],
+    ['refundWindowSecs', beet.i64],
+    ['pendingCommitments', beet.u8],
+    ['awaitingVenue', beet.bool],
     ['bump', beet.u8],
   ],
   ResearchPaper.fromArgs,
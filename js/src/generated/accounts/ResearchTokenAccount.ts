@@ -6,8 +6,9 @@
  */
 
 import * as web3 from '@solana/web3.js'
-import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import { AccessKind, accessKindBeet } from '../types/AccessKind'
 
 /**
  * Arguments used to create {@link ResearchTokenAccount}
@@ -18,6 +19,8 @@ export type ResearchTokenAccountArgs = {
   address: web3.PublicKey
   paperPubkey: web3.PublicKey
   researcherPubkey: web3.PublicKey
+  kind: AccessKind
+  expiresAt: beet.bignum
   bump: number
 }
 /**
@@ -32,6 +35,8 @@ export class ResearchTokenAccount implements ResearchTokenAccountArgs {
     readonly address: web3.PublicKey,
     readonly paperPubkey: web3.PublicKey,
     readonly researcherPubkey: web3.PublicKey,
+    readonly kind: AccessKind,
+    readonly expiresAt: beet.bignum,
     readonly bump: number
   ) {}
 
@@ -43,6 +48,8 @@ export class ResearchTokenAccount implements ResearchTokenAccountArgs {
       args.address,
       args.paperPubkey,
       args.researcherPubkey,
+      args.kind,
+      args.expiresAt,
       args.bump
     )
   }
@@ -152,6 +159,18 @@ export class ResearchTokenAccount implements ResearchTokenAccountArgs {
       address: this.address.toBase58(),
       paperPubkey: this.paperPubkey.toBase58(),
       researcherPubkey: this.researcherPubkey.toBase58(),
+      kind: 'AccessKind.' + AccessKind[this.kind],
+      expiresAt: (() => {
+        const x = <{ toNumber: () => number }>this.expiresAt
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
       bump: this.bump,
     }
   }
@@ -169,6 +188,8 @@ export const researchTokenAccountBeet = new beet.BeetStruct<
     ['address', beetSolana.publicKey],
     ['paperPubkey', beetSolana.publicKey],
     ['researcherPubkey', beetSolana.publicKey],
+    ['kind', accessKindBeet],
+    ['expiresAt', beet.i64],
     ['bump', beet.u8],
   ],
   ResearchTokenAccount.fromArgs,
@@ -1,16 +1,76 @@
+export * from './ArbiterSet'
+export * from './AuthorResponse'
+export * from './ConflictOfInterest'
+export * from './ContentHashRecord'
+export * from './Dispute'
+export * from './DuplicateFlag'
+export * from './Editor'
+export * from './Erratum'
+export * from './Institution'
+export * from './InstitutionLicense'
+export * from './InstitutionMember'
+export * from './Journal'
+export * from './MintEscrow'
+export * from './PaperVersion'
 export * from './PeerReview'
 export * from './ResearchPaper'
 export * from './ResearchTokenAccount'
 export * from './ResearcherProfile'
+export * from './ReviewInvitation'
+export * from './ReviewStake'
+export * from './ReviewerPool'
+export * from './Rubric'
+export * from './Subscription'
+export * from './SubscriptionPlan'
 
 import { ResearcherProfile } from './ResearcherProfile'
 import { ResearchPaper } from './ResearchPaper'
 import { PeerReview } from './PeerReview'
 import { ResearchTokenAccount } from './ResearchTokenAccount'
+import { ReviewStake } from './ReviewStake'
+import { ReviewerPool } from './ReviewerPool'
+import { Editor } from './Editor'
+import { ReviewInvitation } from './ReviewInvitation'
+import { Journal } from './Journal'
+import { Rubric } from './Rubric'
+import { AuthorResponse } from './AuthorResponse'
+import { ArbiterSet } from './ArbiterSet'
+import { Dispute } from './Dispute'
+import { ConflictOfInterest } from './ConflictOfInterest'
+import { Erratum } from './Erratum'
+import { PaperVersion } from './PaperVersion'
+import { ContentHashRecord } from './ContentHashRecord'
+import { DuplicateFlag } from './DuplicateFlag'
+import { Institution } from './Institution'
+import { InstitutionMember } from './InstitutionMember'
+import { InstitutionLicense } from './InstitutionLicense'
+import { SubscriptionPlan } from './SubscriptionPlan'
+import { Subscription } from './Subscription'
+import { MintEscrow } from './MintEscrow'
 
 export const accountProviders = {
   ResearcherProfile,
   ResearchPaper,
   PeerReview,
   ResearchTokenAccount,
+  ReviewStake,
+  ReviewerPool,
+  Editor,
+  ReviewInvitation,
+  Journal,
+  Rubric,
+  AuthorResponse,
+  ArbiterSet,
+  Dispute,
+  ConflictOfInterest,
+  Erratum,
+  PaperVersion,
+  ContentHashRecord,
+  DuplicateFlag,
+  Institution,
+  InstitutionMember,
+  InstitutionLicense,
+  SubscriptionPlan,
+  Subscription,
+  MintEscrow,
 }
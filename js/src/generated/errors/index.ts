@@ -442,6 +442,2308 @@ createErrorFromNameLookup.set(
   () => new ResearchTokenAccountAlreadyExistsError()
 )
 
+/**
+ * InvalidGovernanceAuthority: 'Invalid governance authority'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidGovernanceAuthorityError extends Error {
+  readonly code: number = 0x13
+  readonly name: string = 'InvalidGovernanceAuthority'
+  constructor() {
+    super('Invalid governance authority')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidGovernanceAuthorityError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x13, () => new InvalidGovernanceAuthorityError())
+createErrorFromNameLookup.set(
+  'InvalidGovernanceAuthority',
+  () => new InvalidGovernanceAuthorityError()
+)
+
+/**
+ * InvalidTreasury: 'Invalid treasury account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTreasuryError extends Error {
+  readonly code: number = 0x14
+  readonly name: string = 'InvalidTreasury'
+  constructor() {
+    super('Invalid treasury account')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTreasuryError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x14, () => new InvalidTreasuryError())
+createErrorFromNameLookup.set(
+  'InvalidTreasury',
+  () => new InvalidTreasuryError()
+)
+
+/**
+ * PaperNotFinalized: 'Paper has not reached a final state'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PaperNotFinalizedError extends Error {
+  readonly code: number = 0x15
+  readonly name: string = 'PaperNotFinalized'
+  constructor() {
+    super('Paper has not reached a final state')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PaperNotFinalizedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x15, () => new PaperNotFinalizedError())
+createErrorFromNameLookup.set(
+  'PaperNotFinalized',
+  () => new PaperNotFinalizedError()
+)
+
+/**
+ * ReviewStakeNotFound: 'Review stake not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewStakeNotFoundError extends Error {
+  readonly code: number = 0x16
+  readonly name: string = 'ReviewStakeNotFound'
+  constructor() {
+    super('Review stake not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewStakeNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x16, () => new ReviewStakeNotFoundError())
+createErrorFromNameLookup.set(
+  'ReviewStakeNotFound',
+  () => new ReviewStakeNotFoundError()
+)
+
+/**
+ * ReviewStakeAlreadySlashed: 'Review stake already slashed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewStakeAlreadySlashedError extends Error {
+  readonly code: number = 0x17
+  readonly name: string = 'ReviewStakeAlreadySlashed'
+  constructor() {
+    super('Review stake already slashed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewStakeAlreadySlashedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17, () => new ReviewStakeAlreadySlashedError())
+createErrorFromNameLookup.set(
+  'ReviewStakeAlreadySlashed',
+  () => new ReviewStakeAlreadySlashedError()
+)
+
+/**
+ * InvalidSlashAmount: 'Invalid slash amount'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSlashAmountError extends Error {
+  readonly code: number = 0x18
+  readonly name: string = 'InvalidSlashAmount'
+  constructor() {
+    super('Invalid slash amount')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSlashAmountError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x18, () => new InvalidSlashAmountError())
+createErrorFromNameLookup.set(
+  'InvalidSlashAmount',
+  () => new InvalidSlashAmountError()
+)
+
+/**
+ * ReviewerPoolAlreadyExists: 'Reviewer pool already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewerPoolAlreadyExistsError extends Error {
+  readonly code: number = 0x19
+  readonly name: string = 'ReviewerPoolAlreadyExists'
+  constructor() {
+    super('Reviewer pool already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewerPoolAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x19, () => new ReviewerPoolAlreadyExistsError())
+createErrorFromNameLookup.set(
+  'ReviewerPoolAlreadyExists',
+  () => new ReviewerPoolAlreadyExistsError()
+)
+
+/**
+ * ReviewerPoolNotFound: 'Reviewer pool not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewerPoolNotFoundError extends Error {
+  readonly code: number = 0x1a
+  readonly name: string = 'ReviewerPoolNotFound'
+  constructor() {
+    super('Reviewer pool not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewerPoolNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1a, () => new ReviewerPoolNotFoundError())
+createErrorFromNameLookup.set(
+  'ReviewerPoolNotFound',
+  () => new ReviewerPoolNotFoundError()
+)
+
+/**
+ * InvalidReviewerShare: 'Invalid reviewer share'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidReviewerShareError extends Error {
+  readonly code: number = 0x1b
+  readonly name: string = 'InvalidReviewerShare'
+  constructor() {
+    super('Invalid reviewer share')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidReviewerShareError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1b, () => new InvalidReviewerShareError())
+createErrorFromNameLookup.set(
+  'InvalidReviewerShare',
+  () => new InvalidReviewerShareError()
+)
+
+/**
+ * PeerReviewNotFound: 'Peer Review not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PeerReviewNotFoundError extends Error {
+  readonly code: number = 0x1c
+  readonly name: string = 'PeerReviewNotFound'
+  constructor() {
+    super('Peer Review not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PeerReviewNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1c, () => new PeerReviewNotFoundError())
+createErrorFromNameLookup.set(
+  'PeerReviewNotFound',
+  () => new PeerReviewNotFoundError()
+)
+
+/**
+ * PeerReviewNotApproved: 'Peer Review did not approve the paper'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PeerReviewNotApprovedError extends Error {
+  readonly code: number = 0x1d
+  readonly name: string = 'PeerReviewNotApproved'
+  constructor() {
+    super('Peer Review did not approve the paper')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PeerReviewNotApprovedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1d, () => new PeerReviewNotApprovedError())
+createErrorFromNameLookup.set(
+  'PeerReviewNotApproved',
+  () => new PeerReviewNotApprovedError()
+)
+
+/**
+ * NoRewardToClaim: 'No reward to claim'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoRewardToClaimError extends Error {
+  readonly code: number = 0x1e
+  readonly name: string = 'NoRewardToClaim'
+  constructor() {
+    super('No reward to claim')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoRewardToClaimError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1e, () => new NoRewardToClaimError())
+createErrorFromNameLookup.set(
+  'NoRewardToClaim',
+  () => new NoRewardToClaimError()
+)
+
+/**
+ * IncompleteReviewSet: 'Every review of the paper must be provided'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncompleteReviewSetError extends Error {
+  readonly code: number = 0x1f
+  readonly name: string = 'IncompleteReviewSet'
+  constructor() {
+    super('Every review of the paper must be provided')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncompleteReviewSetError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1f, () => new IncompleteReviewSetError())
+createErrorFromNameLookup.set(
+  'IncompleteReviewSet',
+  () => new IncompleteReviewSetError()
+)
+
+/**
+ * ReviewsAlreadyFinalized: 'Reviews already finalized'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewsAlreadyFinalizedError extends Error {
+  readonly code: number = 0x20
+  readonly name: string = 'ReviewsAlreadyFinalized'
+  constructor() {
+    super('Reviews already finalized')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewsAlreadyFinalizedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x20, () => new ReviewsAlreadyFinalizedError())
+createErrorFromNameLookup.set(
+  'ReviewsAlreadyFinalized',
+  () => new ReviewsAlreadyFinalizedError()
+)
+
+/**
+ * BlindReviewRequired: 'Paper requires blind review, use CommitReview'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BlindReviewRequiredError extends Error {
+  readonly code: number = 0x21
+  readonly name: string = 'BlindReviewRequired'
+  constructor() {
+    super('Paper requires blind review, use CommitReview')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BlindReviewRequiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x21, () => new BlindReviewRequiredError())
+createErrorFromNameLookup.set(
+  'BlindReviewRequired',
+  () => new BlindReviewRequiredError()
+)
+
+/**
+ * NotABlindReview: 'Paper does not use blind review'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotABlindReviewError extends Error {
+  readonly code: number = 0x22
+  readonly name: string = 'NotABlindReview'
+  constructor() {
+    super('Paper does not use blind review')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotABlindReviewError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x22, () => new NotABlindReviewError())
+createErrorFromNameLookup.set(
+  'NotABlindReview',
+  () => new NotABlindReviewError()
+)
+
+/**
+ * ReviewWindowClosed: 'Review window is closed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewWindowClosedError extends Error {
+  readonly code: number = 0x23
+  readonly name: string = 'ReviewWindowClosed'
+  constructor() {
+    super('Review window is closed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewWindowClosedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x23, () => new ReviewWindowClosedError())
+createErrorFromNameLookup.set(
+  'ReviewWindowClosed',
+  () => new ReviewWindowClosedError()
+)
+
+/**
+ * CommitWindowOpen: 'Review commit window is still open'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CommitWindowOpenError extends Error {
+  readonly code: number = 0x24
+  readonly name: string = 'CommitWindowOpen'
+  constructor() {
+    super('Review commit window is still open')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CommitWindowOpenError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x24, () => new CommitWindowOpenError())
+createErrorFromNameLookup.set(
+  'CommitWindowOpen',
+  () => new CommitWindowOpenError()
+)
+
+/**
+ * RevealWindowClosed: 'Review reveal window is closed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RevealWindowClosedError extends Error {
+  readonly code: number = 0x25
+  readonly name: string = 'RevealWindowClosed'
+  constructor() {
+    super('Review reveal window is closed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RevealWindowClosedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x25, () => new RevealWindowClosedError())
+createErrorFromNameLookup.set(
+  'RevealWindowClosed',
+  () => new RevealWindowClosedError()
+)
+
+/**
+ * RevealWindowOpen: 'Review reveal window is still open'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RevealWindowOpenError extends Error {
+  readonly code: number = 0x26
+  readonly name: string = 'RevealWindowOpen'
+  constructor() {
+    super('Review reveal window is still open')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RevealWindowOpenError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x26, () => new RevealWindowOpenError())
+createErrorFromNameLookup.set(
+  'RevealWindowOpen',
+  () => new RevealWindowOpenError()
+)
+
+/**
+ * CommitmentMismatch: 'Revealed scores do not match the commitment'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CommitmentMismatchError extends Error {
+  readonly code: number = 0x27
+  readonly name: string = 'CommitmentMismatch'
+  constructor() {
+    super('Revealed scores do not match the commitment')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CommitmentMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x27, () => new CommitmentMismatchError())
+createErrorFromNameLookup.set(
+  'CommitmentMismatch',
+  () => new CommitmentMismatchError()
+)
+
+/**
+ * PeerReviewAlreadyRevealed: 'Peer Review already revealed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PeerReviewAlreadyRevealedError extends Error {
+  readonly code: number = 0x28
+  readonly name: string = 'PeerReviewAlreadyRevealed'
+  constructor() {
+    super('Peer Review already revealed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PeerReviewAlreadyRevealedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x28, () => new PeerReviewAlreadyRevealedError())
+createErrorFromNameLookup.set(
+  'PeerReviewAlreadyRevealed',
+  () => new PeerReviewAlreadyRevealedError()
+)
+
+/**
+ * InvalidReviewWindow: 'Invalid review window'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidReviewWindowError extends Error {
+  readonly code: number = 0x29
+  readonly name: string = 'InvalidReviewWindow'
+  constructor() {
+    super('Invalid review window')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidReviewWindowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x29, () => new InvalidReviewWindowError())
+createErrorFromNameLookup.set(
+  'InvalidReviewWindow',
+  () => new InvalidReviewWindowError()
+)
+
+/**
+ * ReviewWindowOpen: 'Review window is still open'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewWindowOpenError extends Error {
+  readonly code: number = 0x2a
+  readonly name: string = 'ReviewWindowOpen'
+  constructor() {
+    super('Review window is still open')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewWindowOpenError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2a, () => new ReviewWindowOpenError())
+createErrorFromNameLookup.set(
+  'ReviewWindowOpen',
+  () => new ReviewWindowOpenError()
+)
+
+/**
+ * EditorAlreadyExists: 'Editor already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EditorAlreadyExistsError extends Error {
+  readonly code: number = 0x2b
+  readonly name: string = 'EditorAlreadyExists'
+  constructor() {
+    super('Editor already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EditorAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2b, () => new EditorAlreadyExistsError())
+createErrorFromNameLookup.set(
+  'EditorAlreadyExists',
+  () => new EditorAlreadyExistsError()
+)
+
+/**
+ * EditorNotFound: 'Editor not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EditorNotFoundError extends Error {
+  readonly code: number = 0x2c
+  readonly name: string = 'EditorNotFound'
+  constructor() {
+    super('Editor not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EditorNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2c, () => new EditorNotFoundError())
+createErrorFromNameLookup.set('EditorNotFound', () => new EditorNotFoundError())
+
+/**
+ * PaperHasNoVenue: 'Paper was not submitted to a venue'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PaperHasNoVenueError extends Error {
+  readonly code: number = 0x2d
+  readonly name: string = 'PaperHasNoVenue'
+  constructor() {
+    super('Paper was not submitted to a venue')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PaperHasNoVenueError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2d, () => new PaperHasNoVenueError())
+createErrorFromNameLookup.set(
+  'PaperHasNoVenue',
+  () => new PaperHasNoVenueError()
+)
+
+/**
+ * ReviewInvitationAlreadyExists: 'Review invitation already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewInvitationAlreadyExistsError extends Error {
+  readonly code: number = 0x2e
+  readonly name: string = 'ReviewInvitationAlreadyExists'
+  constructor() {
+    super('Review invitation already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewInvitationAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x2e,
+  () => new ReviewInvitationAlreadyExistsError()
+)
+createErrorFromNameLookup.set(
+  'ReviewInvitationAlreadyExists',
+  () => new ReviewInvitationAlreadyExistsError()
+)
+
+/**
+ * ReviewInvitationRequired: 'Reviewer was not invited to review this paper'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewInvitationRequiredError extends Error {
+  readonly code: number = 0x2f
+  readonly name: string = 'ReviewInvitationRequired'
+  constructor() {
+    super('Reviewer was not invited to review this paper')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewInvitationRequiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2f, () => new ReviewInvitationRequiredError())
+createErrorFromNameLookup.set(
+  'ReviewInvitationRequired',
+  () => new ReviewInvitationRequiredError()
+)
+
+/**
+ * JournalAlreadyExists: 'Journal already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class JournalAlreadyExistsError extends Error {
+  readonly code: number = 0x30
+  readonly name: string = 'JournalAlreadyExists'
+  constructor() {
+    super('Journal already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, JournalAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x30, () => new JournalAlreadyExistsError())
+createErrorFromNameLookup.set(
+  'JournalAlreadyExists',
+  () => new JournalAlreadyExistsError()
+)
+
+/**
+ * JournalNotFound: 'Journal not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class JournalNotFoundError extends Error {
+  readonly code: number = 0x31
+  readonly name: string = 'JournalNotFound'
+  constructor() {
+    super('Journal not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, JournalNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x31, () => new JournalNotFoundError())
+createErrorFromNameLookup.set(
+  'JournalNotFound',
+  () => new JournalNotFoundError()
+)
+
+/**
+ * InvalidJournalAuthority: 'Invalid journal authority'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidJournalAuthorityError extends Error {
+  readonly code: number = 0x32
+  readonly name: string = 'InvalidJournalAuthority'
+  constructor() {
+    super('Invalid journal authority')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidJournalAuthorityError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x32, () => new InvalidJournalAuthorityError())
+createErrorFromNameLookup.set(
+  'InvalidJournalAuthority',
+  () => new InvalidJournalAuthorityError()
+)
+
+/**
+ * InvalidMinApprovals: 'Invalid minimum approvals'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMinApprovalsError extends Error {
+  readonly code: number = 0x33
+  readonly name: string = 'InvalidMinApprovals'
+  constructor() {
+    super('Invalid minimum approvals')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMinApprovalsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x33, () => new InvalidMinApprovalsError())
+createErrorFromNameLookup.set(
+  'InvalidMinApprovals',
+  () => new InvalidMinApprovalsError()
+)
+
+/**
+ * PaperAlreadySubmitted: 'Paper was already submitted to a venue'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PaperAlreadySubmittedError extends Error {
+  readonly code: number = 0x34
+  readonly name: string = 'PaperAlreadySubmitted'
+  constructor() {
+    super('Paper was already submitted to a venue')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PaperAlreadySubmittedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x34, () => new PaperAlreadySubmittedError())
+createErrorFromNameLookup.set(
+  'PaperAlreadySubmitted',
+  () => new PaperAlreadySubmittedError()
+)
+
+/**
+ * RubricAlreadyExists: 'Rubric already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RubricAlreadyExistsError extends Error {
+  readonly code: number = 0x35
+  readonly name: string = 'RubricAlreadyExists'
+  constructor() {
+    super('Rubric already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RubricAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x35, () => new RubricAlreadyExistsError())
+createErrorFromNameLookup.set(
+  'RubricAlreadyExists',
+  () => new RubricAlreadyExistsError()
+)
+
+/**
+ * RubricNotFound: 'Rubric not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RubricNotFoundError extends Error {
+  readonly code: number = 0x36
+  readonly name: string = 'RubricNotFound'
+  constructor() {
+    super('Rubric not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RubricNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x36, () => new RubricNotFoundError())
+createErrorFromNameLookup.set('RubricNotFound', () => new RubricNotFoundError())
+
+/**
+ * InvalidRubric: 'Invalid rubric'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRubricError extends Error {
+  readonly code: number = 0x37
+  readonly name: string = 'InvalidRubric'
+  constructor() {
+    super('Invalid rubric')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRubricError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x37, () => new InvalidRubricError())
+createErrorFromNameLookup.set('InvalidRubric', () => new InvalidRubricError())
+
+/**
+ * InvalidRubricScores: 'Scores do not match the paper\'s rubric'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRubricScoresError extends Error {
+  readonly code: number = 0x38
+  readonly name: string = 'InvalidRubricScores'
+  constructor() {
+    super('Scores do not match the paper\'s rubric')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRubricScoresError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x38, () => new InvalidRubricScoresError())
+createErrorFromNameLookup.set(
+  'InvalidRubricScores',
+  () => new InvalidRubricScoresError()
+)
+
+/**
+ * AuthorResponseAlreadyExists: 'Author response already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuthorResponseAlreadyExistsError extends Error {
+  readonly code: number = 0x39
+  readonly name: string = 'AuthorResponseAlreadyExists'
+  constructor() {
+    super('Author response already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuthorResponseAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x39,
+  () => new AuthorResponseAlreadyExistsError()
+)
+createErrorFromNameLookup.set(
+  'AuthorResponseAlreadyExists',
+  () => new AuthorResponseAlreadyExistsError()
+)
+
+/**
+ * AuthorResponseRequired: 'Peer review has no author response'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuthorResponseRequiredError extends Error {
+  readonly code: number = 0x3a
+  readonly name: string = 'AuthorResponseRequired'
+  constructor() {
+    super('Peer review has no author response')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuthorResponseRequiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3a, () => new AuthorResponseRequiredError())
+createErrorFromNameLookup.set(
+  'AuthorResponseRequired',
+  () => new AuthorResponseRequiredError()
+)
+
+/**
+ * PeerReviewAlreadyAmended: 'Peer review was already amended'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PeerReviewAlreadyAmendedError extends Error {
+  readonly code: number = 0x3b
+  readonly name: string = 'PeerReviewAlreadyAmended'
+  constructor() {
+    super('Peer review was already amended')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PeerReviewAlreadyAmendedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3b, () => new PeerReviewAlreadyAmendedError())
+createErrorFromNameLookup.set(
+  'PeerReviewAlreadyAmended',
+  () => new PeerReviewAlreadyAmendedError()
+)
+
+/**
+ * InvalidArbiterSet: 'Invalid arbiter set'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidArbiterSetError extends Error {
+  readonly code: number = 0x3c
+  readonly name: string = 'InvalidArbiterSet'
+  constructor() {
+    super('Invalid arbiter set')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidArbiterSetError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3c, () => new InvalidArbiterSetError())
+createErrorFromNameLookup.set(
+  'InvalidArbiterSet',
+  () => new InvalidArbiterSetError()
+)
+
+/**
+ * ArbiterSetNotFound: 'Arbiter set not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ArbiterSetNotFoundError extends Error {
+  readonly code: number = 0x3d
+  readonly name: string = 'ArbiterSetNotFound'
+  constructor() {
+    super('Arbiter set not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ArbiterSetNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3d, () => new ArbiterSetNotFoundError())
+createErrorFromNameLookup.set(
+  'ArbiterSetNotFound',
+  () => new ArbiterSetNotFoundError()
+)
+
+/**
+ * InvalidArbiter: 'Signer is not an arbiter'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidArbiterError extends Error {
+  readonly code: number = 0x3e
+  readonly name: string = 'InvalidArbiter'
+  constructor() {
+    super('Signer is not an arbiter')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidArbiterError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3e, () => new InvalidArbiterError())
+createErrorFromNameLookup.set('InvalidArbiter', () => new InvalidArbiterError())
+
+/**
+ * ArbiterAlreadyVoted: 'Arbiter already voted on this dispute'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ArbiterAlreadyVotedError extends Error {
+  readonly code: number = 0x3f
+  readonly name: string = 'ArbiterAlreadyVoted'
+  constructor() {
+    super('Arbiter already voted on this dispute')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ArbiterAlreadyVotedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3f, () => new ArbiterAlreadyVotedError())
+createErrorFromNameLookup.set(
+  'ArbiterAlreadyVoted',
+  () => new ArbiterAlreadyVotedError()
+)
+
+/**
+ * DisputeAlreadyExists: 'Dispute already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DisputeAlreadyExistsError extends Error {
+  readonly code: number = 0x40
+  readonly name: string = 'DisputeAlreadyExists'
+  constructor() {
+    super('Dispute already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DisputeAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x40, () => new DisputeAlreadyExistsError())
+createErrorFromNameLookup.set(
+  'DisputeAlreadyExists',
+  () => new DisputeAlreadyExistsError()
+)
+
+/**
+ * DisputeNotFound: 'Dispute not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DisputeNotFoundError extends Error {
+  readonly code: number = 0x41
+  readonly name: string = 'DisputeNotFound'
+  constructor() {
+    super('Dispute not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DisputeNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x41, () => new DisputeNotFoundError())
+createErrorFromNameLookup.set(
+  'DisputeNotFound',
+  () => new DisputeNotFoundError()
+)
+
+/**
+ * DisputeAlreadyResolved: 'Dispute was already resolved'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DisputeAlreadyResolvedError extends Error {
+  readonly code: number = 0x42
+  readonly name: string = 'DisputeAlreadyResolved'
+  constructor() {
+    super('Dispute was already resolved')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DisputeAlreadyResolvedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x42, () => new DisputeAlreadyResolvedError())
+createErrorFromNameLookup.set(
+  'DisputeAlreadyResolved',
+  () => new DisputeAlreadyResolvedError()
+)
+
+/**
+ * PeerReviewInvalidated: 'Peer review was invalidated'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PeerReviewInvalidatedError extends Error {
+  readonly code: number = 0x43
+  readonly name: string = 'PeerReviewInvalidated'
+  constructor() {
+    super('Peer review was invalidated')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PeerReviewInvalidatedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x43, () => new PeerReviewInvalidatedError())
+createErrorFromNameLookup.set(
+  'PeerReviewInvalidated',
+  () => new PeerReviewInvalidatedError()
+)
+
+/**
+ * ConflictOfInterestAlreadyExists: 'Conflict of interest already declared'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ConflictOfInterestAlreadyExistsError extends Error {
+  readonly code: number = 0x44
+  readonly name: string = 'ConflictOfInterestAlreadyExists'
+  constructor() {
+    super('Conflict of interest already declared')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ConflictOfInterestAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x44,
+  () => new ConflictOfInterestAlreadyExistsError()
+)
+createErrorFromNameLookup.set(
+  'ConflictOfInterestAlreadyExists',
+  () => new ConflictOfInterestAlreadyExistsError()
+)
+
+/**
+ * ConflictOfInterest: 'Reviewer has a conflict of interest with the paper\'s author'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ConflictOfInterestError extends Error {
+  readonly code: number = 0x45
+  readonly name: string = 'ConflictOfInterest'
+  constructor() {
+    super('Reviewer has a conflict of interest with the paper\'s author')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ConflictOfInterestError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x45, () => new ConflictOfInterestError())
+createErrorFromNameLookup.set(
+  'ConflictOfInterest',
+  () => new ConflictOfInterestError()
+)
+
+/**
+ * TooManyExcludedReviewers: 'Too many excluded reviewers'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TooManyExcludedReviewersError extends Error {
+  readonly code: number = 0x46
+  readonly name: string = 'TooManyExcludedReviewers'
+  constructor() {
+    super('Too many excluded reviewers')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TooManyExcludedReviewersError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x46, () => new TooManyExcludedReviewersError())
+createErrorFromNameLookup.set(
+  'TooManyExcludedReviewers',
+  () => new TooManyExcludedReviewersError()
+)
+
+/**
+ * PaperRetracted: 'Paper was retracted'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PaperRetractedError extends Error {
+  readonly code: number = 0x47
+  readonly name: string = 'PaperRetracted'
+  constructor() {
+    super('Paper was retracted')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PaperRetractedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x47, () => new PaperRetractedError())
+createErrorFromNameLookup.set('PaperRetracted', () => new PaperRetractedError())
+
+/**
+ * ErratumAlreadyExists: 'Erratum already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ErratumAlreadyExistsError extends Error {
+  readonly code: number = 0x48
+  readonly name: string = 'ErratumAlreadyExists'
+  constructor() {
+    super('Erratum already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ErratumAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x48, () => new ErratumAlreadyExistsError())
+createErrorFromNameLookup.set(
+  'ErratumAlreadyExists',
+  () => new ErratumAlreadyExistsError()
+)
+
+/**
+ * ReviewVersionMismatch: 'Peer review is for a different version of the paper'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewVersionMismatchError extends Error {
+  readonly code: number = 0x49
+  readonly name: string = 'ReviewVersionMismatch'
+  constructor() {
+    super('Peer review is for a different version of the paper')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewVersionMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x49, () => new ReviewVersionMismatchError())
+createErrorFromNameLookup.set(
+  'ReviewVersionMismatch',
+  () => new ReviewVersionMismatchError()
+)
+
+/**
+ * PaperVersionAlreadyExists: 'Paper version already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PaperVersionAlreadyExistsError extends Error {
+  readonly code: number = 0x4a
+  readonly name: string = 'PaperVersionAlreadyExists'
+  constructor() {
+    super('Paper version already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PaperVersionAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x4a, () => new PaperVersionAlreadyExistsError())
+createErrorFromNameLookup.set(
+  'PaperVersionAlreadyExists',
+  () => new PaperVersionAlreadyExistsError()
+)
+
+/**
+ * DuplicateContentHash: 'Paper content hash is already registered'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicateContentHashError extends Error {
+  readonly code: number = 0x4b
+  readonly name: string = 'DuplicateContentHash'
+  constructor() {
+    super('Paper content hash is already registered')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicateContentHashError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x4b, () => new DuplicateContentHashError())
+createErrorFromNameLookup.set(
+  'DuplicateContentHash',
+  () => new DuplicateContentHashError()
+)
+
+/**
+ * DuplicateFlagAlreadyExists: 'Duplicate flag already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicateFlagAlreadyExistsError extends Error {
+  readonly code: number = 0x4c
+  readonly name: string = 'DuplicateFlagAlreadyExists'
+  constructor() {
+    super('Duplicate flag already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicateFlagAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x4c, () => new DuplicateFlagAlreadyExistsError())
+createErrorFromNameLookup.set(
+  'DuplicateFlagAlreadyExists',
+  () => new DuplicateFlagAlreadyExistsError()
+)
+
+/**
+ * InvalidEmbargo: 'Publication time must be in the future'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidEmbargoError extends Error {
+  readonly code: number = 0x4d
+  readonly name: string = 'InvalidEmbargo'
+  constructor() {
+    super('Publication time must be in the future')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidEmbargoError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x4d, () => new InvalidEmbargoError())
+createErrorFromNameLookup.set('InvalidEmbargo', () => new InvalidEmbargoError())
+
+/**
+ * PaperEmbargoed: 'Paper is under embargo'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PaperEmbargoedError extends Error {
+  readonly code: number = 0x4e
+  readonly name: string = 'PaperEmbargoed'
+  constructor() {
+    super('Paper is under embargo')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PaperEmbargoedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x4e, () => new PaperEmbargoedError())
+createErrorFromNameLookup.set('PaperEmbargoed', () => new PaperEmbargoedError())
+
+/**
+ * InvalidFeeSchedule: 'Invalid access fee schedule'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFeeScheduleError extends Error {
+  readonly code: number = 0x4f
+  readonly name: string = 'InvalidFeeSchedule'
+  constructor() {
+    super('Invalid access fee schedule')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidFeeScheduleError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x4f, () => new InvalidFeeScheduleError())
+createErrorFromNameLookup.set(
+  'InvalidFeeSchedule',
+  () => new InvalidFeeScheduleError()
+)
+
+/**
+ * InvalidPricing: 'Invalid pricing configuration'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPricingError extends Error {
+  readonly code: number = 0x50
+  readonly name: string = 'InvalidPricing'
+  constructor() {
+    super('Invalid pricing configuration')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPricingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x50, () => new InvalidPricingError())
+createErrorFromNameLookup.set('InvalidPricing', () => new InvalidPricingError())
+
+/**
+ * MaxPriceExceeded: 'Access fee exceeds the maximum price'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MaxPriceExceededError extends Error {
+  readonly code: number = 0x51
+  readonly name: string = 'MaxPriceExceeded'
+  constructor() {
+    super('Access fee exceeds the maximum price')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MaxPriceExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x51, () => new MaxPriceExceededError())
+createErrorFromNameLookup.set(
+  'MaxPriceExceeded',
+  () => new MaxPriceExceededError()
+)
+
+/**
+ * InvalidEditionSupply: 'Invalid edition supply'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidEditionSupplyError extends Error {
+  readonly code: number = 0x52
+  readonly name: string = 'InvalidEditionSupply'
+  constructor() {
+    super('Invalid edition supply')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidEditionSupplyError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x52, () => new InvalidEditionSupplyError())
+createErrorFromNameLookup.set(
+  'InvalidEditionSupply',
+  () => new InvalidEditionSupplyError()
+)
+
+/**
+ * SoldOut: 'Paper is sold out'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SoldOutError extends Error {
+  readonly code: number = 0x53
+  readonly name: string = 'SoldOut'
+  constructor() {
+    super('Paper is sold out')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SoldOutError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x53, () => new SoldOutError())
+createErrorFromNameLookup.set('SoldOut', () => new SoldOutError())
+
+/**
+ * NoReservedEditions: 'No reserved editions left to gift'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoReservedEditionsError extends Error {
+  readonly code: number = 0x54
+  readonly name: string = 'NoReservedEditions'
+  constructor() {
+    super('No reserved editions left to gift')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoReservedEditionsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x54, () => new NoReservedEditionsError())
+createErrorFromNameLookup.set(
+  'NoReservedEditions',
+  () => new NoReservedEditionsError()
+)
+
+/**
+ * InstitutionAlreadyExists: 'Institution already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstitutionAlreadyExistsError extends Error {
+  readonly code: number = 0x55
+  readonly name: string = 'InstitutionAlreadyExists'
+  constructor() {
+    super('Institution already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstitutionAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x55, () => new InstitutionAlreadyExistsError())
+createErrorFromNameLookup.set(
+  'InstitutionAlreadyExists',
+  () => new InstitutionAlreadyExistsError()
+)
+
+/**
+ * InstitutionNotFound: 'Institution not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstitutionNotFoundError extends Error {
+  readonly code: number = 0x56
+  readonly name: string = 'InstitutionNotFound'
+  constructor() {
+    super('Institution not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstitutionNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x56, () => new InstitutionNotFoundError())
+createErrorFromNameLookup.set(
+  'InstitutionNotFound',
+  () => new InstitutionNotFoundError()
+)
+
+/**
+ * InvalidInstitutionAdmin: 'Invalid institution admin'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidInstitutionAdminError extends Error {
+  readonly code: number = 0x57
+  readonly name: string = 'InvalidInstitutionAdmin'
+  constructor() {
+    super('Invalid institution admin')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidInstitutionAdminError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x57, () => new InvalidInstitutionAdminError())
+createErrorFromNameLookup.set(
+  'InvalidInstitutionAdmin',
+  () => new InvalidInstitutionAdminError()
+)
+
+/**
+ * InstitutionMemberAlreadyExists: 'Institution member already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstitutionMemberAlreadyExistsError extends Error {
+  readonly code: number = 0x58
+  readonly name: string = 'InstitutionMemberAlreadyExists'
+  constructor() {
+    super('Institution member already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstitutionMemberAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x58,
+  () => new InstitutionMemberAlreadyExistsError()
+)
+createErrorFromNameLookup.set(
+  'InstitutionMemberAlreadyExists',
+  () => new InstitutionMemberAlreadyExistsError()
+)
+
+/**
+ * InstitutionMemberNotFound: 'Institution member not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstitutionMemberNotFoundError extends Error {
+  readonly code: number = 0x59
+  readonly name: string = 'InstitutionMemberNotFound'
+  constructor() {
+    super('Institution member not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstitutionMemberNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x59, () => new InstitutionMemberNotFoundError())
+createErrorFromNameLookup.set(
+  'InstitutionMemberNotFound',
+  () => new InstitutionMemberNotFoundError()
+)
+
+/**
+ * InstitutionLicenseAlreadyExists: 'Institution license already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstitutionLicenseAlreadyExistsError extends Error {
+  readonly code: number = 0x5a
+  readonly name: string = 'InstitutionLicenseAlreadyExists'
+  constructor() {
+    super('Institution license already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstitutionLicenseAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x5a,
+  () => new InstitutionLicenseAlreadyExistsError()
+)
+createErrorFromNameLookup.set(
+  'InstitutionLicenseAlreadyExists',
+  () => new InstitutionLicenseAlreadyExistsError()
+)
+
+/**
+ * InstitutionLicenseNotFound: 'Institution license not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstitutionLicenseNotFoundError extends Error {
+  readonly code: number = 0x5b
+  readonly name: string = 'InstitutionLicenseNotFound'
+  constructor() {
+    super('Institution license not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstitutionLicenseNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x5b, () => new InstitutionLicenseNotFoundError())
+createErrorFromNameLookup.set(
+  'InstitutionLicenseNotFound',
+  () => new InstitutionLicenseNotFoundError()
+)
+
+/**
+ * InvalidInstitutionLicense: 'Invalid institution license'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidInstitutionLicenseError extends Error {
+  readonly code: number = 0x5c
+  readonly name: string = 'InvalidInstitutionLicense'
+  constructor() {
+    super('Invalid institution license')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidInstitutionLicenseError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x5c, () => new InvalidInstitutionLicenseError())
+createErrorFromNameLookup.set(
+  'InvalidInstitutionLicense',
+  () => new InvalidInstitutionLicenseError()
+)
+
+/**
+ * InvalidLicensor: 'Signer does not own the licensed paper or journal'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidLicensorError extends Error {
+  readonly code: number = 0x5d
+  readonly name: string = 'InvalidLicensor'
+  constructor() {
+    super('Signer does not own the licensed paper or journal')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidLicensorError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x5d, () => new InvalidLicensorError())
+createErrorFromNameLookup.set(
+  'InvalidLicensor',
+  () => new InvalidLicensorError()
+)
+
+/**
+ * InstitutionLicenseExpired: 'Institution license has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstitutionLicenseExpiredError extends Error {
+  readonly code: number = 0x5e
+  readonly name: string = 'InstitutionLicenseExpired'
+  constructor() {
+    super('Institution license has expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstitutionLicenseExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x5e, () => new InstitutionLicenseExpiredError())
+createErrorFromNameLookup.set(
+  'InstitutionLicenseExpired',
+  () => new InstitutionLicenseExpiredError()
+)
+
+/**
+ * SubscriptionPlanAlreadyExists: 'Subscription plan already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SubscriptionPlanAlreadyExistsError extends Error {
+  readonly code: number = 0x5f
+  readonly name: string = 'SubscriptionPlanAlreadyExists'
+  constructor() {
+    super('Subscription plan already exists')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SubscriptionPlanAlreadyExistsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x5f,
+  () => new SubscriptionPlanAlreadyExistsError()
+)
+createErrorFromNameLookup.set(
+  'SubscriptionPlanAlreadyExists',
+  () => new SubscriptionPlanAlreadyExistsError()
+)
+
+/**
+ * SubscriptionPlanNotFound: 'Subscription plan not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SubscriptionPlanNotFoundError extends Error {
+  readonly code: number = 0x60
+  readonly name: string = 'SubscriptionPlanNotFound'
+  constructor() {
+    super('Subscription plan not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SubscriptionPlanNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x60, () => new SubscriptionPlanNotFoundError())
+createErrorFromNameLookup.set(
+  'SubscriptionPlanNotFound',
+  () => new SubscriptionPlanNotFoundError()
+)
+
+/**
+ * InvalidSubscriptionPlan: 'Invalid subscription plan'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSubscriptionPlanError extends Error {
+  readonly code: number = 0x61
+  readonly name: string = 'InvalidSubscriptionPlan'
+  constructor() {
+    super('Invalid subscription plan')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSubscriptionPlanError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x61, () => new InvalidSubscriptionPlanError())
+createErrorFromNameLookup.set(
+  'InvalidSubscriptionPlan',
+  () => new InvalidSubscriptionPlanError()
+)
+
+/**
+ * InvalidSubscriptionPeriod: 'Subscription must run for at least one period'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSubscriptionPeriodError extends Error {
+  readonly code: number = 0x62
+  readonly name: string = 'InvalidSubscriptionPeriod'
+  constructor() {
+    super('Subscription must run for at least one period')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSubscriptionPeriodError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x62, () => new InvalidSubscriptionPeriodError())
+createErrorFromNameLookup.set(
+  'InvalidSubscriptionPeriod',
+  () => new InvalidSubscriptionPeriodError()
+)
+
+/**
+ * SubscriptionExpired: 'Subscription has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SubscriptionExpiredError extends Error {
+  readonly code: number = 0x63
+  readonly name: string = 'SubscriptionExpired'
+  constructor() {
+    super('Subscription has expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SubscriptionExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x63, () => new SubscriptionExpiredError())
+createErrorFromNameLookup.set(
+  'SubscriptionExpired',
+  () => new SubscriptionExpiredError()
+)
+
+/**
+ * AccessDenied: 'Reader has no access to the paper'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccessDeniedError extends Error {
+  readonly code: number = 0x64
+  readonly name: string = 'AccessDenied'
+  constructor() {
+    super('Reader has no access to the paper')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccessDeniedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x64, () => new AccessDeniedError())
+createErrorFromNameLookup.set('AccessDenied', () => new AccessDeniedError())
+
+/**
+ * RentalsDisabled: 'Paper does not offer rentals'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RentalsDisabledError extends Error {
+  readonly code: number = 0x65
+  readonly name: string = 'RentalsDisabled'
+  constructor() {
+    super('Paper does not offer rentals')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RentalsDisabledError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x65, () => new RentalsDisabledError())
+createErrorFromNameLookup.set(
+  'RentalsDisabled',
+  () => new RentalsDisabledError()
+)
+
+/**
+ * RentalExpired: 'Rental has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RentalExpiredError extends Error {
+  readonly code: number = 0x66
+  readonly name: string = 'RentalExpired'
+  constructor() {
+    super('Rental has expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RentalExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x66, () => new RentalExpiredError())
+createErrorFromNameLookup.set('RentalExpired', () => new RentalExpiredError())
+
+/**
+ * RentalNotExpired: 'Rental has not expired yet'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RentalNotExpiredError extends Error {
+  readonly code: number = 0x67
+  readonly name: string = 'RentalNotExpired'
+  constructor() {
+    super('Rental has not expired yet')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RentalNotExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x67, () => new RentalNotExpiredError())
+createErrorFromNameLookup.set(
+  'RentalNotExpired',
+  () => new RentalNotExpiredError()
+)
+
+/**
+ * NotARental: 'Research token is not a rental'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotARentalError extends Error {
+  readonly code: number = 0x68
+  readonly name: string = 'NotARental'
+  constructor() {
+    super('Research token is not a rental')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotARentalError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x68, () => new NotARentalError())
+createErrorFromNameLookup.set('NotARental', () => new NotARentalError())
+
+/**
+ * ResearchTokenAccountNotFound: 'Research token account not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ResearchTokenAccountNotFoundError extends Error {
+  readonly code: number = 0x69
+  readonly name: string = 'ResearchTokenAccountNotFound'
+  constructor() {
+    super('Research token account not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ResearchTokenAccountNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x69,
+  () => new ResearchTokenAccountNotFoundError()
+)
+createErrorFromNameLookup.set(
+  'ResearchTokenAccountNotFound',
+  () => new ResearchTokenAccountNotFoundError()
+)
+
+/**
+ * InvalidRefundWindow: 'Invalid refund window'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRefundWindowError extends Error {
+  readonly code: number = 0x6a
+  readonly name: string = 'InvalidRefundWindow'
+  constructor() {
+    super('Invalid refund window')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRefundWindowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x6a, () => new InvalidRefundWindowError())
+createErrorFromNameLookup.set(
+  'InvalidRefundWindow',
+  () => new InvalidRefundWindowError()
+)
+
+/**
+ * MintEscrowNotFound: 'Mint escrow not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintEscrowNotFoundError extends Error {
+  readonly code: number = 0x6b
+  readonly name: string = 'MintEscrowNotFound'
+  constructor() {
+    super('Mint escrow not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintEscrowNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x6b, () => new MintEscrowNotFoundError())
+createErrorFromNameLookup.set(
+  'MintEscrowNotFound',
+  () => new MintEscrowNotFoundError()
+)
+
+/**
+ * InvalidMintEscrow: 'Invalid mint escrow'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMintEscrowError extends Error {
+  readonly code: number = 0x6c
+  readonly name: string = 'InvalidMintEscrow'
+  constructor() {
+    super('Invalid mint escrow')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMintEscrowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x6c, () => new InvalidMintEscrowError())
+createErrorFromNameLookup.set(
+  'InvalidMintEscrow',
+  () => new InvalidMintEscrowError()
+)
+
+/**
+ * RefundWindowClosed: 'Refund window is closed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RefundWindowClosedError extends Error {
+  readonly code: number = 0x6d
+  readonly name: string = 'RefundWindowClosed'
+  constructor() {
+    super('Refund window is closed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RefundWindowClosedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x6d, () => new RefundWindowClosedError())
+createErrorFromNameLookup.set(
+  'RefundWindowClosed',
+  () => new RefundWindowClosedError()
+)
+
+/**
+ * RefundWindowOpen: 'Refund window is still open'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RefundWindowOpenError extends Error {
+  readonly code: number = 0x6e
+  readonly name: string = 'RefundWindowOpen'
+  constructor() {
+    super('Refund window is still open')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RefundWindowOpenError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x6e, () => new RefundWindowOpenError())
+createErrorFromNameLookup.set(
+  'RefundWindowOpen',
+  () => new RefundWindowOpenError()
+)
+
+/**
+ * DisputeOpen: 'Peer review has an open dispute'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DisputeOpenError extends Error {
+  readonly code: number = 0x6f
+  readonly name: string = 'DisputeOpen'
+  constructor() {
+    super('Peer review has an open dispute')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DisputeOpenError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x6f, () => new DisputeOpenError())
+createErrorFromNameLookup.set('DisputeOpen', () => new DisputeOpenError())
+
+/**
+ * ReviewsNotFinalized: 'Reviews have not been finalized'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReviewsNotFinalizedError extends Error {
+  readonly code: number = 0x70
+  readonly name: string = 'ReviewsNotFinalized'
+  constructor() {
+    super('Reviews have not been finalized')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReviewsNotFinalizedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x70, () => new ReviewsNotFinalizedError())
+createErrorFromNameLookup.set(
+  'ReviewsNotFinalized',
+  () => new ReviewsNotFinalizedError()
+)
+
+/**
+ * PeerReviewAlreadyFinalized: 'Peer review was already counted by FinalizeReviews'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PeerReviewAlreadyFinalizedError extends Error {
+  readonly code: number = 0x71
+  readonly name: string = 'PeerReviewAlreadyFinalized'
+  constructor() {
+    super('Peer review was already counted by FinalizeReviews')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PeerReviewAlreadyFinalizedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x71, () => new PeerReviewAlreadyFinalizedError())
+createErrorFromNameLookup.set(
+  'PeerReviewAlreadyFinalized',
+  () => new PeerReviewAlreadyFinalizedError()
+)
+
+/**
+ * PaperVersionNotFound: 'Paper version not found'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PaperVersionNotFoundError extends Error {
+  readonly code: number = 0x72
+  readonly name: string = 'PaperVersionNotFound'
+  constructor() {
+    super('Paper version not found')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PaperVersionNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x72, () => new PaperVersionNotFoundError())
+createErrorFromNameLookup.set(
+  'PaperVersionNotFound',
+  () => new PaperVersionNotFoundError()
+)
+
+/**
+ * CommitmentsPending: 'Blind review commitments are waiting to be revealed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CommitmentsPendingError extends Error {
+  readonly code: number = 0x73
+  readonly name: string = 'CommitmentsPending'
+  constructor() {
+    super('Blind review commitments are waiting to be revealed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CommitmentsPendingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x73, () => new CommitmentsPendingError())
+createErrorFromNameLookup.set(
+  'CommitmentsPending',
+  () => new CommitmentsPendingError()
+)
+
+/**
+ * VenueSubmissionPending: 'Paper is waiting to be submitted to a venue'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VenueSubmissionPendingError extends Error {
+  readonly code: number = 0x74
+  readonly name: string = 'VenueSubmissionPending'
+  constructor() {
+    super('Paper is waiting to be submitted to a venue')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VenueSubmissionPendingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x74, () => new VenueSubmissionPendingError())
+createErrorFromNameLookup.set(
+  'VenueSubmissionPending',
+  () => new VenueSubmissionPendingError()
+)
+
+/**
+ * DisputeExpired: 'Dispute resolution deadline has passed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DisputeExpiredError extends Error {
+  readonly code: number = 0x75
+  readonly name: string = 'DisputeExpired'
+  constructor() {
+    super('Dispute resolution deadline has passed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DisputeExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x75, () => new DisputeExpiredError())
+createErrorFromNameLookup.set('DisputeExpired', () => new DisputeExpiredError())
+
+/**
+ * DisputeWindowOpen: 'Dispute resolution deadline has not passed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DisputeWindowOpenError extends Error {
+  readonly code: number = 0x76
+  readonly name: string = 'DisputeWindowOpen'
+  constructor() {
+    super('Dispute resolution deadline has not passed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DisputeWindowOpenError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x76, () => new DisputeWindowOpenError())
+createErrorFromNameLookup.set(
+  'DisputeWindowOpen',
+  () => new DisputeWindowOpenError()
+)
+
+/**
+ * AccountAlreadyMigrated: 'Account already uses the current layout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountAlreadyMigratedError extends Error {
+  readonly code: number = 0x77
+  readonly name: string = 'AccountAlreadyMigrated'
+  constructor() {
+    super('Account already uses the current layout')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccountAlreadyMigratedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x77, () => new AccountAlreadyMigratedError())
+createErrorFromNameLookup.set(
+  'AccountAlreadyMigrated',
+  () => new AccountAlreadyMigratedError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -40,6 +40,9 @@ export const AddPeerReviewStruct = new beet.FixableBeetArgsStruct<
  * @property [_writable_] researcherProfilePdaAcc
  * @property [_writable_] paperPdaAcc
  * @property [_writable_] peerReviewPdaAcc
+ * @property [_writable_] reviewStakePdaAcc
+ * @property [] reviewInvitationPdaAcc
+ * @property [] conflictOfInterestPdaAcc
  * @property [] systemProgramAcc
  * @category Instructions
  * @category AddPeerReview
@@ -50,6 +53,9 @@ export type AddPeerReviewInstructionAccounts = {
   researcherProfilePdaAcc: web3.PublicKey;
   paperPdaAcc: web3.PublicKey;
   peerReviewPdaAcc: web3.PublicKey;
+  reviewStakePdaAcc: web3.PublicKey;
+  reviewInvitationPdaAcc: web3.PublicKey;
+  conflictOfInterestPdaAcc: web3.PublicKey;
   systemProgramAcc: web3.PublicKey;
 };
 
@@ -95,6 +101,21 @@ export function createAddPeerReviewInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.reviewStakePdaAcc,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.reviewInvitationPdaAcc,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.conflictOfInterestPdaAcc,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.systemProgramAcc,
       isWritable: false,
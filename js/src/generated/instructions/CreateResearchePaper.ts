@@ -42,6 +42,9 @@ export const CreateResearchePaperStruct = new beet.FixableBeetArgsStruct<
  * @property [_writable_, **signer**] publisherAcc
  * @property [_writable_] researcherProfilePdaAcc
  * @property [_writable_] paperPdaAcc
+ * @property [_writable_] reviewerPoolPdaAcc
+ * @property [_writable_] contentHashRecordPdaAcc
+ * @property [_writable_] paperVersionPdaAcc
  * @property [] systemProgramAcc
  * @category Instructions
  * @category CreateResearchePaper
@@ -51,6 +54,9 @@ export type CreateResearchePaperInstructionAccounts = {
   publisherAcc: web3.PublicKey;
   researcherProfilePdaAcc: web3.PublicKey;
   paperPdaAcc: web3.PublicKey;
+  reviewerPoolPdaAcc: web3.PublicKey;
+  contentHashRecordPdaAcc: web3.PublicKey;
+  paperVersionPdaAcc: web3.PublicKey;
   systemProgramAcc: web3.PublicKey;
 };
 
@@ -91,6 +97,21 @@ export function createCreateResearchePaperInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.reviewerPoolPdaAcc,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.contentHashRecordPdaAcc,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.paperVersionPdaAcc,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.systemProgramAcc,
       isWritable: false,
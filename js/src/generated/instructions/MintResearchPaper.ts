@@ -25,7 +25,7 @@ export type MintResearchPaperInstructionArgs = {
  * @category MintResearchPaper
  * @category generated
  */
-export const MintResearchPaperStruct = new beet.BeetArgsStruct<
+export const MintResearchPaperStruct = new beet.FixableBeetArgsStruct<
   MintResearchPaperInstructionArgs & {
     instructionDiscriminator: number;
   }
@@ -44,7 +44,9 @@ export const MintResearchPaperStruct = new beet.BeetArgsStruct<
  * @property [_writable_] researchTokenPdaAccount
  * @property [_writable_] paperPdaAcc
  * @property [_writable_] feeReceiverAcc
+ * @property [_writable_] reviewerPoolPdaAcc
  * @property [] systemProgramAcc
+ * @property [_writable_] mintEscrowPdaAcc (optional)
  * @category Instructions
  * @category MintResearchPaper
  * @category generated
@@ -55,7 +57,9 @@ export type MintResearchPaperInstructionAccounts = {
   researchTokenPdaAccount: web3.PublicKey;
   paperPdaAcc: web3.PublicKey;
   feeReceiverAcc: web3.PublicKey;
+  reviewerPoolPdaAcc: web3.PublicKey;
   systemProgramAcc: web3.PublicKey;
+  mintEscrowPdaAcc?: web3.PublicKey;
 };
 
 export const mintResearchPaperInstructionDiscriminator = 4;
@@ -105,6 +109,11 @@ export function createMintResearchPaperInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.reviewerPoolPdaAcc,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.systemProgramAcc,
       isWritable: false,
@@ -112,6 +121,14 @@ export function createMintResearchPaperInstruction(
     },
   ];
 
+  if (accounts.mintEscrowPdaAcc != null) {
+    keys.push({
+      pubkey: accounts.mintEscrowPdaAcc,
+      isWritable: true,
+      isSigner: false,
+    });
+  }
+
   const ix = new web3.TransactionInstruction({
     programId,
     keys,
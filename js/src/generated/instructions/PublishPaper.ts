@@ -22,7 +22,7 @@ export type PublishPaperInstructionArgs = {
  * @category PublishPaper
  * @category generated
  */
-export const PublishPaperStruct = new beet.BeetArgsStruct<
+export const PublishPaperStruct = new beet.FixableBeetArgsStruct<
   PublishPaperInstructionArgs & {
     instructionDiscriminator: number;
   }
@@ -38,6 +38,7 @@ export const PublishPaperStruct = new beet.BeetArgsStruct<
  *
  * @property [_writable_, **signer**] publisherAcc
  * @property [_writable_] paperPdaAcc
+ * @property [_writable_] paperVersionPdaAcc
  * @category Instructions
  * @category PublishPaper
  * @category generated
@@ -45,6 +46,7 @@ export const PublishPaperStruct = new beet.BeetArgsStruct<
 export type PublishPaperInstructionAccounts = {
   publisherAcc: web3.PublicKey;
   paperPdaAcc: web3.PublicKey;
+  paperVersionPdaAcc: web3.PublicKey;
 };
 
 export const publishPaperInstructionDiscriminator = 2;
@@ -79,6 +81,11 @@ export function createPublishPaperInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.paperVersionPdaAcc,
+      isWritable: true,
+      isSigner: false,
+    },
   ];
 
   const ix = new web3.TransactionInstruction({
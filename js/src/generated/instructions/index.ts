@@ -1,6 +1,49 @@
+export * from './AddInstitutionMember'
 export * from './AddPeerReview'
+export * from './AmendPeerReview'
+export * from './AppendErratum'
+export * from './AppointEditor'
 export * from './CheckAndAssignReputation'
+export * from './ClaimLicensedPaper'
+export * from './ClaimReviewerReward'
+export * from './CloseRental'
+export * from './CommitReview'
+export * from './CrankPaperDeadline'
+export * from './CreateAuthorResponse'
+export * from './CreateInstitution'
+export * from './CreateInstitutionLicense'
+export * from './CreateJournal'
 export * from './CreateResearchePaper'
 export * from './CreateResearcherProfile'
+export * from './CreateRubric'
+export * from './CreateSubscriptionPlan'
+export * from './DeclareConflictOfInterest'
+export * from './DeskReject'
+export * from './ExpireDispute'
+export * from './ExpireReviewCommit'
+export * from './ExtendRental'
+export * from './FinalizeReviews'
+export * from './FlagDuplicate'
+export * from './GiftResearchPaper'
+export * from './InviteReviewer'
+export * from './MigratePeerReview'
+export * from './MigrateResearchPaper'
 export * from './MintResearchPaper'
+export * from './OpenDispute'
 export * from './PublishPaper'
+export * from './ReleaseEscrow'
+export * from './ReleaseReviewStake'
+export * from './RemoveInstitutionMember'
+export * from './RentResearchPaper'
+export * from './RequestRefund'
+export * from './ResolveDispute'
+export * from './RetractPaper'
+export * from './RevealReview'
+export * from './SetArbiters'
+export * from './SkipVenueSubmission'
+export * from './SlashReviewStake'
+export * from './SubmitRevision'
+export * from './SubmitToJournal'
+export * from './Subscribe'
+export * from './VerifyAccess'
+export * from './WithdrawPaper'
@@ -7,12 +7,10 @@
 
 import * as beet from '@metaplex-foundation/beet'
 export type AddPeerReview = {
-  qualityOfResearch: number
-  potentialForRealWorldUseCase: number
-  domainKnowledge: number
-  practicalityOfResultObtained: number
+  scores: Uint8Array
   metaDataMerkleRoot: string
   pdaBump: number
+  stakePdaBump: number
 }
 
 /**
@@ -21,12 +19,10 @@ export type AddPeerReview = {
  */
 export const addPeerReviewBeet = new beet.FixableBeetArgsStruct<AddPeerReview>(
   [
-    ['qualityOfResearch', beet.u8],
-    ['potentialForRealWorldUseCase', beet.u8],
-    ['domainKnowledge', beet.u8],
-    ['practicalityOfResultObtained', beet.u8],
+    ['scores', beet.bytes],
     ['metaDataMerkleRoot', beet.utf8String],
     ['pdaBump', beet.u8],
+    ['stakePdaBump', beet.u8],
   ],
   'AddPeerReview'
 )
@@ -5,12 +5,37 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
+import { FeeDecay, feeDecayBeet } from './FeeDecay'
+import { PricingMode, pricingModeBeet } from './PricingMode'
 export type CreateResearchePaper = {
   accessFee: number
   paperContentHash: string
   metaDataMerkleRoot: string
   pdaBump: number
+  reviewerShareBps: number
+  reviewerPoolPdaBump: number
+  blindReview: boolean
+  reviewWindowSecs: beet.bignum
+  excludedReviewers: web3.PublicKey[]
+  contentHashRecordPdaBump: number
+  feeDecay: FeeDecay
+  feeDecayStepSecs: beet.bignum
+  feeDecayStepBps: number
+  openAccessAfterSecs: beet.bignum
+  pricingMode: PricingMode
+  priceSlope: number
+  tierMintThresholds: number[]
+  tierFees: number[]
+  maxMints: beet.bignum
+  reservedMints: beet.bignum
+  rentalFee: number
+  rentalPeriodSecs: beet.bignum
+  refundWindowSecs: beet.bignum
+  paperVersionPdaBump: number
+  awaitingVenue: boolean
 }
 
 /**
@@ -24,6 +49,27 @@ export const createResearchePaperBeet =
       ['paperContentHash', beet.utf8String],
       ['metaDataMerkleRoot', beet.utf8String],
       ['pdaBump', beet.u8],
+      ['reviewerShareBps', beet.u16],
+      ['reviewerPoolPdaBump', beet.u8],
+      ['blindReview', beet.bool],
+      ['reviewWindowSecs', beet.i64],
+      ['excludedReviewers', beet.array(beetSolana.publicKey)],
+      ['contentHashRecordPdaBump', beet.u8],
+      ['feeDecay', feeDecayBeet],
+      ['feeDecayStepSecs', beet.i64],
+      ['feeDecayStepBps', beet.u16],
+      ['openAccessAfterSecs', beet.i64],
+      ['pricingMode', pricingModeBeet],
+      ['priceSlope', beet.u32],
+      ['tierMintThresholds', beet.array(beet.u32)],
+      ['tierFees', beet.array(beet.u32)],
+      ['maxMints', beet.u64],
+      ['reservedMints', beet.u64],
+      ['rentalFee', beet.u32],
+      ['rentalPeriodSecs', beet.i64],
+      ['refundWindowSecs', beet.i64],
+      ['paperVersionPdaBump', beet.u8],
+      ['awaitingVenue', beet.bool],
     ],
     'CreateResearchePaper'
   )
@@ -5,16 +5,27 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 export type MintResearchPaper = {
   pdaBump: number
+  maxPrice: beet.bignum
+  recipient: beet.COption<web3.PublicKey>
+  escrowPdaBump: number
 }
 
 /**
  * @category userTypes
  * @category generated
  */
-export const mintResearchPaperBeet = new beet.BeetArgsStruct<MintResearchPaper>(
-  [['pdaBump', beet.u8]],
-  'MintResearchPaper'
-)
+export const mintResearchPaperBeet =
+  new beet.FixableBeetArgsStruct<MintResearchPaper>(
+    [
+      ['pdaBump', beet.u8],
+      ['maxPrice', beet.u64],
+      ['recipient', beet.coption(beetSolana.publicKey)],
+      ['escrowPdaBump', beet.u8],
+    ],
+    'MintResearchPaper'
+  )
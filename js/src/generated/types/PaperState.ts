@@ -17,6 +17,11 @@ export enum PaperState {
   RequiresRevision,
   Published,
   Minted,
+  Expired,
+  DeskRejected,
+  Withdrawn,
+  Retracted,
+  ScheduledPublish,
 }
 
 /**
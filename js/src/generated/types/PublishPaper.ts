@@ -8,13 +8,17 @@
 import * as beet from '@metaplex-foundation/beet'
 export type PublishPaper = {
   pdaBump: number
+  publishAt: beet.COption<beet.bignum>
 }
 
 /**
  * @category userTypes
  * @category generated
  */
-export const publishPaperBeet = new beet.BeetArgsStruct<PublishPaper>(
-  [['pdaBump', beet.u8]],
+export const publishPaperBeet = new beet.FixableBeetArgsStruct<PublishPaper>(
+  [
+    ['pdaBump', beet.u8],
+    ['publishAt', beet.coption(beet.i64)],
+  ],
   'PublishPaper'
 )
@@ -1,8 +1,46 @@
+export * from './AccessKind'
+export * from './AccessProof'
+export * from './AddInstitutionMember'
 export * from './AddPeerReview'
+export * from './AmendPeerReview'
+export * from './AppendErratum'
+export * from './AppointEditor'
 export * from './CheckAndAssignReputation'
+export * from './ClaimLicensedPaper'
+export * from './CommitReview'
+export * from './ConflictKind'
+export * from './CreateAuthorResponse'
+export * from './CreateInstitution'
+export * from './CreateInstitutionLicense'
+export * from './CreateJournal'
 export * from './CreateResearchePaper'
 export * from './CreateResearcherProfile'
+export * from './CreateRubric'
+export * from './CreateSubscriptionPlan'
+export * from './DeclareConflictOfInterest'
+export * from './DeskReject'
+export * from './DisputeState'
+export * from './ExtendRental'
+export * from './FeeDecay'
+export * from './FlagDuplicate'
+export * from './GiftResearchPaper'
+export * from './InviteReviewer'
+export * from './LicenseScope'
+export * from './MigrateResearchPaper'
 export * from './MintResearchPaper'
+export * from './OpenDispute'
 export * from './PaperState'
+export * from './PricingMode'
 export * from './PublishPaper'
+export * from './RentResearchPaper'
 export * from './ResearcherProfileState'
+export * from './ResolveDispute'
+export * from './RetractPaper'
+export * from './RevealReview'
+export * from './SetArbiters'
+export * from './SlashReviewStake'
+export * from './SubmitRevision'
+export * from './Subscribe'
+export * from './SubscriptionScope'
+export * from './VerifyAccess'
+export * from './WithdrawPaper'
@@ -0,0 +1,73 @@
+import * as web3 from "@solana/web3.js";
+import { ResearchPaper, ResearchPaperArgs } from "./generated";
+
+/**
+ * Review statistics derived from the running sums kept on a ResearchPaper account.
+ * Averages and variances are fixed point with two decimals, 5025 is 50.25.
+ */
+
+export const SCORE_FIXED_POINT_SCALE = 100;
+
+export type PaperScoreSums = Pick<
+  ResearchPaperArgs,
+  | "rubricDimensionCount"
+  | "scoredReviews"
+  | "scoreSums"
+  | "scoreSquareSums"
+  | "weightedScoreSum"
+  | "weightedScoreSquareSum"
+>;
+
+export type PaperStats = {
+  reviewCount: number;
+  averageScores: number[];
+  scoreVariances: number[];
+  averageWeightedScore: number;
+  weightedScoreVariance: number;
+};
+
+function fixedPointMean(sum: number, count: number): number {
+  if (count === 0) {
+    return 0;
+  }
+
+  return Math.floor((sum * SCORE_FIXED_POINT_SCALE) / count);
+}
+
+function fixedPointVariance(sum: number, squareSum: number, count: number): number {
+  if (count === 0) {
+    return 0;
+  }
+
+  const spread = Math.max(count * squareSum - sum * sum, 0);
+
+  return Math.floor((spread * SCORE_FIXED_POINT_SCALE) / (count * count));
+}
+
+export function paperStats(paper: PaperScoreSums): PaperStats {
+  const count = paper.scoredReviews;
+
+  const dimensions = paper.scoreSums.slice(0, paper.rubricDimensionCount);
+
+  return {
+    reviewCount: count,
+    averageScores: dimensions.map((sum) => fixedPointMean(sum, count)),
+    scoreVariances: dimensions.map((sum, dimension) =>
+      fixedPointVariance(sum, paper.scoreSquareSums[dimension], count)
+    ),
+    averageWeightedScore: fixedPointMean(paper.weightedScoreSum, count),
+    weightedScoreVariance: fixedPointVariance(
+      paper.weightedScoreSum,
+      paper.weightedScoreSquareSum,
+      count
+    ),
+  };
+}
+
+export function paperStatsFromAccountInfo(
+  accountInfo: web3.AccountInfo<Buffer>
+): PaperStats {
+  const [paper] = ResearchPaper.fromAccountInfo(accountInfo);
+
+  return paperStats(paper);
+}
@@ -189,11 +189,40 @@ describe("Integration tests", () => {
 
       console.log("Researcher profile pda", researcherProfilePda.toBase58());
 
+      const [reviewerPoolPda, reviewerPoolBump] =
+        solana.PublicKey.findProgramAddressSync(
+          [Buffer.from("deres_reviewer_pool"), paperPda.toBuffer()],
+          sdk.PROGRAM_ID
+        );
+
+      const [contentHashRecordPda, contentHashRecordBump] =
+        solana.PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("deres_content_hash"),
+            Buffer.from(paperContentHash.slice(0, 32)),
+            Buffer.from(paperContentHash.slice(32, 64)),
+          ],
+          sdk.PROGRAM_ID
+        );
+
+      const [paperVersionPda, paperVersionBump] =
+        solana.PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("deres_paper_version"),
+            paperPda.toBuffer(),
+            Buffer.from([0]),
+          ],
+          sdk.PROGRAM_ID
+        );
+
       const ix = sdk.createCreateResearchePaperInstruction(
         {
           publisherAcc: localWallet.publicKey,
           researcherProfilePdaAcc: researcherProfilePda,
           paperPdaAcc: paperPda,
+          reviewerPoolPdaAcc: reviewerPoolPda,
+          contentHashRecordPdaAcc: contentHashRecordPda,
+          paperVersionPdaAcc: paperVersionPda,
           systemProgramAcc: solana.SystemProgram.programId,
         },
         {
@@ -203,6 +232,27 @@ describe("Integration tests", () => {
             metaDataMerkleRoot:
               "0a69c09f7c1eca87a0a6fb108e3aeb1929a2e4bb732a021612730325fd5875b2",
             pdaBump: bump,
+            reviewerShareBps: 1000,
+            reviewerPoolPdaBump: reviewerPoolBump,
+            blindReview: false,
+            reviewWindowSecs: 0,
+            excludedReviewers: [],
+            contentHashRecordPdaBump: contentHashRecordBump,
+            feeDecay: sdk.FeeDecay.None,
+            feeDecayStepSecs: 0,
+            feeDecayStepBps: 0,
+            openAccessAfterSecs: 0,
+            pricingMode: sdk.PricingMode.Flat,
+            priceSlope: 0,
+            tierMintThresholds: [],
+            tierFees: [],
+            maxMints: 0,
+            reservedMints: 0,
+            rentalFee: 0,
+            rentalPeriodSecs: 0,
+            refundWindowSecs: 0,
+            paperVersionPdaBump: paperVersionBump,
+            awaitingVenue: false,
           },
         }
       );
@@ -335,29 +385,63 @@ describe("Integration tests", () => {
           Buffer.from("deres_peer_review"),
           paperPda.toBuffer(),
           wallet2.publicKey.toBuffer(),
+          Buffer.from([0]),
         ],
         sdk.PROGRAM_ID
       );
 
       console.log("Peer review pda", peerReviewPda.toBase58());
 
+      const [reviewStakePda, stakeBump] =
+        solana.PublicKey.findProgramAddressSync(
+          [Buffer.from("deres_review_stake"), peerReviewPda.toBuffer()],
+          sdk.PROGRAM_ID
+        );
+
+      const reviewInvitationPda = solana.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("deres_review_invitation"),
+          paperPda.toBuffer(),
+          wallet2.publicKey.toBuffer(),
+        ],
+        sdk.PROGRAM_ID
+      )[0];
+
+      const [firstKey, secondKey] =
+        Buffer.compare(
+          localWallet.publicKey.toBuffer(),
+          wallet2.publicKey.toBuffer()
+        ) < 0
+          ? [localWallet.publicKey, wallet2.publicKey]
+          : [wallet2.publicKey, localWallet.publicKey];
+
+      const conflictOfInterestPda = solana.PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("deres_conflict_of_interest"),
+          firstKey.toBuffer(),
+          secondKey.toBuffer(),
+        ],
+        sdk.PROGRAM_ID
+      )[0];
+
       const ix = sdk.createAddPeerReviewInstruction(
         {
           reviewerAcc: wallet2.publicKey,
           researcherProfilePdaAcc: researcherProfilePda,
           paperPdaAcc: paperPda,
           peerReviewPdaAcc: peerReviewPda,
+          reviewStakePdaAcc: reviewStakePda,
+          reviewInvitationPdaAcc: reviewInvitationPda,
+          conflictOfInterestPdaAcc: conflictOfInterestPda,
           systemProgramAcc: solana.SystemProgram.programId,
         },
         {
           addPeerReview: {
-            qualityOfResearch: 100,
-            potentialForRealWorldUseCase: 100,
-            practicalityOfResultObtained: 100,
-            domainKnowledge: 100,
+            scores: Uint8Array.from([100, 100, 100, 100]),
             metaDataMerkleRoot:
               "0a69c09f7c1eca87a0a6fb108e3aeb1929a2e4bb732a021612730325fd5875b2",
             pdaBump: bump2,
+            stakePdaBump: stakeBump,
           },
         }
       );
@@ -402,14 +486,21 @@ describe("Integration tests", () => {
 
       console.log("Paper pda", paperPda.toBase58());
 
+      const [paperVersionPda] = solana.PublicKey.findProgramAddressSync(
+        [Buffer.from("deres_paper_version"), paperPda.toBuffer(), Buffer.from([0])],
+        sdk.PROGRAM_ID
+      );
+
       const ix = sdk.createPublishPaperInstruction(
         {
           publisherAcc: localWallet.publicKey,
           paperPdaAcc: paperPda,
+          paperVersionPdaAcc: paperVersionPda,
         },
         {
           publishPaper: {
             pdaBump: bump,
+            publishAt: null,
           },
         }
       );
@@ -479,6 +570,11 @@ describe("Integration tests", () => {
         ResearchTokenAccountPda.toBase58()
       );
 
+      const reviewerPoolPda = solana.PublicKey.findProgramAddressSync(
+        [Buffer.from("deres_reviewer_pool"), paperPda.toBuffer()],
+        sdk.PROGRAM_ID
+      )[0];
+
       const ix = sdk.createMintResearchPaperInstruction(
         {
           researcherAcc: localWallet.publicKey,
@@ -486,11 +582,15 @@ describe("Integration tests", () => {
           researchTokenPdaAccount: ResearchTokenAccountPda,
           paperPdaAcc: paperPda,
           feeReceiverAcc: localWallet.publicKey,
+          reviewerPoolPdaAcc: reviewerPoolPda,
           systemProgramAcc: solana.SystemProgram.programId,
         },
         {
           mintResearchPaper: {
             pdaBump: bump2,
+            maxPrice: 100,
+            recipient: null,
+            escrowPdaBump: 0,
           },
         }
       );
@@ -548,6 +648,8 @@ describe("Integration tests", () => {
 
       console.log("Minted research paper", acc.pretty());
 
+      console.log("Paper stats", sdk.paperStatsFromAccountInfo(acc_info));
+
       const ResearchTokenAccountPda = solana.PublicKey.findProgramAddressSync(
         [
           Buffer.from("deres_token_account"),
@@ -44,6 +44,208 @@ pub enum DeResearcherError {
     InvalidReputationChecker,
     #[error("Research Token account already exists")]
     ResearchTokenAccountAlreadyExists,
+    #[error("Invalid governance authority")]
+    InvalidGovernanceAuthority,
+    #[error("Invalid treasury account")]
+    InvalidTreasury,
+    #[error("Paper has not reached a final state")]
+    PaperNotFinalized,
+    #[error("Review stake not found")]
+    ReviewStakeNotFound,
+    #[error("Review stake already slashed")]
+    ReviewStakeAlreadySlashed,
+    #[error("Invalid slash amount")]
+    InvalidSlashAmount,
+    #[error("Reviewer pool already exists")]
+    ReviewerPoolAlreadyExists,
+    #[error("Reviewer pool not found")]
+    ReviewerPoolNotFound,
+    #[error("Invalid reviewer share")]
+    InvalidReviewerShare,
+    #[error("Peer Review not found")]
+    PeerReviewNotFound,
+    #[error("Peer Review did not approve the paper")]
+    PeerReviewNotApproved,
+    #[error("No reward to claim")]
+    NoRewardToClaim,
+    #[error("Every review of the paper must be provided")]
+    IncompleteReviewSet,
+    #[error("Reviews already finalized")]
+    ReviewsAlreadyFinalized,
+    #[error("Paper requires blind review, use CommitReview")]
+    BlindReviewRequired,
+    #[error("Paper does not use blind review")]
+    NotABlindReview,
+    #[error("Review window is closed")]
+    ReviewWindowClosed,
+    #[error("Review commit window is still open")]
+    CommitWindowOpen,
+    #[error("Review reveal window is closed")]
+    RevealWindowClosed,
+    #[error("Review reveal window is still open")]
+    RevealWindowOpen,
+    #[error("Revealed scores do not match the commitment")]
+    CommitmentMismatch,
+    #[error("Peer Review already revealed")]
+    PeerReviewAlreadyRevealed,
+    #[error("Invalid review window")]
+    InvalidReviewWindow,
+    #[error("Review window is still open")]
+    ReviewWindowOpen,
+    #[error("Editor already exists")]
+    EditorAlreadyExists,
+    #[error("Editor not found")]
+    EditorNotFound,
+    #[error("Paper was not submitted to a venue")]
+    PaperHasNoVenue,
+    #[error("Review invitation already exists")]
+    ReviewInvitationAlreadyExists,
+    #[error("Reviewer was not invited to review this paper")]
+    ReviewInvitationRequired,
+    #[error("Journal already exists")]
+    JournalAlreadyExists,
+    #[error("Journal not found")]
+    JournalNotFound,
+    #[error("Invalid journal authority")]
+    InvalidJournalAuthority,
+    #[error("Invalid minimum approvals")]
+    InvalidMinApprovals,
+    #[error("Paper was already submitted to a venue")]
+    PaperAlreadySubmitted,
+    #[error("Rubric already exists")]
+    RubricAlreadyExists,
+    #[error("Rubric not found")]
+    RubricNotFound,
+    #[error("Invalid rubric")]
+    InvalidRubric,
+    #[error("Scores do not match the paper's rubric")]
+    InvalidRubricScores,
+    #[error("Author response already exists")]
+    AuthorResponseAlreadyExists,
+    #[error("Peer review has no author response")]
+    AuthorResponseRequired,
+    #[error("Peer review was already amended")]
+    PeerReviewAlreadyAmended,
+    #[error("Invalid arbiter set")]
+    InvalidArbiterSet,
+    #[error("Arbiter set not found")]
+    ArbiterSetNotFound,
+    #[error("Signer is not an arbiter")]
+    InvalidArbiter,
+    #[error("Arbiter already voted on this dispute")]
+    ArbiterAlreadyVoted,
+    #[error("Dispute already exists")]
+    DisputeAlreadyExists,
+    #[error("Dispute not found")]
+    DisputeNotFound,
+    #[error("Dispute was already resolved")]
+    DisputeAlreadyResolved,
+    #[error("Peer review was invalidated")]
+    PeerReviewInvalidated,
+    #[error("Conflict of interest already declared")]
+    ConflictOfInterestAlreadyExists,
+    #[error("Reviewer has a conflict of interest with the paper's author")]
+    ConflictOfInterest,
+    #[error("Too many excluded reviewers")]
+    TooManyExcludedReviewers,
+    #[error("Paper was retracted")]
+    PaperRetracted,
+    #[error("Erratum already exists")]
+    ErratumAlreadyExists,
+    #[error("Peer review is for a different version of the paper")]
+    ReviewVersionMismatch,
+    #[error("Paper version already exists")]
+    PaperVersionAlreadyExists,
+    #[error("Paper content hash is already registered")]
+    DuplicateContentHash,
+    #[error("Duplicate flag already exists")]
+    DuplicateFlagAlreadyExists,
+    #[error("Publication time must be in the future")]
+    InvalidEmbargo,
+    #[error("Paper is under embargo")]
+    PaperEmbargoed,
+    #[error("Invalid access fee schedule")]
+    InvalidFeeSchedule,
+    #[error("Invalid pricing configuration")]
+    InvalidPricing,
+    #[error("Access fee exceeds the maximum price")]
+    MaxPriceExceeded,
+    #[error("Invalid edition supply")]
+    InvalidEditionSupply,
+    #[error("Paper is sold out")]
+    SoldOut,
+    #[error("No reserved editions left to gift")]
+    NoReservedEditions,
+    #[error("Institution already exists")]
+    InstitutionAlreadyExists,
+    #[error("Institution not found")]
+    InstitutionNotFound,
+    #[error("Invalid institution admin")]
+    InvalidInstitutionAdmin,
+    #[error("Institution member already exists")]
+    InstitutionMemberAlreadyExists,
+    #[error("Institution member not found")]
+    InstitutionMemberNotFound,
+    #[error("Institution license already exists")]
+    InstitutionLicenseAlreadyExists,
+    #[error("Institution license not found")]
+    InstitutionLicenseNotFound,
+    #[error("Invalid institution license")]
+    InvalidInstitutionLicense,
+    #[error("Signer does not own the licensed paper or journal")]
+    InvalidLicensor,
+    #[error("Institution license has expired")]
+    InstitutionLicenseExpired,
+    #[error("Subscription plan already exists")]
+    SubscriptionPlanAlreadyExists,
+    #[error("Subscription plan not found")]
+    SubscriptionPlanNotFound,
+    #[error("Invalid subscription plan")]
+    InvalidSubscriptionPlan,
+    #[error("Subscription must run for at least one period")]
+    InvalidSubscriptionPeriod,
+    #[error("Subscription has expired")]
+    SubscriptionExpired,
+    #[error("Reader has no access to the paper")]
+    AccessDenied,
+    #[error("Paper does not offer rentals")]
+    RentalsDisabled,
+    #[error("Rental has expired")]
+    RentalExpired,
+    #[error("Rental has not expired yet")]
+    RentalNotExpired,
+    #[error("Research token is not a rental")]
+    NotARental,
+    #[error("Research token account not found")]
+    ResearchTokenAccountNotFound,
+    #[error("Invalid refund window")]
+    InvalidRefundWindow,
+    #[error("Mint escrow not found")]
+    MintEscrowNotFound,
+    #[error("Invalid mint escrow")]
+    InvalidMintEscrow,
+    #[error("Refund window is closed")]
+    RefundWindowClosed,
+    #[error("Refund window is still open")]
+    RefundWindowOpen,
+    #[error("Peer review has an open dispute")]
+    DisputeOpen,
+    #[error("Reviews have not been finalized")]
+    ReviewsNotFinalized,
+    #[error("Peer review was already counted by FinalizeReviews")]
+    PeerReviewAlreadyFinalized,
+    #[error("Paper version not found")]
+    PaperVersionNotFound,
+    #[error("Blind review commitments are waiting to be revealed")]
+    CommitmentsPending,
+    #[error("Paper is waiting to be submitted to a venue")]
+    VenueSubmissionPending,
+    #[error("Dispute resolution deadline has passed")]
+    DisputeExpired,
+    #[error("Dispute resolution deadline has not passed")]
+    DisputeWindowOpen,
+    #[error("Account already uses the current layout")]
+    AccountAlreadyMigrated,
 }
 
 impl From<DeResearcherError> for ProgramError {
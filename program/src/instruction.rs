@@ -4,18 +4,27 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankInstruction;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
     program::{invoke, invoke_signed},
+    program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
-    system_instruction,
+    system_instruction, system_program,
     sysvar::Sysvar,
 };
 
 use crate::{
     error::DeResearcherError,
-    state::{PeerReview, ResearchPaper, ResearchTokenAccount, ResearcherProfile},
+    state::{
+        checked_string_convt_to_64_bytes, AccessKind, AccessProof, ArbiterSet, AuthorResponse,
+        ConflictKind, ConflictOfInterest, ContentHashRecord, Dispute, DisputeState, DuplicateFlag,
+        Editor, Erratum, FeeDecay, Institution, InstitutionLicense, InstitutionMember, Journal,
+        LicenseScope, MintEscrow, PaperState, PaperVersion, PeerReview, PricingMode, ResearchPaper,
+        ResearchTokenAccount, ResearcherProfile, ReviewInvitation, ReviewStake, ReviewerPool,
+        Rubric, Subscription, SubscriptionPlan, SubscriptionScope,
+    },
 };
 
 const RESEARCH_PAPER_PDA_SEED: &[u8] = b"deres_research_paper";
@@ -25,6 +34,46 @@ const RESEARCH_TOKEN_ACCOUNT_PDA_SEED: &[u8] = b"deres_token_account";
 
 const RESEARCHER_PROFILE_PDA_SEED: &[u8] = b"deres_researcher_profile";
 
+const REVIEW_STAKE_PDA_SEED: &[u8] = b"deres_review_stake";
+
+const REVIEWER_POOL_PDA_SEED: &[u8] = b"deres_reviewer_pool";
+
+const JOURNAL_PDA_SEED: &[u8] = b"deres_journal";
+
+const EDITOR_PDA_SEED: &[u8] = b"deres_editor";
+
+const REVIEW_INVITATION_PDA_SEED: &[u8] = b"deres_review_invitation";
+
+const RUBRIC_PDA_SEED: &[u8] = b"deres_rubric";
+
+const AUTHOR_RESPONSE_PDA_SEED: &[u8] = b"deres_author_response";
+
+const ARBITER_SET_PDA_SEED: &[u8] = b"deres_arbiter_set";
+
+const DISPUTE_PDA_SEED: &[u8] = b"deres_dispute";
+
+const CONFLICT_OF_INTEREST_PDA_SEED: &[u8] = b"deres_conflict_of_interest";
+
+const ERRATUM_PDA_SEED: &[u8] = b"deres_erratum";
+
+const PAPER_VERSION_PDA_SEED: &[u8] = b"deres_paper_version";
+
+const CONTENT_HASH_RECORD_PDA_SEED: &[u8] = b"deres_content_hash";
+
+const DUPLICATE_FLAG_PDA_SEED: &[u8] = b"deres_duplicate_flag";
+
+const INSTITUTION_PDA_SEED: &[u8] = b"deres_institution";
+
+const INSTITUTION_MEMBER_PDA_SEED: &[u8] = b"deres_institution_member";
+
+const INSTITUTION_LICENSE_PDA_SEED: &[u8] = b"deres_institution_license";
+
+const SUBSCRIPTION_PLAN_PDA_SEED: &[u8] = b"deres_subscription_plan";
+
+const SUBSCRIPTION_PDA_SEED: &[u8] = b"deres_subscription";
+
+const MINT_ESCROW_PDA_SEED: &[u8] = b"deres_mint_escrow";
+
 pub const MAX_REPUTATION: u8 = 100;
 
 pub const MIN_REPUTATION_FOR_PEER_REVIEW: u8 = 50;
@@ -32,8 +81,32 @@ pub const MIN_REPUTATION_FOR_PEER_REVIEW: u8 = 50;
 pub const MAX_STRING_SIZE: usize = 64;
 
 // TODO: change this to 5
+// Default for papers that are not submitted to a journal
 pub const MIN_APPROVALS_FOR_PUBLISH: u8 = 1;
 
+pub const MAX_RUBRIC_DIMENSIONS: usize = 8;
+
+pub const MAX_SCORE: u8 = 100;
+
+// FinalizeReviews counts every score from 0 to MAX_SCORE for each rubric dimension
+pub const SCORE_HISTOGRAM_BUCKETS: usize = MAX_SCORE as usize + 1;
+
+// Papers that are not submitted to a journal are scored on four equally weighted dimensions
+pub const DEFAULT_RUBRIC_WEIGHTS: [u16; MAX_RUBRIC_DIMENSIONS] = [1, 1, 1, 1, 0, 0, 0, 0];
+
+pub const DEFAULT_RUBRIC_DIMENSIONS: u8 = 4;
+
+pub const DEFAULT_APPROVAL_THRESHOLD: u8 = 50;
+
+pub const MAX_ARBITERS: usize = 5;
+
+// Bond an author posts to challenge a review, 0.1 SOL
+pub const DISPUTE_BOND_LAMPORTS: u64 = 100_000_000;
+
+pub const MAX_EXCLUDED_REVIEWERS: usize = 4;
+
+pub const MAX_PRICE_TIERS: usize = 4;
+
 pub const REPUTATION_CHECKER_ADDR: [u8; 32] = [
     169, 0, 98, 218, 109, 191, 169, 52, 91, 62, 13, 120, 87, 111, 105, 218, 157, 129, 43, 117, 250,
     6, 176, 236, 145, 237, 44, 88, 60, 29, 189, 169,
@@ -41,6 +114,39 @@ pub const REPUTATION_CHECKER_ADDR: [u8; 32] = [
 
 pub const REPUTATION_CHECKER_PUBKEY: Pubkey = Pubkey::new_from_array(REPUTATION_CHECKER_ADDR);
 
+// Governance and the treasury are operated by the reputation checker key for now
+pub const GOVERNANCE_AUTHORITY_PUBKEY: Pubkey = REPUTATION_CHECKER_PUBKEY;
+
+pub const TREASURY_PUBKEY: Pubkey = REPUTATION_CHECKER_PUBKEY;
+
+// Lamports a reviewer locks in the stake vault for every peer review (0.1 SOL)
+pub const REVIEW_STAKE_LAMPORTS: u64 = 100_000_000;
+
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+// Mean distance from the median scores within which a review counts as consensus
+pub const CONSENSUS_TOLERANCE: u8 = 10;
+
+pub const CONSENSUS_REPUTATION_BUMP: u8 = 1;
+
+// Used when a paper is created without its own review window
+pub const DEFAULT_REVIEW_WINDOW_SECS: i64 = 14 * 24 * 60 * 60;
+
+// Blind reviews are committed during the review window and revealed during this one
+pub const REVIEW_REVEAL_WINDOW_SECS: i64 = 3 * 24 * 60 * 60;
+
+// Longest review window a paper can set for itself
+pub const MAX_REVIEW_WINDOW_SECS: i64 = 365 * 24 * 60 * 60;
+
+// Longest a mint payment can be held in escrow for a refund
+pub const MAX_REFUND_WINDOW_SECS: i64 = 30 * 24 * 60 * 60;
+
+// Time arbiters have to resolve a dispute before its bond is refunded
+pub const DISPUTE_RESOLUTION_WINDOW_SECS: i64 = 14 * 24 * 60 * 60;
+
+// Stakes of a paper left waiting for a revision unlock this long after its review closed
+pub const REVISION_STAKE_LOCK_SECS: i64 = 14 * 24 * 60 * 60;
+
 pub fn validate_pda(
     seeds: Vec<&[u8]>,
     pda: &Pubkey,
@@ -61,9 +167,40 @@ pub fn validate_pda(
         .map_err(|_| DeResearcherError::PdaPubekyMismatch)?;
 
     if actual_pda.ne(pda) {
-        return Err(DeResearcherError::PdaPubekyMismatch.into());
+        return Err(DeResearcherError::PdaPubekyMismatch);
+    }
+
+    Ok(())
+}
+
+pub fn transfer_lamports_from_pda(
+    pda_acc: &AccountInfo,
+    destination_acc: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    let pda_lamports = pda_acc.lamports();
+
+    if pda_lamports < amount {
+        return Err(ProgramError::InsufficientFunds);
     }
 
+    **pda_acc.try_borrow_mut_lamports()? = pda_lamports - amount;
+
+    **destination_acc.try_borrow_mut_lamports()? = destination_acc
+        .lamports()
+        .checked_add(amount)
+        .ok_or(DeResearcherError::SizeOverflow)?;
+
+    Ok(())
+}
+
+pub fn close_pda_account(pda_acc: &AccountInfo, destination_acc: &AccountInfo) -> ProgramResult {
+    transfer_lamports_from_pda(pda_acc, destination_acc, pda_acc.lamports())?;
+
+    pda_acc.realloc(0, false)?;
+
+    pda_acc.assign(&system_program::ID);
+
     Ok(())
 }
 
@@ -80,26 +217,220 @@ pub struct CreateResearchePaper {
     pub paper_content_hash: String,
     pub meta_data_merkle_root: String,
     pub pda_bump: u8,
+    pub reviewer_share_bps: u16,
+    pub reviewer_pool_pda_bump: u8,
+    pub blind_review: bool,
+    pub review_window_secs: i64,
+    pub excluded_reviewers: Vec<Pubkey>,
+    pub content_hash_record_pda_bump: u8,
+    pub fee_decay: FeeDecay,
+    pub fee_decay_step_secs: i64,
+    pub fee_decay_step_bps: u16,
+    pub open_access_after_secs: i64,
+    pub pricing_mode: PricingMode,
+    pub price_slope: u32,
+    pub tier_mint_thresholds: Vec<u32>,
+    pub tier_fees: Vec<u32>,
+    pub max_mints: u64,
+    pub reserved_mints: u64,
+    pub rental_fee: u32,
+    pub rental_period_secs: i64,
+    pub refund_window_secs: i64,
+    pub paper_version_pda_bump: u8,
+    pub awaiting_venue: bool,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct PublishPaper {
-    pda_bump: u8,
+    pub pda_bump: u8,
+    pub publish_at: Option<i64>,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct AddPeerReview {
-    pub quality_of_research: u8,
-    pub potential_for_real_world_use_case: u8,
-    pub domain_knowledge: u8,
-    pub practicality_of_result_obtained: u8,
+    pub scores: Vec<u8>,
+    pub meta_data_merkle_root: String,
+    pub pda_bump: u8,
+    pub stake_pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CommitReview {
+    pub commitment: [u8; 32],
+    pub pda_bump: u8,
+    pub stake_pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RevealReview {
+    pub scores: Vec<u8>,
+    pub meta_data_merkle_root: String,
+    pub salt: [u8; 32],
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateJournal {
+    pub name: String,
+    pub submission_fee: u64,
+    pub min_approvals: u8,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateRubric {
+    pub name: String,
+    pub dimension_names: Vec<String>,
+    pub weights: Vec<u16>,
+    pub approval_threshold: u8,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct AppointEditor {
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct InviteReviewer {
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct DeskReject {
+    pub reason_code: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateAuthorResponse {
+    pub meta_data_merkle_root: String,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct AmendPeerReview {
+    pub scores: Vec<u8>,
+    pub meta_data_merkle_root: String,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SetArbiters {
+    pub arbiters: Vec<Pubkey>,
+    pub quorum: u8,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct OpenDispute {
+    pub meta_data_merkle_root: String,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ResolveDispute {
+    pub uphold: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct DeclareConflictOfInterest {
+    pub kind: ConflictKind,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct WithdrawPaper {
+    pub reason_hash: String,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RetractPaper {
+    pub reason_hash: String,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct AppendErratum {
+    pub content_hash: String,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SubmitRevision {
+    pub paper_content_hash: String,
     pub meta_data_merkle_root: String,
     pub pda_bump: u8,
+    pub content_hash_record_pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct FlagDuplicate {
+    pub evidence_hash: String,
+    pub pda_bump: u8,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 pub struct MintResearchPaper {
     pub pda_bump: u8,
+    pub max_price: u64,
+    pub recipient: Option<Pubkey>,
+    pub escrow_pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct GiftResearchPaper {
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateInstitution {
+    pub name: String,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct AddInstitutionMember {
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateInstitutionLicense {
+    pub scope: LicenseScope,
+    pub fee: u64,
+    pub expires_at: i64,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ClaimLicensedPaper {
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CreateSubscriptionPlan {
+    pub scope: SubscriptionScope,
+    pub price_per_period: u64,
+    pub period_secs: i64,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Subscribe {
+    pub periods: u32,
+    pub pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct VerifyAccess {
+    pub proof: AccessProof,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct RentResearchPaper {
+    pub pda_bump: u8,
+    pub max_price: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ExtendRental {
+    pub max_price: u64,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
@@ -107,6 +438,17 @@ pub struct CheckAndAssignReputation {
     pub reputation: u8,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct MigrateResearchPaper {
+    pub reviewer_pool_pda_bump: u8,
+    pub paper_version_pda_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct SlashReviewStake {
+    pub slash_bps: u16,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Debug, ShankInstruction)]
 pub enum DeResearcherInstruction {
     #[account(
@@ -138,7 +480,25 @@ pub enum DeResearcherInstruction {
         desc = "Researcher's profile PDA account"
     )]
     #[account(2, writable, name = "paper_pda_acc", desc = "Research paper account")]
-    #[account(3, name = "system_program_acc", desc = "System program account")]
+    #[account(
+        3,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer reward pool PDA account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "content_hash_record_pda_acc",
+        desc = "Content hash record PDA account, fails if the hash is already registered"
+    )]
+    #[account(
+        5,
+        writable,
+        name = "paper_version_pda_acc",
+        desc = "Paper version PDA account for version 0"
+    )]
+    #[account(6, name = "system_program_acc", desc = "System program account")]
     CreateResearchePaper(CreateResearchePaper),
     #[account(
         0,
@@ -153,6 +513,12 @@ pub enum DeResearcherInstruction {
         name = "paper_pda_acc",
         desc = "Research paper PDA account"
     )]
+    #[account(
+        2,
+        writable,
+        name = "paper_version_pda_acc",
+        desc = "Paper version PDA account for the paper's current version"
+    )]
     PublishPaper(PublishPaper),
     #[account(
         0,
@@ -179,14 +545,30 @@ pub enum DeResearcherInstruction {
         name = "peer_review_pda_acc",
         desc = "Peer review PDA account"
     )]
-    #[account(4, name = "system_program_acc", desc = "System program account")]
+    #[account(
+        4,
+        writable,
+        name = "review_stake_pda_acc",
+        desc = "Review stake vault PDA account"
+    )]
+    #[account(
+        5,
+        name = "review_invitation_pda_acc",
+        desc = "Review invitation PDA account, required when the paper has a venue"
+    )]
+    #[account(
+        6,
+        name = "conflict_of_interest_pda_acc",
+        desc = "Conflict of interest PDA between the reviewer and the author, must not bar the reviewer"
+    )]
+    #[account(7, name = "system_program_acc", desc = "System program account")]
     AddPeerReview(AddPeerReview),
     #[account(
         0,
         writable,
         signer,
         name = "researcher_acc",
-        desc = "Researcher's account"
+        desc = "Researcher paying for the mint"
     )]
     #[account(
         1,
@@ -198,7 +580,7 @@ pub enum DeResearcherInstruction {
         2,
         writable,
         name = "research_token_pda_account",
-        desc = "Research token PDA account"
+        desc = "Research token PDA account of the recipient"
     )]
     #[account(
         3,
@@ -212,7 +594,20 @@ pub enum DeResearcherInstruction {
         name = "fee_receiver_acc",
         desc = "Fee receiver's account"
     )]
-    #[account(5, name = "system_program_acc", desc = "System program account")]
+    #[account(
+        5,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer reward pool PDA account"
+    )]
+    #[account(6, name = "system_program_acc", desc = "System program account")]
+    #[account(
+        7,
+        optional,
+        writable,
+        name = "mint_escrow_pda_acc",
+        desc = "Mint escrow PDA account, required when the paper has a refund window"
+    )]
     MintResearchPaper(MintResearchPaper),
     #[account(
         0,
@@ -228,552 +623,6717 @@ pub enum DeResearcherInstruction {
         desc = "Researcher's profile account"
     )]
     CheckAndAssignReputation(CheckAndAssignReputation),
-}
-
-fn validate_create_researcher_profile_accounts(
-    researcher_acc: &AccountInfo,
-    researcher_profile_pda_acc: &AccountInfo,
-) -> Result<(), DeResearcherError> {
-    if !researcher_acc.is_signer {
-        return Err(DeResearcherError::InvalidSigner);
-    }
-
-    if !researcher_profile_pda_acc.data_is_empty() {
-        return Err(DeResearcherError::ResearcherProfileAlreadyExists);
-    }
-
-    if !researcher_profile_pda_acc.is_writable {
-        return Err(DeResearcherError::ImmutableAccount);
-    }
-
-    Ok(())
-}
-
-// Create a new researcher profile
-
-pub fn create_researcher_profile_ix(
-    program_id: &Pubkey,
-    accounts: &[AccountInfo],
-    data: CreateResearcherProfile,
-) -> ProgramResult {
-    msg!("Instruction: CreateResearcherProfile");
-    let accounts_iter = &mut accounts.iter();
-    let researcher_acc = next_account_info(accounts_iter)?;
-    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
-    let system_program_acc = next_account_info(accounts_iter)?;
-
-    let researcher_profile_pda = researcher_profile_pda_acc.key;
-
-    let seeds: Vec<&[u8]> = vec![RESEARCHER_PROFILE_PDA_SEED, researcher_acc.key.as_ref()];
-
-    validate_pda(seeds, researcher_profile_pda, data.pda_bump, program_id)?;
-
-    validate_create_researcher_profile_accounts(researcher_acc, researcher_profile_pda_acc)?;
-
-    let rent = Rent::get()?;
-
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "reviewer_acc",
+        desc = "Reviewer's account"
+    )]
+    #[account(1, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(2, name = "peer_review_pda_acc", desc = "Peer review PDA account")]
+    #[account(
+        3,
+        writable,
+        name = "review_stake_pda_acc",
+        desc = "Review stake vault PDA account"
+    )]
+    #[account(
+        4,
+        name = "dispute_pda_acc",
+        desc = "Dispute PDA account of the peer review, empty if never disputed"
+    )]
+    #[account(
+        5,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer reward pool PDA account"
+    )]
+    ReleaseReviewStake,
+    #[account(
+        0,
+        signer,
+        name = "slasher_acc",
+        desc = "Governance authority's or an arbiter's account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "review_stake_pda_acc",
+        desc = "Review stake vault PDA account"
+    )]
+    #[account(2, writable, name = "treasury_acc", desc = "Treasury account")]
+    #[account(
+        3,
+        name = "arbiter_set_pda_acc",
+        desc = "Arbiter set PDA account, checked when an arbiter slashes"
+    )]
+    SlashReviewStake(SlashReviewStake),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "reviewer_acc",
+        desc = "Reviewer's account"
+    )]
+    #[account(1, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(
+        2,
+        writable,
+        name = "peer_review_pda_acc",
+        desc = "Peer review PDA account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer reward pool PDA account"
+    )]
+    ClaimReviewerReward,
+    #[account(0, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(
+        1,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer reward pool PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "peer_review_pda_acc",
+        desc = "Peer review PDA account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "researcher_profile_pda_acc",
+        desc = "Reviewer's profile PDA account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "paper_version_pda_acc",
+        desc = "Paper version PDA account for the paper's current version"
+    )]
+    FinalizeReviews,
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "reviewer_acc",
+        desc = "Reviewer's account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "researcher_profile_pda_acc",
+        desc = "Researcher's profile PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "peer_review_pda_acc",
+        desc = "Peer review PDA account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "review_stake_pda_acc",
+        desc = "Review stake vault PDA account"
+    )]
+    #[account(
+        5,
+        name = "review_invitation_pda_acc",
+        desc = "Review invitation PDA account, required when the paper has a venue"
+    )]
+    #[account(
+        6,
+        name = "conflict_of_interest_pda_acc",
+        desc = "Conflict of interest PDA between the reviewer and the author, must not bar the reviewer"
+    )]
+    #[account(7, name = "system_program_acc", desc = "System program account")]
+    CommitReview(CommitReview),
+    #[account(0, signer, name = "reviewer_acc", desc = "Reviewer's account")]
+    #[account(
+        1,
+        writable,
+        name = "researcher_profile_pda_acc",
+        desc = "Researcher's profile PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "peer_review_pda_acc",
+        desc = "Peer review PDA account"
+    )]
+    RevealReview(RevealReview),
+    #[account(
+        0,
+        writable,
+        name = "reviewer_acc",
+        desc = "Reviewer's account, receives the reclaimed rent"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "peer_review_pda_acc",
+        desc = "Peer review PDA account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "review_stake_pda_acc",
+        desc = "Review stake vault PDA account"
+    )]
+    #[account(4, writable, name = "treasury_acc", desc = "Treasury account")]
+    ExpireReviewCommit,
+    #[account(
+        0,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    CrankPaperDeadline,
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "authority_acc",
+        desc = "Journal authority's account"
+    )]
+    #[account(1, writable, name = "journal_pda_acc", desc = "Journal PDA account")]
+    #[account(2, name = "editor_acc", desc = "Editor's account")]
+    #[account(3, writable, name = "editor_pda_acc", desc = "Editor PDA account")]
+    #[account(4, name = "system_program_acc", desc = "System program account")]
+    AppointEditor(AppointEditor),
+    #[account(0, writable, signer, name = "editor_acc", desc = "Editor's account")]
+    #[account(1, name = "editor_pda_acc", desc = "Editor PDA account")]
+    #[account(2, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(3, name = "reviewer_acc", desc = "Invited reviewer's account")]
+    #[account(
+        4,
+        writable,
+        name = "review_invitation_pda_acc",
+        desc = "Review invitation PDA account"
+    )]
+    #[account(
+        5,
+        name = "conflict_of_interest_pda_acc",
+        desc = "Conflict of interest PDA between the editor and the author, must not bar the editor"
+    )]
+    #[account(6, name = "system_program_acc", desc = "System program account")]
+    InviteReviewer(InviteReviewer),
+    #[account(0, signer, name = "editor_acc", desc = "Editor's account")]
+    #[account(1, name = "editor_pda_acc", desc = "Editor PDA account")]
+    #[account(
+        2,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        3,
+        name = "conflict_of_interest_pda_acc",
+        desc = "Conflict of interest PDA between the editor and the author, must not bar the editor"
+    )]
+    DeskReject(DeskReject),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "authority_acc",
+        desc = "Journal authority's account"
+    )]
+    #[account(1, writable, name = "journal_pda_acc", desc = "Journal PDA account")]
+    #[account(
+        2,
+        name = "rubric_pda_acc",
+        desc = "Rubric PDA account used to score submissions"
+    )]
+    #[account(3, name = "system_program_acc", desc = "System program account")]
+    CreateJournal(CreateJournal),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "publisher_acc",
+        desc = "Publisher's account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(2, writable, name = "journal_pda_acc", desc = "Journal PDA account")]
+    #[account(3, name = "rubric_pda_acc", desc = "Journal's rubric PDA account")]
+    #[account(
+        4,
+        writable,
+        name = "journal_authority_acc",
+        desc = "Journal authority's account, receives the submission fee"
+    )]
+    #[account(5, name = "system_program_acc", desc = "System program account")]
+    SubmitToJournal,
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "authority_acc",
+        desc = "Rubric authority's account"
+    )]
+    #[account(1, writable, name = "rubric_pda_acc", desc = "Rubric PDA account")]
+    #[account(2, name = "system_program_acc", desc = "System program account")]
+    CreateRubric(CreateRubric),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "author_acc",
+        desc = "Paper author's account"
+    )]
+    #[account(1, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(2, name = "peer_review_pda_acc", desc = "Peer review PDA account")]
+    #[account(
+        3,
+        writable,
+        name = "author_response_pda_acc",
+        desc = "Author response PDA account"
+    )]
+    #[account(4, name = "system_program_acc", desc = "System program account")]
+    CreateAuthorResponse(CreateAuthorResponse),
+    #[account(0, signer, name = "reviewer_acc", desc = "Reviewer's account")]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "peer_review_pda_acc",
+        desc = "Peer review PDA account"
+    )]
+    #[account(
+        3,
+        name = "author_response_pda_acc",
+        desc = "Author response PDA account"
+    )]
+    AmendPeerReview(AmendPeerReview),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "governance_acc",
+        desc = "Governance authority's account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "arbiter_set_pda_acc",
+        desc = "Arbiter set PDA account"
+    )]
+    #[account(2, name = "system_program_acc", desc = "System program account")]
+    SetArbiters(SetArbiters),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "author_acc",
+        desc = "Paper author's account, posts the bond"
+    )]
+    #[account(1, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(
+        2,
+        name = "peer_review_pda_acc",
+        desc = "Disputed peer review PDA account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "dispute_pda_acc",
+        desc = "Dispute PDA account, holds the bond"
+    )]
+    #[account(
+        4,
+        name = "arbiter_set_pda_acc",
+        desc = "Arbiter set PDA account, copied into the dispute"
+    )]
+    #[account(5, name = "system_program_acc", desc = "System program account")]
+    OpenDispute(OpenDispute),
+    #[account(0, signer, name = "arbiter_acc", desc = "Arbiter's account")]
+    #[account(1, writable, name = "dispute_pda_acc", desc = "Dispute PDA account")]
+    #[account(
+        2,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "peer_review_pda_acc",
+        desc = "Disputed peer review PDA account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "author_acc",
+        desc = "Paper author's account, refunded the bond if the dispute is upheld"
+    )]
+    #[account(
+        5,
+        writable,
+        name = "reviewer_acc",
+        desc = "Reviewer's account, paid the bond if the dispute is rejected"
+    )]
+    #[account(
+        6,
+        writable,
+        name = "review_stake_pda_acc",
+        desc = "Review stake vault PDA account, forfeited if the dispute is upheld"
+    )]
+    #[account(7, writable, name = "treasury_acc", desc = "Treasury account")]
+    #[account(
+        8,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer pool PDA account, loses the review's consensus weight if upheld"
+    )]
+    ResolveDispute(ResolveDispute),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "declarer_acc",
+        desc = "Researcher declaring the conflict"
+    )]
+    #[account(
+        1,
+        name = "counterparty_acc",
+        desc = "Researcher the declarer is conflicted with, signs to make the conflict mutual"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "conflict_of_interest_pda_acc",
+        desc = "Conflict of interest PDA account"
+    )]
+    #[account(3, name = "system_program_acc", desc = "System program account")]
+    DeclareConflictOfInterest(DeclareConflictOfInterest),
+    #[account(0, signer, name = "publisher_acc", desc = "Publisher's account")]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "paper_version_pda_acc",
+        desc = "Paper version PDA account for the paper's current version"
+    )]
+    WithdrawPaper(WithdrawPaper),
+    #[account(
+        0,
+        signer,
+        name = "governance_acc",
+        desc = "Governance authority's account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "paper_version_pda_acc",
+        desc = "Paper version PDA account for the paper's current version"
+    )]
+    RetractPaper(RetractPaper),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "publisher_acc",
+        desc = "Publisher's account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "erratum_pda_acc",
+        desc = "Erratum PDA account for the paper's next erratum index"
+    )]
+    #[account(3, name = "system_program_acc", desc = "System program account")]
+    AppendErratum(AppendErratum),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "publisher_acc",
+        desc = "Publisher's account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "paper_version_pda_acc",
+        desc = "Paper version PDA account for the version being superseded"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "new_paper_version_pda_acc",
+        desc = "Paper version PDA account for the revised version"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer reward pool PDA account"
+    )]
+    #[account(
+        5,
+        writable,
+        name = "content_hash_record_pda_acc",
+        desc = "Content hash record PDA account of the revised content, fails if already registered"
+    )]
+    #[account(6, name = "system_program_acc", desc = "System program account")]
+    SubmitRevision(SubmitRevision),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "author_acc",
+        desc = "Author of the original paper"
+    )]
+    #[account(
+        1,
+        name = "original_paper_pda_acc",
+        desc = "Author's research paper PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "suspect_paper_pda_acc",
+        desc = "Research paper PDA account suspected of duplicating the original"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "duplicate_flag_pda_acc",
+        desc = "Duplicate flag PDA account"
+    )]
+    #[account(4, name = "system_program_acc", desc = "System program account")]
+    FlagDuplicate(FlagDuplicate),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "author_acc",
+        desc = "Author gifting the edition"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "researcher_profile_pda_acc",
+        desc = "Author's researcher profile PDA account"
+    )]
+    #[account(
+        2,
+        name = "recipient_acc",
+        desc = "Account receiving the gifted edition"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "research_token_pda_acc",
+        desc = "Recipient's research token PDA account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(5, name = "system_program_acc", desc = "System program account")]
+    GiftResearchPaper(GiftResearchPaper),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "admin_acc",
+        desc = "Institution's admin account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "institution_pda_acc",
+        desc = "Institution PDA account"
+    )]
+    #[account(2, name = "system_program_acc", desc = "System program account")]
+    CreateInstitution(CreateInstitution),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "admin_acc",
+        desc = "Institution's admin account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "institution_pda_acc",
+        desc = "Institution PDA account"
+    )]
+    #[account(2, name = "member_acc", desc = "Account joining the institution")]
+    #[account(
+        3,
+        writable,
+        name = "institution_member_pda_acc",
+        desc = "Institution member PDA account"
+    )]
+    #[account(4, name = "system_program_acc", desc = "System program account")]
+    AddInstitutionMember(AddInstitutionMember),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "admin_acc",
+        desc = "Institution's admin account, receives the member account's rent"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "institution_pda_acc",
+        desc = "Institution PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "institution_member_pda_acc",
+        desc = "Institution member PDA account"
+    )]
+    RemoveInstitutionMember,
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "admin_acc",
+        desc = "Institution's admin account paying for the license"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "institution_pda_acc",
+        desc = "Institution PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        signer,
+        name = "licensor_acc",
+        desc = "Creator of the paper or authority of the journal being licensed"
+    )]
+    #[account(
+        3,
+        name = "licensed_pda_acc",
+        desc = "Research paper or journal PDA account being licensed"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "institution_license_pda_acc",
+        desc = "Institution license PDA account"
+    )]
+    #[account(5, name = "system_program_acc", desc = "System program account")]
+    CreateInstitutionLicense(CreateInstitutionLicense),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "member_acc",
+        desc = "Institution member claiming the paper"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "research_token_pda_acc",
+        desc = "Member's research token PDA account"
+    )]
+    #[account(2, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(
+        3,
+        name = "institution_member_pda_acc",
+        desc = "Member's institution member PDA account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "institution_license_pda_acc",
+        desc = "Institution license covering the paper or its journal"
+    )]
+    #[account(5, name = "system_program_acc", desc = "System program account")]
+    ClaimLicensedPaper(ClaimLicensedPaper),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "owner_acc",
+        desc = "Journal authority or author offering the subscription"
+    )]
+    #[account(
+        1,
+        name = "target_acc",
+        desc = "Journal PDA account, or the author's own account for author subscriptions"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "subscription_plan_pda_acc",
+        desc = "Subscription plan PDA account"
+    )]
+    #[account(3, name = "system_program_acc", desc = "System program account")]
+    CreateSubscriptionPlan(CreateSubscriptionPlan),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "subscriber_acc",
+        desc = "Subscriber's account"
+    )]
+    #[account(
+        1,
+        name = "subscription_plan_pda_acc",
+        desc = "Subscription plan PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "plan_owner_acc",
+        desc = "Owner of the subscription plan receiving the payment"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "subscription_pda_acc",
+        desc = "Subscriber's subscription PDA account, created or renewed"
+    )]
+    #[account(4, name = "system_program_acc", desc = "System program account")]
+    Subscribe(Subscribe),
+    #[account(0, name = "reader_acc", desc = "Reader whose access is verified")]
+    #[account(1, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(
+        2,
+        name = "access_pda_acc",
+        desc = "Reader's research token or subscription PDA account"
+    )]
+    VerifyAccess(VerifyAccess),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "researcher_acc",
+        desc = "Researcher renting the paper"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "research_token_pda_acc",
+        desc = "Researcher's research token PDA account"
+    )]
+    #[account(2, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(
+        3,
+        writable,
+        name = "fee_receiver_acc",
+        desc = "Fee receiver's account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer reward pool PDA account"
+    )]
+    #[account(5, name = "system_program_acc", desc = "System program account")]
+    RentResearchPaper(RentResearchPaper),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "researcher_acc",
+        desc = "Holder of the rental"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "research_token_pda_acc",
+        desc = "Holder's rental research token PDA account"
+    )]
+    #[account(2, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(
+        3,
+        writable,
+        name = "fee_receiver_acc",
+        desc = "Fee receiver's account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer reward pool PDA account"
+    )]
+    #[account(5, name = "system_program_acc", desc = "System program account")]
+    ExtendRental(ExtendRental),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "researcher_acc",
+        desc = "Holder of the rental, receives the rent"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "research_token_pda_acc",
+        desc = "Holder's expired rental research token PDA account"
+    )]
+    CloseRental,
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "buyer_acc",
+        desc = "Account that paid for the mint"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "research_token_pda_acc",
+        desc = "Research token PDA account bought with the escrowed payment"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "mint_escrow_pda_acc",
+        desc = "Mint escrow PDA account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "researcher_profile_pda_acc",
+        desc = "Profile PDA account credited with the mint's citation"
+    )]
+    RequestRefund,
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "author_acc",
+        desc = "Author collecting the payment"
+    )]
+    #[account(1, name = "paper_pda_acc", desc = "Research paper PDA account")]
+    #[account(
+        2,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer reward pool PDA account"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "buyer_acc",
+        desc = "Account that paid for the mint, receives the escrow's rent"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "mint_escrow_pda_acc",
+        desc = "Mint escrow PDA account"
+    )]
+    ReleaseEscrow,
+    #[account(0, signer, name = "publisher_acc", desc = "Publisher's account")]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account"
+    )]
+    SkipVenueSubmission,
+    #[account(0, writable, name = "dispute_pda_acc", desc = "Dispute PDA account")]
+    #[account(
+        1,
+        writable,
+        name = "author_acc",
+        desc = "Paper author's account, refunded the bond"
+    )]
+    ExpireDispute,
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "publisher_acc",
+        desc = "Paper creator's account, pays for the larger account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Research paper PDA account in the legacy layout"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "reviewer_pool_pda_acc",
+        desc = "Reviewer pool PDA account, created for the paper"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "paper_version_pda_acc",
+        desc = "Paper version PDA account for version 0, created for the paper"
+    )]
+    #[account(4, name = "system_program_acc", desc = "System program account")]
+    MigrateResearchPaper(MigrateResearchPaper),
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "payer_acc",
+        desc = "Account paying for the larger account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "paper_pda_acc",
+        desc = "Migrated research paper PDA account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "peer_review_pda_acc",
+        desc = "Peer review PDA account in the legacy layout"
+    )]
+    #[account(3, name = "system_program_acc", desc = "System program account")]
+    MigratePeerReview,
+}
+
+fn validate_create_researcher_profile_accounts(
+    researcher_acc: &AccountInfo,
+    researcher_profile_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !researcher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !researcher_profile_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearcherProfileAlreadyExists);
+    }
+
+    if !researcher_profile_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Create a new researcher profile
+
+pub fn create_researcher_profile_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: CreateResearcherProfile,
+) -> ProgramResult {
+    msg!("Instruction: CreateResearcherProfile");
+    let accounts_iter = &mut accounts.iter();
+    let researcher_acc = next_account_info(accounts_iter)?;
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda = researcher_profile_pda_acc.key;
+
+    let seeds: Vec<&[u8]> = vec![RESEARCHER_PROFILE_PDA_SEED, researcher_acc.key.as_ref()];
+
+    validate_pda(seeds, researcher_profile_pda, data.pda_bump, program_id)?;
+
+    validate_create_researcher_profile_accounts(researcher_acc, researcher_profile_pda_acc)?;
+
+    let rent = Rent::get()?;
+
     let rent_exempt = rent.minimum_balance(ResearcherProfile::size());
 
-    let create_researcher_profile_ix = system_instruction::create_account(
+    let create_researcher_profile_ix = system_instruction::create_account(
+        researcher_acc.key,
+        researcher_profile_pda_acc.key,
+        rent_exempt,
+        ResearcherProfile::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_researcher_profile_ix,
+        &[
+            researcher_acc.clone(),
+            researcher_profile_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            RESEARCHER_PROFILE_PDA_SEED,
+            researcher_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    ResearcherProfile::create_new(researcher_profile_pda_acc, researcher_acc.key, data)?;
+
+    Ok(())
+}
+
+fn validate_create_research_paper_accounts(
+    publisher_acc: &AccountInfo,
+    researcher_profile_pda_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    reviewer_pool_pda_acc: &AccountInfo,
+    content_hash_record_pda_acc: &AccountInfo,
+    paper_version_pda_acc: &AccountInfo,
+    data: &CreateResearchePaper,
+) -> Result<(), DeResearcherError> {
+    if !publisher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if researcher_profile_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearcherProfileNotFound);
+    }
+
+    if !paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperAlreadyExists);
+    }
+
+    if !reviewer_pool_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewerPoolAlreadyExists);
+    }
+
+    if !content_hash_record_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::DuplicateContentHash);
+    }
+
+    if !paper_version_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperVersionAlreadyExists);
+    }
+
+    if data.reviewer_share_bps > MAX_BASIS_POINTS {
+        return Err(DeResearcherError::InvalidReviewerShare);
+    }
+
+    if data.review_window_secs < 0 || data.review_window_secs > MAX_REVIEW_WINDOW_SECS {
+        return Err(DeResearcherError::InvalidReviewWindow);
+    }
+
+    let is_valid_fee_decay = match data.fee_decay {
+        FeeDecay::None => true,
+        FeeDecay::Linear => data.open_access_after_secs > 0,
+        FeeDecay::Step => {
+            data.fee_decay_step_secs > 0 && data.fee_decay_step_bps <= MAX_BASIS_POINTS
+        }
+    };
+
+    if !is_valid_fee_decay || data.open_access_after_secs < 0 {
+        return Err(DeResearcherError::InvalidFeeSchedule);
+    }
+
+    // Tiers apply from their mint threshold onwards, so thresholds have to be increasing,
+    // a zero threshold marks an unused tier slot
+
+    let is_valid_tiers = data.tier_mint_thresholds.len() <= MAX_PRICE_TIERS
+        && data.tier_mint_thresholds.len() == data.tier_fees.len();
+
+    let is_valid_pricing = is_valid_tiers
+        && match data.pricing_mode {
+            PricingMode::Flat | PricingMode::LinearBondingCurve => {
+                data.tier_mint_thresholds.is_empty()
+            }
+            PricingMode::VolumeTiers => {
+                !data.tier_mint_thresholds.is_empty()
+                    && data.tier_mint_thresholds[0] > 0
+                    && data
+                        .tier_mint_thresholds
+                        .windows(2)
+                        .all(|pair| pair[0] < pair[1])
+            }
+        };
+
+    if !is_valid_pricing {
+        return Err(DeResearcherError::InvalidPricing);
+    }
+
+    if data.max_mints > 0 && data.reserved_mints > data.max_mints {
+        return Err(DeResearcherError::InvalidEditionSupply);
+    }
+
+    if data.rental_period_secs < 0 {
+        return Err(DeResearcherError::InvalidFeeSchedule);
+    }
+
+    if data.refund_window_secs < 0 || data.refund_window_secs > MAX_REFUND_WINDOW_SECS {
+        return Err(DeResearcherError::InvalidRefundWindow);
+    }
+
+    Ok(())
+}
+
+// Create a new research paper
+
+pub fn create_research_paper_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: CreateResearchePaper,
+) -> ProgramResult {
+    msg!("Instruction: CreateResearchePaper");
+    let accounts_iter = &mut accounts.iter();
+
+    let publisher_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
+
+    let content_hash_record_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_version_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda = paper_pda_acc.key;
+
+    let paper_seeds: Vec<&[u8]> = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        data.paper_content_hash[..32].as_ref(),
+        publisher_acc.key.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda, data.pda_bump, program_id)?;
+
+    let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda.as_ref()];
+
+    validate_pda(
+        reviewer_pool_seeds,
+        reviewer_pool_pda_acc.key,
+        data.reviewer_pool_pda_bump,
+        program_id,
+    )?;
+
+    let first_version = [0];
+
+    let paper_version_seeds = vec![
+        PAPER_VERSION_PDA_SEED,
+        paper_pda.as_ref(),
+        first_version.as_ref(),
+    ];
+
+    validate_pda(
+        paper_version_seeds,
+        paper_version_pda_acc.key,
+        data.paper_version_pda_bump,
+        program_id,
+    )?;
+
+    let researcher_profile_seeds = vec![RESEARCHER_PROFILE_PDA_SEED, publisher_acc.key.as_ref()];
+
+    let researcher_profile_pda = researcher_profile_pda_acc.key;
+
+    let researcher_profile =
+        ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.data.borrow())?;
+
+    validate_pda(
+        researcher_profile_seeds,
+        researcher_profile_pda,
+        researcher_profile.bump,
+        program_id,
+    )?;
+
+    // The record is seeded only by the content hash, and only by its canonical bump,
+    // so a second paper with the same content can't be created by any publisher
+
+    let content_hash_bytes = checked_string_convt_to_64_bytes(&data.paper_content_hash)?;
+
+    let content_hash_record_seeds = vec![
+        CONTENT_HASH_RECORD_PDA_SEED,
+        content_hash_bytes[..32].as_ref(),
+        content_hash_bytes[32..].as_ref(),
+    ];
+
+    let (content_hash_record_pda, content_hash_record_bump) =
+        Pubkey::find_program_address(&content_hash_record_seeds, program_id);
+
+    if content_hash_record_pda.ne(content_hash_record_pda_acc.key)
+        || content_hash_record_bump != data.content_hash_record_pda_bump
+    {
+        return Err(DeResearcherError::PdaPubekyMismatch.into());
+    }
+
+    validate_create_research_paper_accounts(
+        publisher_acc,
+        researcher_profile_pda_acc,
+        paper_pda_acc,
+        reviewer_pool_pda_acc,
+        content_hash_record_pda_acc,
+        paper_version_pda_acc,
+        &data,
+    )?;
+
+    let rent = Rent::get()?;
+
+    let rent_exempt = rent.minimum_balance(ResearchPaper::size());
+
+    let create_researche_paper_ix = system_instruction::create_account(
+        publisher_acc.key,
+        paper_pda_acc.key,
+        rent_exempt,
+        ResearchPaper::size() as u64,
+        program_id,
+    );
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    invoke_signed(
+        &create_researche_paper_ix,
+        &[
+            publisher_acc.clone(),
+            paper_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            RESEARCH_PAPER_PDA_SEED,
+            data.paper_content_hash[..32].as_ref(),
+            publisher_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    let create_reviewer_pool_ix = system_instruction::create_account(
+        publisher_acc.key,
+        reviewer_pool_pda_acc.key,
+        rent.minimum_balance(ReviewerPool::size()),
+        ReviewerPool::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_reviewer_pool_ix,
+        &[
+            publisher_acc.clone(),
+            reviewer_pool_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            REVIEWER_POOL_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            &[data.reviewer_pool_pda_bump],
+        ]],
+    )?;
+
+    ReviewerPool::create_new(
+        reviewer_pool_pda_acc,
+        paper_pda_acc,
+        data.reviewer_pool_pda_bump,
+    )?;
+
+    let create_content_hash_record_ix = system_instruction::create_account(
+        publisher_acc.key,
+        content_hash_record_pda_acc.key,
+        rent.minimum_balance(ContentHashRecord::size()),
+        ContentHashRecord::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_content_hash_record_ix,
+        &[
+            publisher_acc.clone(),
+            content_hash_record_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            CONTENT_HASH_RECORD_PDA_SEED,
+            content_hash_bytes[..32].as_ref(),
+            content_hash_bytes[32..].as_ref(),
+            &[data.content_hash_record_pda_bump],
+        ]],
+    )?;
+
+    ContentHashRecord::create_new(
+        content_hash_record_pda_acc,
+        paper_pda_acc,
+        publisher_acc,
+        content_hash_bytes,
+        data.content_hash_record_pda_bump,
+    )?;
+
+    let create_paper_version_ix = system_instruction::create_account(
+        publisher_acc.key,
+        paper_version_pda_acc.key,
+        rent.minimum_balance(PaperVersion::size()),
+        PaperVersion::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_paper_version_ix,
+        &[
+            publisher_acc.clone(),
+            paper_version_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            PAPER_VERSION_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            first_version.as_ref(),
+            &[data.paper_version_pda_bump],
+        ]],
+    )?;
+
+    PaperVersion::create_new(
+        paper_version_pda_acc,
+        paper_pda_acc,
+        0,
+        content_hash_bytes,
+        checked_string_convt_to_64_bytes(&data.meta_data_merkle_root)?,
+        data.paper_version_pda_bump,
+    )?;
+
+    ResearchPaper::create_new(
+        paper_pda_acc,
+        researcher_profile_pda_acc,
+        publisher_acc,
+        data,
+    )?;
+    Ok(())
+}
+
+fn validate_publish_paper_accounts(
+    publisher_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    paper_pda: &Pubkey,
+) -> Result<(), DeResearcherError> {
+    if !publisher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if paper_pda.ne(paper_pda_acc.key) {
+        return Err(DeResearcherError::PubkeyMismatch);
+    }
+
+    Ok(())
+}
+
+// Publish a research paper
+
+pub fn publish_paper_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: PublishPaper,
+) -> ProgramResult {
+    msg!("Instruction: PublishPaper");
+    let accounts_iter = &mut accounts.iter();
+
+    let publisher_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_version_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda = paper_pda_acc.key;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let seeds: Vec<&[u8]> = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        publisher_acc.key.as_ref(),
+    ];
+
+    validate_pda(seeds, paper_pda, data.pda_bump, program_id)?;
+    validate_publish_paper_accounts(publisher_acc, paper_pda_acc, paper_pda)?;
+
+    validate_paper_version_pda(
+        program_id,
+        paper_pda_acc,
+        paper_version_pda_acc,
+        paper.version,
+    )?;
+
+    ResearchPaper::publish_paper(
+        paper_pda_acc,
+        publisher_acc,
+        data.publish_at,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    record_final_approvals(paper_pda_acc, paper_version_pda_acc)?;
+
+    Ok(())
+}
+
+// The version a paper ends on keeps the approvals it had at the end, whether or not its
+// reviews were ever finalized
+
+fn record_final_approvals(
+    paper_pda_acc: &AccountInfo,
+    paper_version_pda_acc: &AccountInfo,
+) -> ProgramResult {
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    PaperVersion::record_approvals(paper_version_pda_acc, &paper, 0)
+}
+
+fn validate_add_peer_review_accounts(
+    reviewer_acc: &AccountInfo,
+    researcher_profile_pda_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
+    review_stake_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !reviewer_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if researcher_profile_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearcherProfileNotFound);
+    }
+
+    if !peer_review_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PeerReviewAlreadyExists);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !peer_review_pda_acc.is_writable || !review_stake_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+fn validate_researcher_for_peer_review(
+    _researcher_profile: &ResearcherProfile,
+) -> Result<(), DeResearcherError> {
+    // TODO: add this check
+    // if researcher_profile.state != ResearcherProfileState::Approved {
+    //     return Err(DeResearcherError::NotAllowedForPeerReview);
+    // }
+
+    Ok(())
+}
+
+// Add a peer review to a research paper
+
+pub fn add_peer_review_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: AddPeerReview,
+) -> ProgramResult {
+    msg!("Instruction: AddPeerReview");
+    let accounts_iter = &mut accounts.iter();
+
+    let reviewer_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let review_stake_pda_acc = next_account_info(accounts_iter)?;
+
+    let review_invitation_pda_acc = next_account_info(accounts_iter)?;
+
+    let conflict_of_interest_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    if paper.blind_review {
+        return Err(DeResearcherError::BlindReviewRequired.into());
+    }
+
+    paper.validate_scores(&data.scores)?;
+
+    create_peer_review_accounts(
+        program_id,
+        reviewer_acc,
+        researcher_profile_pda_acc,
+        paper_pda_acc,
+        peer_review_pda_acc,
+        review_stake_pda_acc,
+        review_invitation_pda_acc,
+        conflict_of_interest_pda_acc,
+        system_program_acc,
+        &paper,
+        data.pda_bump,
+        data.stake_pda_bump,
+    )?;
+
+    PeerReview::create_new(
+        peer_review_pda_acc,
+        reviewer_acc,
+        paper_pda_acc,
+        researcher_profile_pda_acc,
+        data,
+    )?;
+
+    Ok(())
+}
+
+// Validate a reviewer and create the peer review account along with its stake vault
+
+#[allow(clippy::too_many_arguments)]
+fn create_peer_review_accounts<'a>(
+    program_id: &Pubkey,
+    reviewer_acc: &AccountInfo<'a>,
+    researcher_profile_pda_acc: &AccountInfo<'a>,
+    paper_pda_acc: &AccountInfo<'a>,
+    peer_review_pda_acc: &AccountInfo<'a>,
+    review_stake_pda_acc: &AccountInfo<'a>,
+    review_invitation_pda_acc: &AccountInfo<'a>,
+    conflict_of_interest_pda_acc: &AccountInfo<'a>,
+    system_program_acc: &AccountInfo<'a>,
+    paper: &ResearchPaper,
+    pda_bump: u8,
+    stake_pda_bump: u8,
+) -> ProgramResult {
+    let researcher_profile_pda = researcher_profile_pda_acc.key;
+
+    let paper_pda = paper_pda_acc.key;
+
+    let researcher_profile =
+        ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.data.borrow())?;
+
+    validate_researcher_for_peer_review(&researcher_profile)?;
+
+    if !paper.state.is_under_review() {
+        return Err(DeResearcherError::InvalidState.into());
+    }
+
+    // A paper headed for a venue is only reviewed once it is submitted there
+
+    if paper.awaiting_venue {
+        return Err(DeResearcherError::VenueSubmissionPending.into());
+    }
+
+    let peer_review_pda = peer_review_pda_acc.key;
+
+    let review_version = [paper.version];
+
+    let peer_review_seeds = vec![
+        PEER_REVIEW_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        reviewer_acc.key.as_ref(),
+        review_version.as_ref(),
+    ];
+
+    validate_pda(peer_review_seeds, peer_review_pda, pda_bump, program_id)?;
+
+    let review_stake_seeds = vec![REVIEW_STAKE_PDA_SEED, peer_review_pda.as_ref()];
+
+    validate_pda(
+        review_stake_seeds,
+        review_stake_pda_acc.key,
+        stake_pda_bump,
+        program_id,
+    )?;
+
+    if paper.creator_pubkey.eq(reviewer_acc.key) {
+        return Err(DeResearcherError::PublisherCannotAddPeerReview.into());
+    }
+
+    if paper.is_excluded_reviewer(reviewer_acc.key) {
+        return Err(DeResearcherError::ConflictOfInterest.into());
+    }
+
+    validate_no_conflict_of_interest(
+        program_id,
+        reviewer_acc.key,
+        &paper.creator_pubkey,
+        conflict_of_interest_pda_acc,
+    )?;
+
+    if Clock::get()?.unix_timestamp >= paper.review_deadline() {
+        return Err(DeResearcherError::ReviewWindowClosed.into());
+    }
+
+    // Papers submitted to a venue only accept reviews from reviewers invited by its editors
+
+    if paper.has_venue() {
+        if review_invitation_pda_acc.data_is_empty() {
+            return Err(DeResearcherError::ReviewInvitationRequired.into());
+        }
+
+        let review_invitation =
+            ReviewInvitation::try_from_slice(&review_invitation_pda_acc.data.borrow())?;
+
+        let review_invitation_seeds = vec![
+            REVIEW_INVITATION_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            reviewer_acc.key.as_ref(),
+        ];
+
+        validate_pda(
+            review_invitation_seeds,
+            review_invitation_pda_acc.key,
+            review_invitation.bump,
+            program_id,
+        )?;
+    }
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda, paper.bump, program_id)?;
+
+    let researcher_profile_seeds = vec![RESEARCHER_PROFILE_PDA_SEED, reviewer_acc.key.as_ref()];
+
+    validate_pda(
+        researcher_profile_seeds,
+        researcher_profile_pda,
+        researcher_profile.bump,
+        program_id,
+    )?;
+
+    validate_add_peer_review_accounts(
+        reviewer_acc,
+        researcher_profile_pda_acc,
+        paper_pda_acc,
+        peer_review_pda_acc,
+        review_stake_pda_acc,
+    )?;
+
+    let rent = Rent::get()?;
+
+    let rent_exempt = rent.minimum_balance(PeerReview::size());
+
+    let create_peer_review_ix = system_instruction::create_account(
+        reviewer_acc.key,
+        peer_review_pda_acc.key,
+        rent_exempt,
+        PeerReview::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_peer_review_ix,
+        &[
+            reviewer_acc.clone(),
+            peer_review_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            PEER_REVIEW_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            reviewer_acc.key.as_ref(),
+            &[paper.version],
+            &[pda_bump],
+        ]],
+    )?;
+
+    // The vault holds its own rent plus the locked stake until it is released or slashed
+
+    let create_review_stake_ix = system_instruction::create_account(
+        reviewer_acc.key,
+        review_stake_pda_acc.key,
+        rent.minimum_balance(ReviewStake::size()) + REVIEW_STAKE_LAMPORTS,
+        ReviewStake::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_review_stake_ix,
+        &[
+            reviewer_acc.clone(),
+            review_stake_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            REVIEW_STAKE_PDA_SEED,
+            peer_review_pda_acc.key.as_ref(),
+            &[stake_pda_bump],
+        ]],
+    )?;
+
+    ReviewStake::create_new(
+        review_stake_pda_acc,
+        peer_review_pda_acc,
+        reviewer_acc,
+        paper_pda_acc,
+        REVIEW_STAKE_LAMPORTS,
+        stake_pda_bump,
+    )?;
+
+    Ok(())
+}
+
+fn validate_mint_res_paper_accounts(
+    researcher_acc: &AccountInfo,
+    researcher_profile_pda_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    fee_receiver_acc: &AccountInfo,
+    reviewer_pool_pda_acc: &AccountInfo,
+    paper: &ResearchPaper,
+) -> Result<(), DeResearcherError> {
+    if !researcher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if researcher_profile_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearcherProfileNotFound);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if fee_receiver_acc.key.ne(&paper.creator_pubkey) {
+        return Err(DeResearcherError::InvalidFeeReceiver);
+    }
+
+    if reviewer_pool_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewerPoolNotFound);
+    }
+
+    if !reviewer_pool_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Split an access payment between the creator and the paper's reviewer pool
+
+fn pay_access_fee<'a>(
+    payer_acc: &AccountInfo<'a>,
+    fee_receiver_acc: &AccountInfo<'a>,
+    reviewer_pool_pda_acc: &AccountInfo<'a>,
+    paper: &ResearchPaper,
+    access_fee: u64,
+) -> ProgramResult {
+    let reviewer_share = paper.reviewer_share(access_fee);
+
+    let creator_share = access_fee - reviewer_share;
+
+    if creator_share > 0 {
+        invoke(
+            &system_instruction::transfer(payer_acc.key, &paper.creator_pubkey, creator_share),
+            &[payer_acc.clone(), fee_receiver_acc.clone()],
+        )?;
+    }
+
+    if reviewer_share > 0 {
+        invoke(
+            &system_instruction::transfer(payer_acc.key, reviewer_pool_pda_acc.key, reviewer_share),
+            &[payer_acc.clone(), reviewer_pool_pda_acc.clone()],
+        )?;
+
+        ReviewerPool::deposit(reviewer_pool_pda_acc, reviewer_share)?;
+    }
+
+    Ok(())
+}
+
+// Mint a research paper
+
+pub fn mint_res_paper_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: MintResearchPaper,
+) -> ProgramResult {
+    msg!("Instruction: MintResearchPaper");
+    let accounts_iter = &mut accounts.iter();
+
+    let researcher_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+
+    let research_token_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let research_token_pda = research_token_pda_acc.key;
+
+    // The payer can mint on behalf of a recipient who neither signs nor pays
+
+    let recipient = data.recipient.unwrap_or(*researcher_acc.key);
+
+    let res_token_acc_seeds = vec![
+        RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        recipient.as_ref(),
+    ];
+
+    validate_pda(
+        res_token_acc_seeds,
+        research_token_pda,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let fee_receiver_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    validate_mint_res_paper_accounts(
+        researcher_acc,
+        researcher_profile_pda_acc,
+        paper_pda_acc,
+        fee_receiver_acc,
+        reviewer_pool_pda_acc,
+        &paper,
+    )?;
+
+    let reviewer_pool = ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.data.borrow())?;
+
+    let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda_acc.key.as_ref()];
+
+    validate_pda(
+        reviewer_pool_seeds,
+        reviewer_pool_pda_acc.key,
+        reviewer_pool.bump,
+        program_id,
+    )?;
+
+    // A rented or licensed token is upgraded in place to permanent access
+
+    let is_upgrade = !research_token_pda_acc.data_is_empty();
+
+    if is_upgrade {
+        let research_token =
+            ResearchTokenAccount::from_account_data(&research_token_pda_acc.data.borrow())?;
+
+        if research_token.kind == AccessKind::Permanent {
+            return Err(DeResearcherError::ResearchTokenAccountAlreadyExists.into());
+        }
+    }
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    if !is_upgrade {
+        let create_res_token_acc_ix = system_instruction::create_account(
+            researcher_acc.key,
+            research_token_pda,
+            Rent::get()?.minimum_balance(ResearchTokenAccount::size()),
+            ResearchTokenAccount::size() as u64,
+            program_id,
+        );
+
+        invoke_signed(
+            &create_res_token_acc_ix,
+            &[
+                researcher_acc.clone(),
+                research_token_pda_acc.clone(),
+                system_program_acc.clone(),
+            ],
+            &[&[
+                RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+                paper_pda_acc.key.as_ref(),
+                recipient.as_ref(),
+                &[data.pda_bump],
+            ]],
+        )?;
+    }
+
+    let access_fee = paper.current_access_fee(Clock::get()?.unix_timestamp);
+
+    if access_fee > data.max_price {
+        return Err(DeResearcherError::MaxPriceExceeded.into());
+    }
+
+    // With a refund window the payment waits in an escrow until the author releases it
+
+    if paper.refund_window_secs > 0 {
+        let mint_escrow_pda_acc = next_account_info(accounts_iter)?;
+
+        let mint_escrow_seeds = vec![MINT_ESCROW_PDA_SEED, research_token_pda.as_ref()];
+
+        validate_pda(
+            mint_escrow_seeds,
+            mint_escrow_pda_acc.key,
+            data.escrow_pda_bump,
+            program_id,
+        )?;
+
+        if !mint_escrow_pda_acc.data_is_empty() {
+            return Err(DeResearcherError::InvalidMintEscrow.into());
+        }
+
+        let create_mint_escrow_ix = system_instruction::create_account(
+            researcher_acc.key,
+            mint_escrow_pda_acc.key,
+            Rent::get()?
+                .minimum_balance(MintEscrow::size())
+                .checked_add(access_fee)
+                .ok_or(DeResearcherError::SizeOverflow)?,
+            MintEscrow::size() as u64,
+            program_id,
+        );
+
+        invoke_signed(
+            &create_mint_escrow_ix,
+            &[
+                researcher_acc.clone(),
+                mint_escrow_pda_acc.clone(),
+                system_program_acc.clone(),
+            ],
+            &[&[
+                MINT_ESCROW_PDA_SEED,
+                research_token_pda.as_ref(),
+                &[data.escrow_pda_bump],
+            ]],
+        )?;
+
+        let refund_deadline = Clock::get()?
+            .unix_timestamp
+            .checked_add(paper.refund_window_secs)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+
+        MintEscrow::create_new(
+            mint_escrow_pda_acc,
+            paper_pda_acc,
+            researcher_acc,
+            research_token_pda_acc,
+            researcher_profile_pda_acc,
+            access_fee,
+            refund_deadline,
+            data.escrow_pda_bump,
+        )?;
+    } else {
+        pay_access_fee(
+            researcher_acc,
+            fee_receiver_acc,
+            reviewer_pool_pda_acc,
+            &paper,
+            access_fee,
+        )?;
+    }
+
+    ResearchTokenAccount::mint_paper(
+        research_token_pda_acc,
+        &recipient,
+        paper_pda_acc,
+        researcher_profile_pda_acc,
+        data.pda_bump,
+        false,
+    )?;
+
+    Ok(())
+}
+
+fn validate_gift_research_paper_accounts(
+    author_acc: &AccountInfo,
+    researcher_profile_pda_acc: &AccountInfo,
+    research_token_pda_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !author_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if researcher_profile_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearcherProfileNotFound);
+    }
+
+    if !research_token_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearchTokenAccountAlreadyExists);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !paper_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Gift one of the paper's reserved editions, the author pays the rent
+
+pub fn gift_research_paper_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: GiftResearchPaper,
+) -> ProgramResult {
+    msg!("Instruction: GiftResearchPaper");
+    let accounts_iter = &mut accounts.iter();
+
+    let author_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+
+    let recipient_acc = next_account_info(accounts_iter)?;
+
+    let research_token_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    validate_gift_research_paper_accounts(
+        author_acc,
+        researcher_profile_pda_acc,
+        research_token_pda_acc,
+        paper_pda_acc,
+    )?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        author_acc.key.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let researcher_profile_seeds = vec![RESEARCHER_PROFILE_PDA_SEED, author_acc.key.as_ref()];
+
+    validate_pda(
+        researcher_profile_seeds,
+        researcher_profile_pda_acc.key,
+        ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.data.borrow())?.bump,
+        program_id,
+    )?;
+
+    let res_token_acc_seeds = vec![
+        RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        recipient_acc.key.as_ref(),
+    ];
+
+    validate_pda(
+        res_token_acc_seeds,
+        research_token_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let create_res_token_acc_ix = system_instruction::create_account(
+        author_acc.key,
+        research_token_pda_acc.key,
+        Rent::get()?.minimum_balance(ResearchTokenAccount::size()),
+        ResearchTokenAccount::size() as u64,
+        program_id,
+    );
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+    invoke_signed(
+        &create_res_token_acc_ix,
+        &[
+            author_acc.clone(),
+            research_token_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            recipient_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    ResearchTokenAccount::mint_paper(
+        research_token_pda_acc,
+        recipient_acc.key,
+        paper_pda_acc,
+        researcher_profile_pda_acc,
+        data.pda_bump,
+        true,
+    )?;
+
+    Ok(())
+}
+
+pub fn validate_check_and_assign_reputation_accounts(
+    reputation_checker_acc: &AccountInfo,
+    researcher_profile_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if researcher_profile_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearcherProfileNotFound);
+    }
+
+    if !researcher_profile_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if reputation_checker_acc.key.ne(&REPUTATION_CHECKER_PUBKEY) {
+        return Err(DeResearcherError::InvalidReputationChecker);
+    }
+
+    if !reputation_checker_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    Ok(())
+}
+
+// Check and assign reputation
+
+pub fn check_and_assign_reputation_ix(
+    accounts: &[AccountInfo],
+    data: CheckAndAssignReputation,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let reputation_checker_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+
+    validate_check_and_assign_reputation_accounts(
+        reputation_checker_acc,
+        researcher_profile_pda_acc,
+    )?;
+
+    ResearcherProfile::assign_reputation(researcher_profile_pda_acc, data)?;
+
+    Ok(())
+}
+
+fn validate_release_review_stake_accounts(
+    reviewer_acc: &AccountInfo,
+    review_stake_pda_acc: &AccountInfo,
+    review_stake: &ReviewStake,
+    paper_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !reviewer_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !review_stake_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if review_stake.reviewer_pubkey.ne(reviewer_acc.key)
+        || review_stake.paper_pubkey.ne(paper_pda_acc.key)
+        || review_stake.peer_review_pubkey.ne(peer_review_pda_acc.key)
+    {
+        return Err(DeResearcherError::PubkeyMismatch);
+    }
+
+    Ok(())
+}
+
+// Return a reviewer's stake once its review round is over and no dispute is open. A final paper
+// has to go through FinalizeReviews first, so governance can still slash an outlier, a paper left
+// waiting for a revision releases its stakes after a lock period, a superseded version right away
+
+pub fn release_review_stake_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: ReleaseReviewStake");
+    let accounts_iter = &mut accounts.iter();
+
+    let reviewer_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let review_stake_pda_acc = next_account_info(accounts_iter)?;
+
+    let dispute_pda_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
+
+    if review_stake_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewStakeNotFound.into());
+    }
+
+    let review_stake = ReviewStake::try_from_slice(&review_stake_pda_acc.data.borrow())?;
+
+    let review_stake_seeds = vec![REVIEW_STAKE_PDA_SEED, peer_review_pda_acc.key.as_ref()];
+
+    validate_pda(
+        review_stake_seeds,
+        review_stake_pda_acc.key,
+        review_stake.bump,
+        program_id,
+    )?;
+
+    // The stake stays locked while the review's canonical dispute PDA holds an open dispute
+
+    let (dispute_pda, _) = Pubkey::find_program_address(
+        &[DISPUTE_PDA_SEED, peer_review_pda_acc.key.as_ref()],
+        program_id,
+    );
+
+    if dispute_pda.ne(dispute_pda_acc.key) {
+        return Err(DeResearcherError::PdaPubekyMismatch.into());
+    }
+
+    if !dispute_pda_acc.data_is_empty() {
+        let dispute = Dispute::try_from_slice(&dispute_pda_acc.data.borrow())?;
+
+        if dispute.state == DisputeState::Open {
+            return Err(DeResearcherError::DisputeOpen.into());
+        }
+    }
+
+    validate_release_review_stake_accounts(
+        reviewer_acc,
+        review_stake_pda_acc,
+        &review_stake,
+        paper_pda_acc,
+        peer_review_pda_acc,
+    )?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    // An unrevealed commitment forfeits its stake through ExpireReviewCommit instead
+
+    let peer_review = load_peer_review(program_id, paper_pda_acc, peer_review_pda_acc)?;
+
+    if !peer_review.is_revealed {
+        return Err(DeResearcherError::InvalidState.into());
+    }
+
+    // An upheld dispute forfeits the stake when it's resolved, so nothing is left to release
+
+    if peer_review.is_invalidated {
+        return Err(DeResearcherError::PeerReviewInvalidated.into());
+    }
+
+    if peer_review.paper_version == paper.version {
+        match paper.state {
+            PaperState::RequiresRevision => {
+                let unlocks_at = paper
+                    .review_closes_at()
+                    .saturating_add(REVISION_STAKE_LOCK_SECS);
+
+                if Clock::get()?.unix_timestamp < unlocks_at {
+                    return Err(DeResearcherError::PaperNotFinalized.into());
+                }
+            }
+            state if state.is_final() => {
+                let reviewer_pool =
+                    ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.data.borrow())?;
+
+                let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda_acc.key.as_ref()];
+
+                validate_pda(
+                    reviewer_pool_seeds,
+                    reviewer_pool_pda_acc.key,
+                    reviewer_pool.bump,
+                    program_id,
+                )?;
+
+                if !reviewer_pool.is_finalized {
+                    return Err(DeResearcherError::ReviewsNotFinalized.into());
+                }
+            }
+            _ => return Err(DeResearcherError::PaperNotFinalized.into()),
+        }
+    }
+
+    close_pda_account(review_stake_pda_acc, reviewer_acc)?;
+
+    Ok(())
+}
+
+fn validate_slash_review_stake_accounts(
+    slasher_acc: &AccountInfo,
+    review_stake_pda_acc: &AccountInfo,
+    treasury_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !slasher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if review_stake_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewStakeNotFound);
+    }
+
+    if !review_stake_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if treasury_acc.key.ne(&TREASURY_PUBKEY) {
+        return Err(DeResearcherError::InvalidTreasury);
+    }
+
+    Ok(())
+}
+
+// Slash part of a review stake into the treasury for an outlier or bad-faith review, either
+// governance or one of the arbiters in the current arbiter set can slash
+
+pub fn slash_review_stake_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: SlashReviewStake,
+) -> ProgramResult {
+    msg!("Instruction: SlashReviewStake");
+    let accounts_iter = &mut accounts.iter();
+
+    let slasher_acc = next_account_info(accounts_iter)?;
+
+    let review_stake_pda_acc = next_account_info(accounts_iter)?;
+
+    let treasury_acc = next_account_info(accounts_iter)?;
+
+    let arbiter_set_pda_acc = next_account_info(accounts_iter)?;
+
+    validate_slash_review_stake_accounts(slasher_acc, review_stake_pda_acc, treasury_acc)?;
+
+    if slasher_acc.key.ne(&GOVERNANCE_AUTHORITY_PUBKEY) {
+        if arbiter_set_pda_acc.data_is_empty() {
+            return Err(DeResearcherError::InvalidGovernanceAuthority.into());
+        }
+
+        let arbiter_set = ArbiterSet::try_from_slice(&arbiter_set_pda_acc.data.borrow())?;
+
+        validate_pda(
+            vec![ARBITER_SET_PDA_SEED],
+            arbiter_set_pda_acc.key,
+            arbiter_set.bump,
+            program_id,
+        )?;
+
+        if !arbiter_set.is_arbiter(slasher_acc.key) {
+            return Err(DeResearcherError::InvalidArbiter.into());
+        }
+    }
+
+    let review_stake = ReviewStake::try_from_slice(&review_stake_pda_acc.data.borrow())?;
+
+    let review_stake_seeds = vec![
+        REVIEW_STAKE_PDA_SEED,
+        review_stake.peer_review_pubkey.as_ref(),
+    ];
+
+    validate_pda(
+        review_stake_seeds,
+        review_stake_pda_acc.key,
+        review_stake.bump,
+        program_id,
+    )?;
+
+    let slashed_amount = ReviewStake::slash(review_stake_pda_acc, data)?;
+
+    transfer_lamports_from_pda(review_stake_pda_acc, treasury_acc, slashed_amount)?;
+
+    Ok(())
+}
+
+fn validate_claim_reviewer_reward_accounts(
+    reviewer_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
+    reviewer_pool_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !reviewer_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if peer_review_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PeerReviewNotFound);
+    }
+
+    if reviewer_pool_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewerPoolNotFound);
+    }
+
+    if !peer_review_pda_acc.is_writable || !reviewer_pool_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Claim a reviewer's consensus-weighted share of the paper's finalized reviewer pool
+
+pub fn claim_reviewer_reward_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: ClaimReviewerReward");
+    let accounts_iter = &mut accounts.iter();
+
+    let reviewer_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
+
+    validate_claim_reviewer_reward_accounts(
+        reviewer_acc,
+        paper_pda_acc,
+        peer_review_pda_acc,
+        reviewer_pool_pda_acc,
+    )?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let peer_review = load_peer_review(program_id, paper_pda_acc, peer_review_pda_acc)?;
+
+    if peer_review.reviewer_pubkey.ne(reviewer_acc.key) {
+        return Err(DeResearcherError::PdaPubekyMismatch.into());
+    }
+
+    let reviewer_pool = ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.data.borrow())?;
+
+    let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda_acc.key.as_ref()];
+
+    validate_pda(
+        reviewer_pool_seeds,
+        reviewer_pool_pda_acc.key,
+        reviewer_pool.bump,
+        program_id,
+    )?;
+
+    let reward = PeerReview::claim_reward(peer_review_pda_acc, &paper, &reviewer_pool)?;
+
+    ReviewerPool::withdraw(reviewer_pool_pda_acc, reward)?;
+
+    transfer_lamports_from_pda(reviewer_pool_pda_acc, reviewer_acc, reward)?;
+
+    Ok(())
+}
+
+fn validate_finalize_reviews_accounts(
+    paper_pda_acc: &AccountInfo,
+    reviewer_pool_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
+    researcher_profile_pda_acc: &AccountInfo,
+    paper: &ResearchPaper,
+) -> Result<(), DeResearcherError> {
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if reviewer_pool_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewerPoolNotFound);
+    }
+
+    if researcher_profile_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearcherProfileNotFound);
+    }
+
+    if !reviewer_pool_pda_acc.is_writable
+        || !peer_review_pda_acc.is_writable
+        || !researcher_profile_pda_acc.is_writable
+    {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if !paper.state.is_final() {
+        return Err(DeResearcherError::PaperNotFinalized);
+    }
+
+    Ok(())
+}
+
+// Permissionless crank processing one review of a finalized paper per call, every review is
+// first tallied into the pool's score histogram, then weighted by its agreement with the median
+
+pub fn finalize_reviews_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: FinalizeReviews");
+    let accounts_iter = &mut accounts.iter();
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_version_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    validate_paper_version_pda(
+        program_id,
+        paper_pda_acc,
+        paper_version_pda_acc,
+        paper.version,
+    )?;
+
+    validate_finalize_reviews_accounts(
+        paper_pda_acc,
+        reviewer_pool_pda_acc,
+        peer_review_pda_acc,
+        researcher_profile_pda_acc,
+        &paper,
+    )?;
+
+    let reviewer_pool = ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.data.borrow())?;
+
+    let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda_acc.key.as_ref()];
+
+    validate_pda(
+        reviewer_pool_seeds,
+        reviewer_pool_pda_acc.key,
+        reviewer_pool.bump,
+        program_id,
+    )?;
+
+    if reviewer_pool.is_finalized {
+        return Err(DeResearcherError::ReviewsAlreadyFinalized.into());
+    }
+
+    let peer_review = load_peer_review(program_id, paper_pda_acc, peer_review_pda_acc)?;
+
+    if peer_review.paper_version != paper.version {
+        return Err(DeResearcherError::ReviewVersionMismatch.into());
+    }
+
+    if !peer_review.is_revealed {
+        return Err(DeResearcherError::InvalidState.into());
+    }
+
+    let researcher_profile =
+        ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.data.borrow())?;
+
+    let researcher_profile_seeds = vec![
+        RESEARCHER_PROFILE_PDA_SEED,
+        peer_review.reviewer_pubkey.as_ref(),
+    ];
+
+    validate_pda(
+        researcher_profile_seeds,
+        researcher_profile_pda_acc.key,
+        researcher_profile.bump,
+        program_id,
+    )?;
+
+    if reviewer_pool.tallied_reviews < paper.total_reviews {
+        ReviewerPool::tally_review(
+            reviewer_pool_pda_acc,
+            peer_review_pda_acc,
+            paper.rubric_dimension_count,
+        )?;
+    } else {
+        ReviewerPool::weigh_review(
+            reviewer_pool_pda_acc,
+            peer_review_pda_acc,
+            researcher_profile_pda_acc,
+            paper_version_pda_acc,
+            &paper,
+        )?;
+    }
+
+    Ok(())
+}
+
+// Commit to a blind review by storing the hash of its scores and a salt
+
+pub fn commit_review_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: CommitReview,
+) -> ProgramResult {
+    msg!("Instruction: CommitReview");
+    let accounts_iter = &mut accounts.iter();
+
+    let reviewer_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let review_stake_pda_acc = next_account_info(accounts_iter)?;
+
+    let review_invitation_pda_acc = next_account_info(accounts_iter)?;
+
+    let conflict_of_interest_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    if !paper.blind_review {
+        return Err(DeResearcherError::NotABlindReview.into());
+    }
+
+    if !paper_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount.into());
+    }
+
+    create_peer_review_accounts(
+        program_id,
+        reviewer_acc,
+        researcher_profile_pda_acc,
+        paper_pda_acc,
+        peer_review_pda_acc,
+        review_stake_pda_acc,
+        review_invitation_pda_acc,
+        conflict_of_interest_pda_acc,
+        system_program_acc,
+        &paper,
+        data.pda_bump,
+        data.stake_pda_bump,
+    )?;
+
+    PeerReview::create_commitment(peer_review_pda_acc, reviewer_acc, paper_pda_acc, data)?;
+
+    Ok(())
+}
+
+fn validate_reveal_review_accounts(
+    reviewer_acc: &AccountInfo,
+    researcher_profile_pda_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !reviewer_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if researcher_profile_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearcherProfileNotFound);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if peer_review_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PeerReviewNotFound);
+    }
+
+    if !researcher_profile_pda_acc.is_writable
+        || !paper_pda_acc.is_writable
+        || !peer_review_pda_acc.is_writable
+    {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Reveal a committed blind review once the commit window has closed
+
+pub fn reveal_review_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: RevealReview,
+) -> ProgramResult {
+    msg!("Instruction: RevealReview");
+    let accounts_iter = &mut accounts.iter();
+
+    let reviewer_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    validate_reveal_review_accounts(
+        reviewer_acc,
+        researcher_profile_pda_acc,
+        paper_pda_acc,
+        peer_review_pda_acc,
+    )?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let peer_review = PeerReview::try_from_slice(&peer_review_pda_acc.data.borrow())?;
+
+    let review_version = [peer_review.paper_version];
+
+    let peer_review_seeds = vec![
+        PEER_REVIEW_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        reviewer_acc.key.as_ref(),
+        review_version.as_ref(),
+    ];
+
+    validate_pda(
+        peer_review_seeds,
+        peer_review_pda_acc.key,
+        peer_review.bump,
+        program_id,
+    )?;
+
+    let researcher_profile =
+        ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.data.borrow())?;
+
+    let researcher_profile_seeds = vec![RESEARCHER_PROFILE_PDA_SEED, reviewer_acc.key.as_ref()];
+
+    validate_pda(
+        researcher_profile_seeds,
+        researcher_profile_pda_acc.key,
+        researcher_profile.bump,
+        program_id,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    if now < paper.review_deadline() {
+        return Err(DeResearcherError::CommitWindowOpen.into());
+    }
+
+    if now >= paper.reveal_deadline() {
+        return Err(DeResearcherError::RevealWindowClosed.into());
+    }
+
+    if !paper.state.accepts_reveal() {
+        return Err(DeResearcherError::InvalidState.into());
+    }
+
+    paper.validate_scores(&data.scores)?;
+
+    PeerReview::reveal(
+        peer_review_pda_acc,
+        paper_pda_acc,
+        researcher_profile_pda_acc,
+        data,
+    )?;
+
+    Ok(())
+}
+
+fn validate_expire_review_commit_accounts(
+    reviewer_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
+    review_stake_pda_acc: &AccountInfo,
+    treasury_acc: &AccountInfo,
+    peer_review: &PeerReview,
+) -> Result<(), DeResearcherError> {
+    if !paper_pda_acc.is_writable
+        || !peer_review_pda_acc.is_writable
+        || !review_stake_pda_acc.is_writable
+    {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if review_stake_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewStakeNotFound);
+    }
+
+    if peer_review.reviewer_pubkey.ne(reviewer_acc.key) {
+        return Err(DeResearcherError::PubkeyMismatch);
+    }
+
+    if treasury_acc.key.ne(&TREASURY_PUBKEY) {
+        return Err(DeResearcherError::InvalidTreasury);
+    }
+
+    if peer_review.is_revealed {
+        return Err(DeResearcherError::InvalidState);
+    }
+
+    Ok(())
+}
+
+// Permissionless crank closing a blind review that was never revealed. The stake is forfeited
+// when the reviewer let the reveal window pass, and refunded when the paper left review
+// before the commitment could be revealed
+
+pub fn expire_review_commit_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: ExpireReviewCommit");
+    let accounts_iter = &mut accounts.iter();
+
+    let reviewer_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let review_stake_pda_acc = next_account_info(accounts_iter)?;
+
+    let treasury_acc = next_account_info(accounts_iter)?;
+
+    if peer_review_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PeerReviewNotFound.into());
+    }
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let peer_review = PeerReview::try_from_slice(&peer_review_pda_acc.data.borrow())?;
+
+    let review_version = [peer_review.paper_version];
+
+    let peer_review_seeds = vec![
+        PEER_REVIEW_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        peer_review.reviewer_pubkey.as_ref(),
+        review_version.as_ref(),
+    ];
+
+    validate_pda(
+        peer_review_seeds,
+        peer_review_pda_acc.key,
+        peer_review.bump,
+        program_id,
+    )?;
+
+    validate_expire_review_commit_accounts(
+        reviewer_acc,
+        paper_pda_acc,
+        peer_review_pda_acc,
+        review_stake_pda_acc,
+        treasury_acc,
+        &peer_review,
+    )?;
+
+    let review_stake = ReviewStake::try_from_slice(&review_stake_pda_acc.data.borrow())?;
+
+    let review_stake_seeds = vec![REVIEW_STAKE_PDA_SEED, peer_review_pda_acc.key.as_ref()];
+
+    validate_pda(
+        review_stake_seeds,
+        review_stake_pda_acc.key,
+        review_stake.bump,
+        program_id,
+    )?;
+
+    // Expired and RequiresRevision are only reached once the reveal window has passed, and a
+    // superseded version was revised from RequiresRevision, so the reviewer had the full window
+
+    let is_superseded = peer_review.paper_version != paper.version;
+
+    let is_forfeited = is_superseded
+        || paper.state.accepts_reveal()
+        || matches!(
+            paper.state,
+            PaperState::Expired | PaperState::RequiresRevision
+        );
+
+    if is_forfeited && !is_superseded && Clock::get()?.unix_timestamp < paper.reveal_deadline() {
+        return Err(DeResearcherError::RevealWindowOpen.into());
+    }
+
+    ResearchPaper::settle_commitment(paper_pda_acc, peer_review.paper_version)?;
+
+    if is_forfeited {
+        transfer_lamports_from_pda(review_stake_pda_acc, treasury_acc, review_stake.amount)?;
+    }
+
+    close_pda_account(review_stake_pda_acc, reviewer_acc)?;
+
+    close_pda_account(peer_review_pda_acc, reviewer_acc)?;
+
+    Ok(())
+}
+
+// Permissionless crank closing the review of a paper whose review window has passed
+
+pub fn crank_paper_deadline_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: CrankPaperDeadline");
+    let accounts_iter = &mut accounts.iter();
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound.into());
+    }
+
+    if !paper_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount.into());
+    }
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    ResearchPaper::expire_review(paper_pda_acc, Clock::get()?.unix_timestamp)?;
+
+    Ok(())
+}
+
+fn validate_create_journal_accounts(
+    authority_acc: &AccountInfo,
+    journal_pda_acc: &AccountInfo,
+    data: &CreateJournal,
+) -> Result<(), DeResearcherError> {
+    if !authority_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !journal_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::JournalAlreadyExists);
+    }
+
+    if !journal_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if data.min_approvals == 0 {
+        return Err(DeResearcherError::InvalidMinApprovals);
+    }
+
+    Ok(())
+}
+
+// Create a journal that papers can be submitted to
+
+pub fn create_journal_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: CreateJournal,
+) -> ProgramResult {
+    msg!("Instruction: CreateJournal");
+    let accounts_iter = &mut accounts.iter();
+
+    let authority_acc = next_account_info(accounts_iter)?;
+
+    let journal_pda_acc = next_account_info(accounts_iter)?;
+
+    let rubric_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    load_rubric(program_id, rubric_pda_acc)?;
+
+    // The journal is seeded by the first 32 bytes of its zero padded name
+
+    let name_bytes = checked_string_convt_to_64_bytes(&data.name)?;
+
+    let journal_seeds = vec![
+        JOURNAL_PDA_SEED,
+        authority_acc.key.as_ref(),
+        name_bytes[..32].as_ref(),
+    ];
+
+    validate_pda(
+        journal_seeds,
+        journal_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    validate_create_journal_accounts(authority_acc, journal_pda_acc, &data)?;
+
+    let create_journal_ix = system_instruction::create_account(
+        authority_acc.key,
+        journal_pda_acc.key,
+        Rent::get()?.minimum_balance(Journal::size()),
+        Journal::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_journal_ix,
+        &[
+            authority_acc.clone(),
+            journal_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            JOURNAL_PDA_SEED,
+            authority_acc.key.as_ref(),
+            name_bytes[..32].as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    Journal::create_new(journal_pda_acc, authority_acc, rubric_pda_acc, data)?;
+
+    Ok(())
+}
+
+// Load a journal and check that it is a valid journal PDA
+
+fn load_journal(
+    program_id: &Pubkey,
+    journal_pda_acc: &AccountInfo,
+) -> Result<Journal, ProgramError> {
+    if journal_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::JournalNotFound.into());
+    }
+
+    let journal = Journal::try_from_slice(&journal_pda_acc.data.borrow())?;
+
+    let journal_seeds = vec![
+        JOURNAL_PDA_SEED,
+        journal.authority_pubkey.as_ref(),
+        journal.name[..32].as_ref(),
+    ];
+
+    validate_pda(journal_seeds, journal_pda_acc.key, journal.bump, program_id)?;
+
+    Ok(journal)
+}
+
+fn validate_appoint_editor_accounts(
+    authority_acc: &AccountInfo,
+    journal_pda_acc: &AccountInfo,
+    editor_pda_acc: &AccountInfo,
+    journal: &Journal,
+) -> Result<(), DeResearcherError> {
+    if !authority_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if journal.authority_pubkey.ne(authority_acc.key) {
+        return Err(DeResearcherError::InvalidJournalAuthority);
+    }
+
+    if !editor_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::EditorAlreadyExists);
+    }
+
+    if !journal_pda_acc.is_writable || !editor_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Appoint an editor for a journal
+
+pub fn appoint_editor_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: AppointEditor,
+) -> ProgramResult {
+    msg!("Instruction: AppointEditor");
+    let accounts_iter = &mut accounts.iter();
+
+    let authority_acc = next_account_info(accounts_iter)?;
+
+    let journal_pda_acc = next_account_info(accounts_iter)?;
+
+    let editor_acc = next_account_info(accounts_iter)?;
+
+    let editor_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    let journal = load_journal(program_id, journal_pda_acc)?;
+
+    let editor_seeds = vec![
+        EDITOR_PDA_SEED,
+        journal_pda_acc.key.as_ref(),
+        editor_acc.key.as_ref(),
+    ];
+
+    validate_pda(editor_seeds, editor_pda_acc.key, data.pda_bump, program_id)?;
+
+    validate_appoint_editor_accounts(authority_acc, journal_pda_acc, editor_pda_acc, &journal)?;
+
+    let create_editor_ix = system_instruction::create_account(
+        authority_acc.key,
+        editor_pda_acc.key,
+        Rent::get()?.minimum_balance(Editor::size()),
+        Editor::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_editor_ix,
+        &[
+            authority_acc.clone(),
+            editor_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            EDITOR_PDA_SEED,
+            journal_pda_acc.key.as_ref(),
+            editor_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    Editor::create_new(editor_pda_acc, journal_pda_acc, editor_acc, data)?;
+
+    Journal::add_editor(journal_pda_acc)?;
+
+    Ok(())
+}
+
+// Check that the signer is an editor of the paper's venue
+
+fn validate_editor_for_paper(
+    program_id: &Pubkey,
+    editor_acc: &AccountInfo,
+    editor_pda_acc: &AccountInfo,
+    conflict_of_interest_pda_acc: &AccountInfo,
+    paper: &ResearchPaper,
+) -> ProgramResult {
+    if !editor_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner.into());
+    }
+
+    if !paper.has_venue() {
+        return Err(DeResearcherError::PaperHasNoVenue.into());
+    }
+
+    if editor_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::EditorNotFound.into());
+    }
+
+    let editor = Editor::try_from_slice(&editor_pda_acc.data.borrow())?;
+
+    let editor_seeds = vec![
+        EDITOR_PDA_SEED,
+        paper.venue_pubkey.as_ref(),
+        editor_acc.key.as_ref(),
+    ];
+
+    validate_pda(editor_seeds, editor_pda_acc.key, editor.bump, program_id)?;
+
+    validate_no_conflict_of_interest(
+        program_id,
+        editor_acc.key,
+        &paper.creator_pubkey,
+        conflict_of_interest_pda_acc,
+    )?;
+
+    Ok(())
+}
+
+// Invite a reviewer to review a paper submitted to the editor's venue
+
+pub fn invite_reviewer_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: InviteReviewer,
+) -> ProgramResult {
+    msg!("Instruction: InviteReviewer");
+    let accounts_iter = &mut accounts.iter();
+
+    let editor_acc = next_account_info(accounts_iter)?;
+
+    let editor_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_acc = next_account_info(accounts_iter)?;
+
+    let review_invitation_pda_acc = next_account_info(accounts_iter)?;
+
+    let conflict_of_interest_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound.into());
+    }
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    validate_editor_for_paper(
+        program_id,
+        editor_acc,
+        editor_pda_acc,
+        conflict_of_interest_pda_acc,
+        &paper,
+    )?;
+
+    // A desk-rejected, withdrawn or decided paper takes no new reviewers
+
+    if !paper.state.is_under_review() {
+        return Err(DeResearcherError::InvalidState.into());
+    }
+
+    if paper.creator_pubkey.eq(reviewer_acc.key) {
+        return Err(DeResearcherError::PublisherCannotAddPeerReview.into());
+    }
+
+    let review_invitation_seeds = vec![
+        REVIEW_INVITATION_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        reviewer_acc.key.as_ref(),
+    ];
+
+    validate_pda(
+        review_invitation_seeds,
+        review_invitation_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    if !review_invitation_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewInvitationAlreadyExists.into());
+    }
+
+    let create_review_invitation_ix = system_instruction::create_account(
+        editor_acc.key,
+        review_invitation_pda_acc.key,
+        Rent::get()?.minimum_balance(ReviewInvitation::size()),
+        ReviewInvitation::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_review_invitation_ix,
+        &[
+            editor_acc.clone(),
+            review_invitation_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            REVIEW_INVITATION_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            reviewer_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    ReviewInvitation::create_new(
+        review_invitation_pda_acc,
+        paper_pda_acc,
+        reviewer_acc,
+        editor_acc,
+        data,
+    )?;
+
+    Ok(())
+}
+
+// Reject a paper before it goes out for review
+
+pub fn desk_reject_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: DeskReject,
+) -> ProgramResult {
+    msg!("Instruction: DeskReject");
+    let accounts_iter = &mut accounts.iter();
+
+    let editor_acc = next_account_info(accounts_iter)?;
+
+    let editor_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let conflict_of_interest_pda_acc = next_account_info(accounts_iter)?;
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound.into());
+    }
+
+    if !paper_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount.into());
+    }
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    validate_editor_for_paper(
+        program_id,
+        editor_acc,
+        editor_pda_acc,
+        conflict_of_interest_pda_acc,
+        &paper,
+    )?;
+
+    ResearchPaper::desk_reject(paper_pda_acc, data)?;
+
+    Ok(())
+}
+
+fn validate_submit_to_journal_accounts(
+    publisher_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    journal_pda_acc: &AccountInfo,
+    rubric_pda_acc: &AccountInfo,
+    journal_authority_acc: &AccountInfo,
+    journal: &Journal,
+) -> Result<(), DeResearcherError> {
+    if !publisher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !paper_pda_acc.is_writable || !journal_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if rubric_pda_acc.key.ne(&journal.rubric_pubkey) {
+        return Err(DeResearcherError::InvalidRubric);
+    }
+
+    if journal_authority_acc.key.ne(&journal.authority_pubkey) {
+        return Err(DeResearcherError::InvalidFeeReceiver);
+    }
+
+    Ok(())
+}
+
+// Submit a paper to a journal, its review then follows the journal's policy
+
+pub fn submit_to_journal_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: SubmitToJournal");
+    let accounts_iter = &mut accounts.iter();
+
+    let publisher_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let journal_pda_acc = next_account_info(accounts_iter)?;
+
+    let rubric_pda_acc = next_account_info(accounts_iter)?;
+
+    let journal_authority_acc = next_account_info(accounts_iter)?;
+
+    let journal = load_journal(program_id, journal_pda_acc)?;
+
+    validate_submit_to_journal_accounts(
+        publisher_acc,
+        paper_pda_acc,
+        journal_pda_acc,
+        rubric_pda_acc,
+        journal_authority_acc,
+        &journal,
+    )?;
+
+    let rubric = load_rubric(program_id, rubric_pda_acc)?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        publisher_acc.key.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    if journal.submission_fee > 0 {
+        invoke(
+            &system_instruction::transfer(
+                publisher_acc.key,
+                journal_authority_acc.key,
+                journal.submission_fee,
+            ),
+            &[publisher_acc.clone(), journal_authority_acc.clone()],
+        )?;
+    }
+
+    ResearchPaper::submit_to_journal(
+        paper_pda_acc,
+        publisher_acc,
+        journal_pda_acc,
+        &journal,
+        &rubric,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    Journal::add_paper(journal_pda_acc)?;
+
+    Ok(())
+}
+
+fn validate_create_rubric_accounts(
+    authority_acc: &AccountInfo,
+    rubric_pda_acc: &AccountInfo,
+    data: &CreateRubric,
+) -> Result<(), DeResearcherError> {
+    if !authority_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !rubric_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::RubricAlreadyExists);
+    }
+
+    if !rubric_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if data.dimension_names.is_empty()
+        || data.dimension_names.len() > MAX_RUBRIC_DIMENSIONS
+        || data.dimension_names.len() != data.weights.len()
+        || data.weights.contains(&0)
+        || data.approval_threshold > MAX_SCORE
+    {
+        return Err(DeResearcherError::InvalidRubric);
+    }
+
+    Ok(())
+}
+
+// Create a rubric of named, weighted dimensions that reviews are scored against
+
+pub fn create_rubric_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: CreateRubric,
+) -> ProgramResult {
+    msg!("Instruction: CreateRubric");
+    let accounts_iter = &mut accounts.iter();
+
+    let authority_acc = next_account_info(accounts_iter)?;
+
+    let rubric_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    // Like journals, rubrics are seeded by the first 32 bytes of their zero padded name
+
+    let name_bytes = checked_string_convt_to_64_bytes(&data.name)?;
+
+    let rubric_seeds = vec![
+        RUBRIC_PDA_SEED,
+        authority_acc.key.as_ref(),
+        name_bytes[..32].as_ref(),
+    ];
+
+    validate_pda(rubric_seeds, rubric_pda_acc.key, data.pda_bump, program_id)?;
+
+    validate_create_rubric_accounts(authority_acc, rubric_pda_acc, &data)?;
+
+    let create_rubric_ix = system_instruction::create_account(
+        authority_acc.key,
+        rubric_pda_acc.key,
+        Rent::get()?.minimum_balance(Rubric::size()),
+        Rubric::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_rubric_ix,
+        &[
+            authority_acc.clone(),
+            rubric_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            RUBRIC_PDA_SEED,
+            authority_acc.key.as_ref(),
+            name_bytes[..32].as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    Rubric::create_new(rubric_pda_acc, authority_acc, data)?;
+
+    Ok(())
+}
+
+// Load a rubric and check that it is a valid rubric PDA
+
+fn load_rubric(program_id: &Pubkey, rubric_pda_acc: &AccountInfo) -> Result<Rubric, ProgramError> {
+    if rubric_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::RubricNotFound.into());
+    }
+
+    let rubric = Rubric::try_from_slice(&rubric_pda_acc.data.borrow())?;
+
+    let rubric_seeds = vec![
+        RUBRIC_PDA_SEED,
+        rubric.authority_pubkey.as_ref(),
+        rubric.name[..32].as_ref(),
+    ];
+
+    validate_pda(rubric_seeds, rubric_pda_acc.key, rubric.bump, program_id)?;
+
+    Ok(rubric)
+}
+
+// Load a peer review of the given paper and check that it is a valid peer review PDA
+
+fn load_peer_review(
+    program_id: &Pubkey,
+    paper_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
+) -> Result<PeerReview, ProgramError> {
+    if peer_review_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PeerReviewNotFound.into());
+    }
+
+    let peer_review = PeerReview::try_from_slice(&peer_review_pda_acc.data.borrow())?;
+
+    // Reviews migrated from the legacy layout keep the address they had before papers were
+    // versioned
+
+    let legacy_peer_review_seeds = vec![
+        PEER_REVIEW_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        peer_review.reviewer_pubkey.as_ref(),
+    ];
+
+    if peer_review.paper_version == 0
+        && validate_pda(
+            legacy_peer_review_seeds,
+            peer_review_pda_acc.key,
+            peer_review.bump,
+            program_id,
+        )
+        .is_ok()
+    {
+        return Ok(peer_review);
+    }
+
+    let review_version = [peer_review.paper_version];
+
+    let peer_review_seeds = vec![
+        PEER_REVIEW_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        peer_review.reviewer_pubkey.as_ref(),
+        review_version.as_ref(),
+    ];
+
+    validate_pda(
+        peer_review_seeds,
+        peer_review_pda_acc.key,
+        peer_review.bump,
+        program_id,
+    )?;
+
+    Ok(peer_review)
+}
+
+fn validate_paper_version_pda(
+    program_id: &Pubkey,
+    paper_pda_acc: &AccountInfo,
+    paper_version_pda_acc: &AccountInfo,
+    version: u8,
+) -> ProgramResult {
+    if paper_version_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperVersionNotFound.into());
+    }
+
+    if !paper_version_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount.into());
+    }
+
+    let paper_version = PaperVersion::try_from_slice(&paper_version_pda_acc.data.borrow())?;
+
+    let version = [version];
+
+    let paper_version_seeds = vec![
+        PAPER_VERSION_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        version.as_ref(),
+    ];
+
+    validate_pda(
+        paper_version_seeds,
+        paper_version_pda_acc.key,
+        paper_version.bump,
+        program_id,
+    )?;
+
+    Ok(())
+}
+
+fn validate_create_author_response_accounts(
+    author_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    author_response_pda_acc: &AccountInfo,
+    peer_review: &PeerReview,
+) -> Result<(), DeResearcherError> {
+    if !author_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !author_response_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::AuthorResponseAlreadyExists);
+    }
+
+    if !author_response_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if !peer_review.is_revealed {
+        return Err(DeResearcherError::InvalidState);
+    }
+
+    Ok(())
+}
+
+// Let a paper's author respond to one of its reviews
+
+pub fn create_author_response_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: CreateAuthorResponse,
+) -> ProgramResult {
+    msg!("Instruction: CreateAuthorResponse");
+    let accounts_iter = &mut accounts.iter();
+
+    let author_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let author_response_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    let peer_review = load_peer_review(program_id, paper_pda_acc, peer_review_pda_acc)?;
+
+    validate_create_author_response_accounts(
+        author_acc,
+        paper_pda_acc,
+        author_response_pda_acc,
+        &peer_review,
+    )?;
+
+    // Only the paper's creator can respond, the paper PDA is seeded by its creator
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        author_acc.key.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let author_response_seeds = vec![AUTHOR_RESPONSE_PDA_SEED, peer_review_pda_acc.key.as_ref()];
+
+    validate_pda(
+        author_response_seeds,
+        author_response_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let create_author_response_ix = system_instruction::create_account(
+        author_acc.key,
+        author_response_pda_acc.key,
+        Rent::get()?.minimum_balance(AuthorResponse::size()),
+        AuthorResponse::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_author_response_ix,
+        &[
+            author_acc.clone(),
+            author_response_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            AUTHOR_RESPONSE_PDA_SEED,
+            peer_review_pda_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    AuthorResponse::create_new(
+        author_response_pda_acc,
+        peer_review_pda_acc,
+        paper_pda_acc,
+        author_acc,
+        data,
+    )?;
+
+    Ok(())
+}
+
+fn validate_amend_peer_review_accounts(
+    reviewer_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
+    author_response_pda_acc: &AccountInfo,
+    peer_review: &PeerReview,
+) -> Result<(), DeResearcherError> {
+    if !reviewer_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !paper_pda_acc.is_writable || !peer_review_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if author_response_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::AuthorResponseRequired);
+    }
+
+    if peer_review.reviewer_pubkey.ne(reviewer_acc.key) {
+        return Err(DeResearcherError::PubkeyMismatch);
+    }
+
+    if peer_review.is_amended {
+        return Err(DeResearcherError::PeerReviewAlreadyAmended);
+    }
+
+    if peer_review.is_invalidated {
+        return Err(DeResearcherError::PeerReviewInvalidated);
+    }
+
+    Ok(())
+}
+
+// Let a reviewer revise their scores once after the author has responded
+
+pub fn amend_peer_review_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: AmendPeerReview,
+) -> ProgramResult {
+    msg!("Instruction: AmendPeerReview");
+    let accounts_iter = &mut accounts.iter();
+
+    let reviewer_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let author_response_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review = load_peer_review(program_id, paper_pda_acc, peer_review_pda_acc)?;
+
+    validate_amend_peer_review_accounts(
+        reviewer_acc,
+        paper_pda_acc,
+        peer_review_pda_acc,
+        author_response_pda_acc,
+        &peer_review,
+    )?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let author_response = AuthorResponse::try_from_slice(&author_response_pda_acc.data.borrow())?;
+
+    let author_response_seeds = vec![AUTHOR_RESPONSE_PDA_SEED, peer_review_pda_acc.key.as_ref()];
+
+    validate_pda(
+        author_response_seeds,
+        author_response_pda_acc.key,
+        author_response.bump,
+        program_id,
+    )?;
+
+    // Scores can only change while the tally can still move, before publishing or reward payouts
+
+    if !matches!(
+        paper.state,
+        PaperState::InPeerReview | PaperState::ApprovedToPublish
+    ) || peer_review.reward_claimed > 0
+        || peer_review.consensus_weight > 0
+    {
+        return Err(DeResearcherError::InvalidState.into());
+    }
+
+    paper.validate_scores(&data.scores)?;
+
+    PeerReview::amend(peer_review_pda_acc, paper_pda_acc, data)?;
+
+    Ok(())
+}
+
+fn validate_set_arbiters_accounts(
+    governance_acc: &AccountInfo,
+    arbiter_set_pda_acc: &AccountInfo,
+    data: &SetArbiters,
+) -> Result<(), DeResearcherError> {
+    if governance_acc.key.ne(&GOVERNANCE_AUTHORITY_PUBKEY) {
+        return Err(DeResearcherError::InvalidGovernanceAuthority);
+    }
+
+    if !governance_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !arbiter_set_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    // A quorum must be a strict majority so a dispute can't reach both outcomes
+
+    if data.arbiters.is_empty()
+        || data.arbiters.len() > MAX_ARBITERS
+        || data.quorum as usize > data.arbiters.len()
+        || data.quorum as usize * 2 <= data.arbiters.len()
+    {
+        return Err(DeResearcherError::InvalidArbiterSet);
+    }
+
+    Ok(())
+}
+
+// Appoint, or replace, the arbiters that resolve review disputes
+
+pub fn set_arbiters_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: SetArbiters,
+) -> ProgramResult {
+    msg!("Instruction: SetArbiters");
+    let accounts_iter = &mut accounts.iter();
+
+    let governance_acc = next_account_info(accounts_iter)?;
+
+    let arbiter_set_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    validate_set_arbiters_accounts(governance_acc, arbiter_set_pda_acc, &data)?;
+
+    validate_pda(
+        vec![ARBITER_SET_PDA_SEED],
+        arbiter_set_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    if arbiter_set_pda_acc.data_is_empty() {
+        let create_arbiter_set_ix = system_instruction::create_account(
+            governance_acc.key,
+            arbiter_set_pda_acc.key,
+            Rent::get()?.minimum_balance(ArbiterSet::size()),
+            ArbiterSet::size() as u64,
+            program_id,
+        );
+
+        invoke_signed(
+            &create_arbiter_set_ix,
+            &[
+                governance_acc.clone(),
+                arbiter_set_pda_acc.clone(),
+                system_program_acc.clone(),
+            ],
+            &[&[ARBITER_SET_PDA_SEED, &[data.pda_bump]]],
+        )?;
+    }
+
+    ArbiterSet::set(arbiter_set_pda_acc, data)?;
+
+    Ok(())
+}
+
+fn validate_open_dispute_accounts(
+    author_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    dispute_pda_acc: &AccountInfo,
+    peer_review: &PeerReview,
+) -> Result<(), DeResearcherError> {
+    if !author_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !dispute_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::DisputeAlreadyExists);
+    }
+
+    if !dispute_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if !peer_review.is_revealed {
+        return Err(DeResearcherError::InvalidState);
+    }
+
+    if peer_review.is_invalidated {
+        return Err(DeResearcherError::PeerReviewInvalidated);
+    }
+
+    Ok(())
+}
+
+// Let a paper's author challenge one of its reviews by posting a bond
+
+pub fn open_dispute_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: OpenDispute,
+) -> ProgramResult {
+    msg!("Instruction: OpenDispute");
+    let accounts_iter = &mut accounts.iter();
+
+    let author_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let dispute_pda_acc = next_account_info(accounts_iter)?;
+
+    let arbiter_set_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    let peer_review = load_peer_review(program_id, paper_pda_acc, peer_review_pda_acc)?;
+
+    validate_open_dispute_accounts(author_acc, paper_pda_acc, dispute_pda_acc, &peer_review)?;
+
+    if arbiter_set_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ArbiterSetNotFound.into());
+    }
+
+    let arbiter_set = ArbiterSet::try_from_slice(&arbiter_set_pda_acc.data.borrow())?;
+
+    validate_pda(
+        vec![ARBITER_SET_PDA_SEED],
+        arbiter_set_pda_acc.key,
+        arbiter_set.bump,
+        program_id,
+    )?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        author_acc.key.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    // Disputes can only change the outcome of a paper that hasn't been published
+
+    if !matches!(
+        paper.state,
+        PaperState::InPeerReview | PaperState::ApprovedToPublish | PaperState::RequiresRevision
+    ) {
+        return Err(DeResearcherError::InvalidState.into());
+    }
+
+    let dispute_seeds = vec![DISPUTE_PDA_SEED, peer_review_pda_acc.key.as_ref()];
+
+    validate_pda(
+        dispute_seeds,
+        dispute_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    // The dispute account itself holds the bond on top of its rent
+
+    let create_dispute_ix = system_instruction::create_account(
+        author_acc.key,
+        dispute_pda_acc.key,
+        Rent::get()?.minimum_balance(Dispute::size()) + DISPUTE_BOND_LAMPORTS,
+        Dispute::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_dispute_ix,
+        &[
+            author_acc.clone(),
+            dispute_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            DISPUTE_PDA_SEED,
+            peer_review_pda_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    Dispute::create_new(
+        dispute_pda_acc,
+        paper_pda_acc,
+        peer_review_pda_acc,
+        author_acc,
+        &arbiter_set,
+        DISPUTE_BOND_LAMPORTS,
+        data,
+    )?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn validate_resolve_dispute_accounts(
+    arbiter_acc: &AccountInfo,
+    dispute_pda_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
+    author_acc: &AccountInfo,
+    reviewer_acc: &AccountInfo,
+    dispute: &Dispute,
+    peer_review: &PeerReview,
+) -> Result<(), DeResearcherError> {
+    if !arbiter_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !dispute_pda_acc.is_writable
+        || !paper_pda_acc.is_writable
+        || !peer_review_pda_acc.is_writable
+        || !author_acc.is_writable
+        || !reviewer_acc.is_writable
+    {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if dispute.paper_pubkey.ne(paper_pda_acc.key)
+        || dispute.peer_review_pubkey.ne(peer_review_pda_acc.key)
+        || dispute.author_pubkey.ne(author_acc.key)
+        || peer_review.reviewer_pubkey.ne(reviewer_acc.key)
+    {
+        return Err(DeResearcherError::PubkeyMismatch);
+    }
+
+    if dispute.state != DisputeState::Open {
+        return Err(DeResearcherError::DisputeAlreadyResolved);
+    }
+
+    Ok(())
+}
+
+// Record an arbiter's vote, once a quorum agrees the review is invalidated or the dispute rejected
+
+pub fn resolve_dispute_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: ResolveDispute,
+) -> ProgramResult {
+    msg!("Instruction: ResolveDispute");
+    let accounts_iter = &mut accounts.iter();
+
+    let arbiter_acc = next_account_info(accounts_iter)?;
+
+    let dispute_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    let author_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_acc = next_account_info(accounts_iter)?;
+
+    let review_stake_pda_acc = next_account_info(accounts_iter)?;
+
+    let treasury_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
+
+    if dispute_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::DisputeNotFound.into());
+    }
+
+    let dispute = Dispute::try_from_slice(&dispute_pda_acc.data.borrow())?;
+
+    let dispute_seeds = vec![DISPUTE_PDA_SEED, peer_review_pda_acc.key.as_ref()];
+
+    validate_pda(dispute_seeds, dispute_pda_acc.key, dispute.bump, program_id)?;
+
+    let peer_review = load_peer_review(program_id, paper_pda_acc, peer_review_pda_acc)?;
+
+    validate_resolve_dispute_accounts(
+        arbiter_acc,
+        dispute_pda_acc,
+        paper_pda_acc,
+        peer_review_pda_acc,
+        author_acc,
+        reviewer_acc,
+        &dispute,
+        &peer_review,
+    )?;
+
+    if Clock::get()?.unix_timestamp >= dispute.expires_at {
+        return Err(DeResearcherError::DisputeExpired.into());
+    }
+
+    let arbiter_index = dispute
+        .arbiter_index(arbiter_acc.key)
+        .ok_or(DeResearcherError::InvalidArbiter)?;
+
+    let state = Dispute::vote(dispute_pda_acc, arbiter_index, data.uphold)?;
+
+    match state {
+        DisputeState::Upheld => {
+            if !reviewer_pool_pda_acc.is_writable {
+                return Err(DeResearcherError::ImmutableAccount.into());
+            }
+
+            let reviewer_pool = ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.data.borrow())?;
+
+            let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda_acc.key.as_ref()];
+
+            validate_pda(
+                reviewer_pool_seeds,
+                reviewer_pool_pda_acc.key,
+                reviewer_pool.bump,
+                program_id,
+            )?;
+
+            PeerReview::invalidate(peer_review_pda_acc, paper_pda_acc, reviewer_pool_pda_acc)?;
+
+            forfeit_review_stake(
+                program_id,
+                peer_review_pda_acc,
+                review_stake_pda_acc,
+                reviewer_acc,
+                treasury_acc,
+            )?;
+
+            transfer_lamports_from_pda(dispute_pda_acc, author_acc, dispute.bond)?;
+        }
+        DisputeState::Rejected => {
+            transfer_lamports_from_pda(dispute_pda_acc, reviewer_acc, dispute.bond)?;
+        }
+        DisputeState::Open | DisputeState::Expired => {}
+    }
+
+    Ok(())
+}
+
+// An invalidated review loses its whole stake to the treasury, the vault's rent goes back to the
+// reviewer. A stake that was already released when the dispute opened has nothing left to forfeit
+
+fn forfeit_review_stake(
+    program_id: &Pubkey,
+    peer_review_pda_acc: &AccountInfo,
+    review_stake_pda_acc: &AccountInfo,
+    reviewer_acc: &AccountInfo,
+    treasury_acc: &AccountInfo,
+) -> ProgramResult {
+    let (review_stake_pda, _) = Pubkey::find_program_address(
+        &[REVIEW_STAKE_PDA_SEED, peer_review_pda_acc.key.as_ref()],
+        program_id,
+    );
+
+    if review_stake_pda.ne(review_stake_pda_acc.key) {
+        return Err(DeResearcherError::PdaPubekyMismatch.into());
+    }
+
+    if treasury_acc.key.ne(&TREASURY_PUBKEY) {
+        return Err(DeResearcherError::InvalidTreasury.into());
+    }
+
+    if review_stake_pda_acc.data_is_empty() {
+        return Ok(());
+    }
+
+    if !review_stake_pda_acc.is_writable || !treasury_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount.into());
+    }
+
+    let review_stake = ReviewStake::try_from_slice(&review_stake_pda_acc.data.borrow())?;
+
+    transfer_lamports_from_pda(review_stake_pda_acc, treasury_acc, review_stake.amount)?;
+
+    close_pda_account(review_stake_pda_acc, reviewer_acc)?;
+
+    Ok(())
+}
+
+fn validate_expire_dispute_accounts(
+    dispute_pda_acc: &AccountInfo,
+    author_acc: &AccountInfo,
+    dispute: &Dispute,
+) -> Result<(), DeResearcherError> {
+    if !dispute_pda_acc.is_writable || !author_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if dispute.author_pubkey.ne(author_acc.key) {
+        return Err(DeResearcherError::PubkeyMismatch);
+    }
+
+    Ok(())
+}
+
+// Permissionless crank refunding the bond of a dispute the arbiters left unresolved
+
+pub fn expire_dispute_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: ExpireDispute");
+    let accounts_iter = &mut accounts.iter();
+
+    let dispute_pda_acc = next_account_info(accounts_iter)?;
+
+    let author_acc = next_account_info(accounts_iter)?;
+
+    if dispute_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::DisputeNotFound.into());
+    }
+
+    let dispute = Dispute::try_from_slice(&dispute_pda_acc.data.borrow())?;
+
+    let dispute_seeds = vec![DISPUTE_PDA_SEED, dispute.peer_review_pubkey.as_ref()];
+
+    validate_pda(dispute_seeds, dispute_pda_acc.key, dispute.bump, program_id)?;
+
+    validate_expire_dispute_accounts(dispute_pda_acc, author_acc, &dispute)?;
+
+    Dispute::expire(dispute_pda_acc, Clock::get()?.unix_timestamp)?;
+
+    transfer_lamports_from_pda(dispute_pda_acc, author_acc, dispute.bond)?;
+
+    Ok(())
+}
+
+// Conflicts are symmetric, so the pair of researchers is ordered before seeding
+
+fn conflict_of_interest_seeds<'a>(researcher: &'a Pubkey, other: &'a Pubkey) -> Vec<&'a [u8]> {
+    let (first, second) = if researcher < other {
+        (researcher, other)
+    } else {
+        (other, researcher)
+    };
+
+    vec![
+        CONFLICT_OF_INTEREST_PDA_SEED,
+        first.as_ref(),
+        second.as_ref(),
+    ]
+}
+
+fn conflict_of_interest_pda(
+    researcher: &Pubkey,
+    other: &Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&conflict_of_interest_seeds(researcher, other), program_id)
+}
+
+// The canonical conflict PDA for the researcher and the paper's author must not exist, or
+// only hold the author's one-sided declaration
+
+fn validate_no_conflict_of_interest(
+    program_id: &Pubkey,
+    researcher: &Pubkey,
+    author: &Pubkey,
+    conflict_of_interest_pda_acc: &AccountInfo,
+) -> ProgramResult {
+    let (conflict_of_interest_pda, _) = conflict_of_interest_pda(researcher, author, program_id);
+
+    if conflict_of_interest_pda.ne(conflict_of_interest_pda_acc.key) {
+        return Err(DeResearcherError::PdaPubekyMismatch.into());
+    }
+
+    if conflict_of_interest_pda_acc.data_is_empty() {
+        return Ok(());
+    }
+
+    let conflict_of_interest =
+        ConflictOfInterest::try_from_slice(&conflict_of_interest_pda_acc.data.borrow())?;
+
+    if conflict_of_interest.bars(researcher) {
+        return Err(DeResearcherError::ConflictOfInterest.into());
+    }
+
+    Ok(())
+}
+
+fn validate_declare_conflict_of_interest_accounts(
+    declarer_acc: &AccountInfo,
+    counterparty_acc: &AccountInfo,
+    conflict_of_interest_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !declarer_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if declarer_acc.key.eq(counterparty_acc.key) {
+        return Err(DeResearcherError::PubkeyMismatch);
+    }
+
+    if !conflict_of_interest_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Declare a conflict of interest between two researchers. Signed by both it bars either from
+// reviewing the other's papers, signed by the declarer alone it only recuses the declarer, so
+// nobody can bar a researcher from reviewing their papers unilaterally. A declaration from
+// the other side of a one-sided conflict makes it mutual
+
+pub fn declare_conflict_of_interest_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: DeclareConflictOfInterest,
+) -> ProgramResult {
+    msg!("Instruction: DeclareConflictOfInterest");
+    let accounts_iter = &mut accounts.iter();
+
+    let declarer_acc = next_account_info(accounts_iter)?;
+
+    let counterparty_acc = next_account_info(accounts_iter)?;
+
+    let conflict_of_interest_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    validate_declare_conflict_of_interest_accounts(
+        declarer_acc,
+        counterparty_acc,
+        conflict_of_interest_pda_acc,
+    )?;
+
+    // Only the canonical bump is accepted so the review check can find the record
+
+    let (conflict_of_interest_pda, bump) =
+        conflict_of_interest_pda(declarer_acc.key, counterparty_acc.key, program_id);
+
+    if conflict_of_interest_pda.ne(conflict_of_interest_pda_acc.key) || bump != data.pda_bump {
+        return Err(DeResearcherError::PdaPubekyMismatch.into());
+    }
+
+    if !conflict_of_interest_pda_acc.data_is_empty() {
+        let conflict_of_interest =
+            ConflictOfInterest::try_from_slice(&conflict_of_interest_pda_acc.data.borrow())?;
+
+        let is_mutual =
+            counterparty_acc.is_signer || conflict_of_interest.declarer_pubkey.ne(declarer_acc.key);
+
+        if conflict_of_interest.is_mutual || !is_mutual {
+            return Err(DeResearcherError::ConflictOfInterestAlreadyExists.into());
+        }
+
+        ConflictOfInterest::make_mutual(conflict_of_interest_pda_acc)?;
+
+        return Ok(());
+    }
+
+    let create_conflict_of_interest_ix = system_instruction::create_account(
+        declarer_acc.key,
+        conflict_of_interest_pda_acc.key,
+        Rent::get()?.minimum_balance(ConflictOfInterest::size()),
+        ConflictOfInterest::size() as u64,
+        program_id,
+    );
+
+    let mut conflict_of_interest_seeds =
+        conflict_of_interest_seeds(declarer_acc.key, counterparty_acc.key);
+
+    let bump_seed = [data.pda_bump];
+
+    conflict_of_interest_seeds.push(&bump_seed);
+
+    invoke_signed(
+        &create_conflict_of_interest_ix,
+        &[
+            declarer_acc.clone(),
+            conflict_of_interest_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&conflict_of_interest_seeds],
+    )?;
+
+    ConflictOfInterest::create_new(
+        conflict_of_interest_pda_acc,
+        declarer_acc,
+        counterparty_acc,
+        data,
+    )?;
+
+    Ok(())
+}
+
+fn validate_withdraw_paper_accounts(
+    publisher_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !publisher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !paper_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Let a paper's creator withdraw it at any point before it is published
+
+pub fn withdraw_paper_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: WithdrawPaper,
+) -> ProgramResult {
+    msg!("Instruction: WithdrawPaper");
+    let accounts_iter = &mut accounts.iter();
+
+    let publisher_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_version_pda_acc = next_account_info(accounts_iter)?;
+
+    validate_withdraw_paper_accounts(publisher_acc, paper_pda_acc)?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        publisher_acc.key.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    validate_paper_version_pda(
+        program_id,
+        paper_pda_acc,
+        paper_version_pda_acc,
+        paper.version,
+    )?;
+
+    ResearchPaper::withdraw(paper_pda_acc, data, Clock::get()?.unix_timestamp)?;
+
+    record_final_approvals(paper_pda_acc, paper_version_pda_acc)?;
+
+    Ok(())
+}
+
+fn validate_skip_venue_submission_accounts(
+    publisher_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !publisher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !paper_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Let a paper's creator give up on a venue and open the paper to peer review
+
+pub fn skip_venue_submission_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: SkipVenueSubmission");
+    let accounts_iter = &mut accounts.iter();
+
+    let publisher_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    validate_skip_venue_submission_accounts(publisher_acc, paper_pda_acc)?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        publisher_acc.key.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    ResearchPaper::skip_venue_submission(paper_pda_acc, Clock::get()?.unix_timestamp)?;
+
+    Ok(())
+}
+
+fn validate_retract_paper_accounts(
+    governance_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if governance_acc.key.ne(&GOVERNANCE_AUTHORITY_PUBKEY) {
+        return Err(DeResearcherError::InvalidGovernanceAuthority);
+    }
+
+    if !governance_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !paper_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Retract a published paper, existing token accounts are left untouched
+
+pub fn retract_paper_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: RetractPaper,
+) -> ProgramResult {
+    msg!("Instruction: RetractPaper");
+    let accounts_iter = &mut accounts.iter();
+
+    let governance_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_version_pda_acc = next_account_info(accounts_iter)?;
+
+    validate_retract_paper_accounts(governance_acc, paper_pda_acc)?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    validate_paper_version_pda(
+        program_id,
+        paper_pda_acc,
+        paper_version_pda_acc,
+        paper.version,
+    )?;
+
+    ResearchPaper::retract(paper_pda_acc, data, Clock::get()?.unix_timestamp)?;
+
+    record_final_approvals(paper_pda_acc, paper_version_pda_acc)?;
+
+    Ok(())
+}
+
+fn validate_append_erratum_accounts(
+    publisher_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    erratum_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !publisher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if !erratum_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ErratumAlreadyExists);
+    }
+
+    if !paper_pda_acc.is_writable || !erratum_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Append a correction notice to a published paper
+
+pub fn append_erratum_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: AppendErratum,
+) -> ProgramResult {
+    msg!("Instruction: AppendErratum");
+    let accounts_iter = &mut accounts.iter();
+
+    let publisher_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let erratum_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    validate_append_erratum_accounts(publisher_acc, paper_pda_acc, erratum_pda_acc)?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        publisher_acc.key.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    if !paper.is_published(Clock::get()?.unix_timestamp) {
+        return Err(DeResearcherError::InvalidState.into());
+    }
+
+    // Errata are seeded by their index so the whole chain can be derived from the counter
+
+    let erratum_index = paper.total_errata.to_le_bytes();
+
+    let erratum_seeds = vec![
+        ERRATUM_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        erratum_index.as_ref(),
+    ];
+
+    validate_pda(
+        erratum_seeds,
+        erratum_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let create_erratum_ix = system_instruction::create_account(
+        publisher_acc.key,
+        erratum_pda_acc.key,
+        Rent::get()?.minimum_balance(Erratum::size()),
+        Erratum::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_erratum_ix,
+        &[
+            publisher_acc.clone(),
+            erratum_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            ERRATUM_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            erratum_index.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    Erratum::create_new(erratum_pda_acc, paper_pda_acc, publisher_acc, data)?;
+
+    Ok(())
+}
+
+fn validate_submit_revision_accounts(
+    publisher_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    new_paper_version_pda_acc: &AccountInfo,
+    reviewer_pool_pda_acc: &AccountInfo,
+    content_hash_record_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !publisher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if reviewer_pool_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewerPoolNotFound);
+    }
+
+    if !new_paper_version_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperVersionAlreadyExists);
+    }
+
+    if !content_hash_record_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::DuplicateContentHash);
+    }
+
+    if !paper_pda_acc.is_writable
+        || !new_paper_version_pda_acc.is_writable
+        || !reviewer_pool_pda_acc.is_writable
+        || !content_hash_record_pda_acc.is_writable
+    {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Submit a revised version of a paper that requires revision, keeping the old version on record
+
+pub fn submit_revision_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: SubmitRevision,
+) -> ProgramResult {
+    msg!("Instruction: SubmitRevision");
+    let accounts_iter = &mut accounts.iter();
+
+    let publisher_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_version_pda_acc = next_account_info(accounts_iter)?;
+
+    let new_paper_version_pda_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
+
+    let content_hash_record_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    validate_submit_revision_accounts(
+        publisher_acc,
+        paper_pda_acc,
+        new_paper_version_pda_acc,
+        reviewer_pool_pda_acc,
+        content_hash_record_pda_acc,
+    )?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        publisher_acc.key.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let reviewer_pool = ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.data.borrow())?;
+
+    let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda_acc.key.as_ref()];
+
+    validate_pda(
+        reviewer_pool_seeds,
+        reviewer_pool_pda_acc.key,
+        reviewer_pool.bump,
+        program_id,
+    )?;
+
+    validate_paper_version_pda(
+        program_id,
+        paper_pda_acc,
+        paper_version_pda_acc,
+        paper.version,
+    )?;
+
+    let new_version = [paper
+        .version
+        .checked_add(1)
+        .ok_or(DeResearcherError::SizeOverflow)?];
+
+    let new_paper_version_seeds = vec![
+        PAPER_VERSION_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        new_version.as_ref(),
+    ];
+
+    validate_pda(
+        new_paper_version_seeds,
+        new_paper_version_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let create_paper_version_ix = system_instruction::create_account(
+        publisher_acc.key,
+        new_paper_version_pda_acc.key,
+        Rent::get()?.minimum_balance(PaperVersion::size()),
+        PaperVersion::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_paper_version_ix,
+        &[
+            publisher_acc.clone(),
+            new_paper_version_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            PAPER_VERSION_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            new_version.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    // The revised content is registered like a new paper's, so it can't duplicate any paper
+
+    let content_hash_bytes = checked_string_convt_to_64_bytes(&data.paper_content_hash)?;
+
+    let content_hash_record_seeds = vec![
+        CONTENT_HASH_RECORD_PDA_SEED,
+        content_hash_bytes[..32].as_ref(),
+        content_hash_bytes[32..].as_ref(),
+    ];
+
+    let (content_hash_record_pda, content_hash_record_bump) =
+        Pubkey::find_program_address(&content_hash_record_seeds, program_id);
+
+    if content_hash_record_pda.ne(content_hash_record_pda_acc.key)
+        || content_hash_record_bump != data.content_hash_record_pda_bump
+    {
+        return Err(DeResearcherError::PdaPubekyMismatch.into());
+    }
+
+    let create_content_hash_record_ix = system_instruction::create_account(
+        publisher_acc.key,
+        content_hash_record_pda_acc.key,
+        Rent::get()?.minimum_balance(ContentHashRecord::size()),
+        ContentHashRecord::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_content_hash_record_ix,
+        &[
+            publisher_acc.clone(),
+            content_hash_record_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            CONTENT_HASH_RECORD_PDA_SEED,
+            content_hash_bytes[..32].as_ref(),
+            content_hash_bytes[32..].as_ref(),
+            &[data.content_hash_record_pda_bump],
+        ]],
+    )?;
+
+    ContentHashRecord::create_new(
+        content_hash_record_pda_acc,
+        paper_pda_acc,
+        publisher_acc,
+        content_hash_bytes,
+        data.content_hash_record_pda_bump,
+    )?;
+
+    ResearchPaper::submit_revision(
+        paper_pda_acc,
+        paper_version_pda_acc,
+        new_paper_version_pda_acc,
+        data,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    ReviewerPool::reopen(reviewer_pool_pda_acc)?;
+
+    Ok(())
+}
+
+fn validate_flag_duplicate_accounts(
+    author_acc: &AccountInfo,
+    original_paper_pda_acc: &AccountInfo,
+    suspect_paper_pda_acc: &AccountInfo,
+    duplicate_flag_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !author_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if original_paper_pda_acc.data_is_empty() || suspect_paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if original_paper_pda_acc.key.eq(suspect_paper_pda_acc.key) {
+        return Err(DeResearcherError::PubkeyMismatch);
+    }
+
+    if !duplicate_flag_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::DuplicateFlagAlreadyExists);
+    }
+
+    if !suspect_paper_pda_acc.is_writable || !duplicate_flag_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Let an author flag another paper as a suspected duplicate of their own
+
+pub fn flag_duplicate_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: FlagDuplicate,
+) -> ProgramResult {
+    msg!("Instruction: FlagDuplicate");
+    let accounts_iter = &mut accounts.iter();
+
+    let author_acc = next_account_info(accounts_iter)?;
+
+    let original_paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let suspect_paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let duplicate_flag_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    validate_flag_duplicate_accounts(
+        author_acc,
+        original_paper_pda_acc,
+        suspect_paper_pda_acc,
+        duplicate_flag_pda_acc,
+    )?;
+
+    let original_paper = ResearchPaper::try_from_slice(&original_paper_pda_acc.data.borrow())?;
+
+    let original_paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        original_paper.paper_content_hash[..32].as_ref(),
+        author_acc.key.as_ref(),
+    ];
+
+    validate_pda(
+        original_paper_seeds,
+        original_paper_pda_acc.key,
+        original_paper.bump,
+        program_id,
+    )?;
+
+    let suspect_paper = ResearchPaper::try_from_slice(&suspect_paper_pda_acc.data.borrow())?;
+
+    let suspect_paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        suspect_paper.paper_content_hash[..32].as_ref(),
+        suspect_paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(
+        suspect_paper_seeds,
+        suspect_paper_pda_acc.key,
+        suspect_paper.bump,
+        program_id,
+    )?;
+
+    let duplicate_flag_seeds = vec![
+        DUPLICATE_FLAG_PDA_SEED,
+        suspect_paper_pda_acc.key.as_ref(),
+        original_paper_pda_acc.key.as_ref(),
+    ];
+
+    validate_pda(
+        duplicate_flag_seeds,
+        duplicate_flag_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let create_duplicate_flag_ix = system_instruction::create_account(
+        author_acc.key,
+        duplicate_flag_pda_acc.key,
+        Rent::get()?.minimum_balance(DuplicateFlag::size()),
+        DuplicateFlag::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_duplicate_flag_ix,
+        &[
+            author_acc.clone(),
+            duplicate_flag_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            DUPLICATE_FLAG_PDA_SEED,
+            suspect_paper_pda_acc.key.as_ref(),
+            original_paper_pda_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    DuplicateFlag::create_new(
+        duplicate_flag_pda_acc,
+        original_paper_pda_acc,
+        suspect_paper_pda_acc,
+        author_acc,
+        data,
+    )?;
+
+    Ok(())
+}
+
+fn validate_create_institution_accounts(
+    admin_acc: &AccountInfo,
+    institution_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !admin_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !institution_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::InstitutionAlreadyExists);
+    }
+
+    if !institution_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Create an institution whose admin manages its members and licenses
+
+pub fn create_institution_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: CreateInstitution,
+) -> ProgramResult {
+    msg!("Instruction: CreateInstitution");
+    let accounts_iter = &mut accounts.iter();
+
+    let admin_acc = next_account_info(accounts_iter)?;
+
+    let institution_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    // The institution is seeded by the first 32 bytes of its zero padded name
+
+    let name_bytes = checked_string_convt_to_64_bytes(&data.name)?;
+
+    let institution_seeds = vec![
+        INSTITUTION_PDA_SEED,
+        admin_acc.key.as_ref(),
+        name_bytes[..32].as_ref(),
+    ];
+
+    validate_pda(
+        institution_seeds,
+        institution_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    validate_create_institution_accounts(admin_acc, institution_pda_acc)?;
+
+    let create_institution_ix = system_instruction::create_account(
+        admin_acc.key,
+        institution_pda_acc.key,
+        Rent::get()?.minimum_balance(Institution::size()),
+        Institution::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_institution_ix,
+        &[
+            admin_acc.clone(),
+            institution_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            INSTITUTION_PDA_SEED,
+            admin_acc.key.as_ref(),
+            name_bytes[..32].as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    Institution::create_new(institution_pda_acc, admin_acc, data)?;
+
+    Ok(())
+}
+
+// Load an institution and check that the signer is its admin
+
+fn load_institution_as_admin(
+    program_id: &Pubkey,
+    admin_acc: &AccountInfo,
+    institution_pda_acc: &AccountInfo,
+) -> Result<Institution, ProgramError> {
+    if institution_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::InstitutionNotFound.into());
+    }
+
+    let institution = Institution::try_from_slice(&institution_pda_acc.data.borrow())?;
+
+    let institution_seeds = vec![
+        INSTITUTION_PDA_SEED,
+        institution.admin_pubkey.as_ref(),
+        institution.name[..32].as_ref(),
+    ];
+
+    validate_pda(
+        institution_seeds,
+        institution_pda_acc.key,
+        institution.bump,
+        program_id,
+    )?;
+
+    if institution.admin_pubkey.ne(admin_acc.key) {
+        return Err(DeResearcherError::InvalidInstitutionAdmin.into());
+    }
+
+    if !admin_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner.into());
+    }
+
+    if !institution_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount.into());
+    }
+
+    Ok(institution)
+}
+
+// Register an account as a member of the institution
+
+pub fn add_institution_member_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: AddInstitutionMember,
+) -> ProgramResult {
+    msg!("Instruction: AddInstitutionMember");
+    let accounts_iter = &mut accounts.iter();
+
+    let admin_acc = next_account_info(accounts_iter)?;
+
+    let institution_pda_acc = next_account_info(accounts_iter)?;
+
+    let member_acc = next_account_info(accounts_iter)?;
+
+    let institution_member_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    load_institution_as_admin(program_id, admin_acc, institution_pda_acc)?;
+
+    let institution_member_seeds = vec![
+        INSTITUTION_MEMBER_PDA_SEED,
+        institution_pda_acc.key.as_ref(),
+        member_acc.key.as_ref(),
+    ];
+
+    validate_pda(
+        institution_member_seeds,
+        institution_member_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    if !institution_member_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::InstitutionMemberAlreadyExists.into());
+    }
+
+    let create_institution_member_ix = system_instruction::create_account(
+        admin_acc.key,
+        institution_member_pda_acc.key,
+        Rent::get()?.minimum_balance(InstitutionMember::size()),
+        InstitutionMember::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_institution_member_ix,
+        &[
+            admin_acc.clone(),
+            institution_member_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            INSTITUTION_MEMBER_PDA_SEED,
+            institution_pda_acc.key.as_ref(),
+            member_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    InstitutionMember::create_new(
+        institution_member_pda_acc,
+        institution_pda_acc,
+        member_acc,
+        data,
+    )?;
+
+    Institution::add_member(institution_pda_acc)?;
+
+    Ok(())
+}
+
+// Load an institution member and check that it belongs to the institution
+
+fn load_institution_member(
+    program_id: &Pubkey,
+    institution_pubkey: &Pubkey,
+    institution_member_pda_acc: &AccountInfo,
+) -> Result<InstitutionMember, ProgramError> {
+    if institution_member_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::InstitutionMemberNotFound.into());
+    }
+
+    let institution_member =
+        InstitutionMember::try_from_slice(&institution_member_pda_acc.data.borrow())?;
+
+    let institution_member_seeds = vec![
+        INSTITUTION_MEMBER_PDA_SEED,
+        institution_pubkey.as_ref(),
+        institution_member.member_pubkey.as_ref(),
+    ];
+
+    validate_pda(
+        institution_member_seeds,
+        institution_member_pda_acc.key,
+        institution_member.bump,
+        program_id,
+    )?;
+
+    Ok(institution_member)
+}
+
+// Remove a member from the institution and return the member account's rent to the admin
+
+pub fn remove_institution_member_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: RemoveInstitutionMember");
+    let accounts_iter = &mut accounts.iter();
+
+    let admin_acc = next_account_info(accounts_iter)?;
+
+    let institution_pda_acc = next_account_info(accounts_iter)?;
+
+    let institution_member_pda_acc = next_account_info(accounts_iter)?;
+
+    load_institution_as_admin(program_id, admin_acc, institution_pda_acc)?;
+
+    load_institution_member(
+        program_id,
+        institution_pda_acc.key,
+        institution_member_pda_acc,
+    )?;
+
+    if !institution_member_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount.into());
+    }
+
+    close_pda_account(institution_member_pda_acc, admin_acc)?;
+
+    Institution::remove_member(institution_pda_acc)?;
+
+    Ok(())
+}
+
+fn validate_create_institution_license_accounts(
+    licensor_acc: &AccountInfo,
+    licensed_pda_acc: &AccountInfo,
+    institution_license_pda_acc: &AccountInfo,
+    data: &CreateInstitutionLicense,
+    now: i64,
+) -> Result<(), DeResearcherError> {
+    if !licensor_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if licensed_pda_acc.data_is_empty() {
+        return Err(match data.scope {
+            LicenseScope::Paper => DeResearcherError::PaperNotFound,
+            LicenseScope::Journal => DeResearcherError::JournalNotFound,
+        });
+    }
+
+    if !institution_license_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::InstitutionLicenseAlreadyExists);
+    }
+
+    if !institution_license_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if data.expires_at <= now {
+        return Err(DeResearcherError::InvalidInstitutionLicense);
+    }
+
+    Ok(())
+}
+
+// License a paper or a whole journal to an institution, the licensor co-signs the agreed fee
+
+pub fn create_institution_license_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: CreateInstitutionLicense,
+) -> ProgramResult {
+    msg!("Instruction: CreateInstitutionLicense");
+    let accounts_iter = &mut accounts.iter();
+
+    let admin_acc = next_account_info(accounts_iter)?;
+
+    let institution_pda_acc = next_account_info(accounts_iter)?;
+
+    let licensor_acc = next_account_info(accounts_iter)?;
+
+    let licensed_pda_acc = next_account_info(accounts_iter)?;
+
+    let institution_license_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    load_institution_as_admin(program_id, admin_acc, institution_pda_acc)?;
+
+    validate_create_institution_license_accounts(
+        licensor_acc,
+        licensed_pda_acc,
+        institution_license_pda_acc,
+        &data,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let licensor_pubkey = match data.scope {
+        LicenseScope::Paper => {
+            let paper = ResearchPaper::try_from_slice(&licensed_pda_acc.data.borrow())?;
+
+            let paper_seeds = vec![
+                RESEARCH_PAPER_PDA_SEED,
+                paper.paper_content_hash[..32].as_ref(),
+                paper.creator_pubkey.as_ref(),
+            ];
+
+            validate_pda(paper_seeds, licensed_pda_acc.key, paper.bump, program_id)?;
+
+            paper.creator_pubkey
+        }
+        LicenseScope::Journal => load_journal(program_id, licensed_pda_acc)?.authority_pubkey,
+    };
+
+    if licensor_pubkey.ne(licensor_acc.key) {
+        return Err(DeResearcherError::InvalidLicensor.into());
+    }
+
+    let institution_license_seeds = vec![
+        INSTITUTION_LICENSE_PDA_SEED,
+        institution_pda_acc.key.as_ref(),
+        licensed_pda_acc.key.as_ref(),
+    ];
+
+    validate_pda(
+        institution_license_seeds,
+        institution_license_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let create_institution_license_ix = system_instruction::create_account(
+        admin_acc.key,
+        institution_license_pda_acc.key,
+        Rent::get()?.minimum_balance(InstitutionLicense::size()),
+        InstitutionLicense::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_institution_license_ix,
+        &[
+            admin_acc.clone(),
+            institution_license_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            INSTITUTION_LICENSE_PDA_SEED,
+            institution_pda_acc.key.as_ref(),
+            licensed_pda_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    if data.fee > 0 {
+        invoke(
+            &system_instruction::transfer(admin_acc.key, licensor_acc.key, data.fee),
+            &[admin_acc.clone(), licensor_acc.clone()],
+        )?;
+    }
+
+    InstitutionLicense::create_new(
+        institution_license_pda_acc,
+        institution_pda_acc,
+        licensed_pda_acc,
+        data,
+    )?;
+
+    Institution::add_license(institution_pda_acc)?;
+
+    Ok(())
+}
+
+fn validate_claim_licensed_paper_accounts(
+    member_acc: &AccountInfo,
+    research_token_pda_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    institution_license_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !member_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !research_token_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearchTokenAccountAlreadyExists);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if institution_license_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::InstitutionLicenseNotFound);
+    }
+
+    if !institution_license_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Let a member of a licensed institution claim a paper without paying the access fee,
+// the claimed token stops granting access when the license expires
+
+pub fn claim_licensed_paper_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: ClaimLicensedPaper,
+) -> ProgramResult {
+    msg!("Instruction: ClaimLicensedPaper");
+    let accounts_iter = &mut accounts.iter();
+
+    let member_acc = next_account_info(accounts_iter)?;
+
+    let research_token_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let institution_member_pda_acc = next_account_info(accounts_iter)?;
+
+    let institution_license_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    validate_claim_licensed_paper_accounts(
+        member_acc,
+        research_token_pda_acc,
+        paper_pda_acc,
+        institution_license_pda_acc,
+    )?;
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    paper.check_purchasable(now)?;
+
+    let institution_license =
+        InstitutionLicense::try_from_slice(&institution_license_pda_acc.data.borrow())?;
+
+    let institution_license_seeds = vec![
+        INSTITUTION_LICENSE_PDA_SEED,
+        institution_license.institution_pubkey.as_ref(),
+        institution_license.licensed_pubkey.as_ref(),
+    ];
+
+    validate_pda(
+        institution_license_seeds,
+        institution_license_pda_acc.key,
+        institution_license.bump,
+        program_id,
+    )?;
+
+    // A journal license covers every paper submitted to the journal
+
+    let licensed_pubkey = match institution_license.scope {
+        LicenseScope::Paper => *paper_pda_acc.key,
+        LicenseScope::Journal => paper.venue_pubkey,
+    };
+
+    if institution_license.licensed_pubkey.ne(&licensed_pubkey) {
+        return Err(DeResearcherError::InvalidInstitutionLicense.into());
+    }
+
+    let institution_member = load_institution_member(
+        program_id,
+        &institution_license.institution_pubkey,
+        institution_member_pda_acc,
+    )?;
+
+    if institution_member.member_pubkey.ne(member_acc.key) {
+        return Err(DeResearcherError::InstitutionMemberNotFound.into());
+    }
+
+    let res_token_acc_seeds = vec![
+        RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        member_acc.key.as_ref(),
+    ];
+
+    validate_pda(
+        res_token_acc_seeds,
+        research_token_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    InstitutionLicense::claim(institution_license_pda_acc, now)?;
+
+    let create_res_token_acc_ix = system_instruction::create_account(
+        member_acc.key,
+        research_token_pda_acc.key,
+        Rent::get()?.minimum_balance(ResearchTokenAccount::size()),
+        ResearchTokenAccount::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_res_token_acc_ix,
+        &[
+            member_acc.clone(),
+            research_token_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            member_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    ResearchTokenAccount::claim_licensed_paper(
+        research_token_pda_acc,
+        member_acc,
+        paper_pda_acc,
+        data.pda_bump,
+        institution_license.expires_at,
+    )?;
+
+    Ok(())
+}
+
+fn validate_create_subscription_plan_accounts(
+    owner_acc: &AccountInfo,
+    subscription_plan_pda_acc: &AccountInfo,
+    data: &CreateSubscriptionPlan,
+) -> Result<(), DeResearcherError> {
+    if !owner_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !subscription_plan_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::SubscriptionPlanAlreadyExists);
+    }
+
+    if !subscription_plan_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if data.period_secs <= 0 {
+        return Err(DeResearcherError::InvalidSubscriptionPlan);
+    }
+
+    Ok(())
+}
+
+// Offer a subscription to a journal or to everything an author publishes
+
+pub fn create_subscription_plan_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: CreateSubscriptionPlan,
+) -> ProgramResult {
+    msg!("Instruction: CreateSubscriptionPlan");
+    let accounts_iter = &mut accounts.iter();
+
+    let owner_acc = next_account_info(accounts_iter)?;
+
+    let target_acc = next_account_info(accounts_iter)?;
+
+    let subscription_plan_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    validate_create_subscription_plan_accounts(owner_acc, subscription_plan_pda_acc, &data)?;
+
+    let target_owner_pubkey = match data.scope {
+        SubscriptionScope::Journal => load_journal(program_id, target_acc)?.authority_pubkey,
+        SubscriptionScope::Author => *target_acc.key,
+    };
+
+    if target_owner_pubkey.ne(owner_acc.key) {
+        return Err(DeResearcherError::InvalidSubscriptionPlan.into());
+    }
+
+    let subscription_plan_seeds = vec![SUBSCRIPTION_PLAN_PDA_SEED, target_acc.key.as_ref()];
+
+    validate_pda(
+        subscription_plan_seeds,
+        subscription_plan_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let create_subscription_plan_ix = system_instruction::create_account(
+        owner_acc.key,
+        subscription_plan_pda_acc.key,
+        Rent::get()?.minimum_balance(SubscriptionPlan::size()),
+        SubscriptionPlan::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_subscription_plan_ix,
+        &[
+            owner_acc.clone(),
+            subscription_plan_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            SUBSCRIPTION_PLAN_PDA_SEED,
+            target_acc.key.as_ref(),
+            &[data.pda_bump],
+        ]],
+    )?;
+
+    SubscriptionPlan::create_new(subscription_plan_pda_acc, owner_acc, target_acc, data)?;
+
+    Ok(())
+}
+
+fn validate_subscribe_accounts(
+    subscriber_acc: &AccountInfo,
+    subscription_plan_pda_acc: &AccountInfo,
+    subscription_pda_acc: &AccountInfo,
+    data: &Subscribe,
+) -> Result<(), DeResearcherError> {
+    if !subscriber_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if subscription_plan_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::SubscriptionPlanNotFound);
+    }
+
+    if !subscription_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if data.periods == 0 {
+        return Err(DeResearcherError::InvalidSubscriptionPeriod);
+    }
+
+    Ok(())
+}
+
+// Buy or renew a subscription, a renewal extends from the current expiry if it is still active
+
+pub fn subscribe_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: Subscribe,
+) -> ProgramResult {
+    msg!("Instruction: Subscribe");
+    let accounts_iter = &mut accounts.iter();
+
+    let subscriber_acc = next_account_info(accounts_iter)?;
+
+    let subscription_plan_pda_acc = next_account_info(accounts_iter)?;
+
+    let plan_owner_acc = next_account_info(accounts_iter)?;
+
+    let subscription_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    validate_subscribe_accounts(
+        subscriber_acc,
+        subscription_plan_pda_acc,
+        subscription_pda_acc,
+        &data,
+    )?;
+
+    let subscription_plan =
+        SubscriptionPlan::try_from_slice(&subscription_plan_pda_acc.data.borrow())?;
+
+    let subscription_plan_seeds = vec![
+        SUBSCRIPTION_PLAN_PDA_SEED,
+        subscription_plan.target_pubkey.as_ref(),
+    ];
+
+    validate_pda(
+        subscription_plan_seeds,
+        subscription_plan_pda_acc.key,
+        subscription_plan.bump,
+        program_id,
+    )?;
+
+    if plan_owner_acc.key.ne(&subscription_plan.owner_pubkey) {
+        return Err(DeResearcherError::InvalidFeeReceiver.into());
+    }
+
+    let subscription_seeds = vec![
+        SUBSCRIPTION_PDA_SEED,
+        subscriber_acc.key.as_ref(),
+        subscription_plan.target_pubkey.as_ref(),
+    ];
+
+    validate_pda(
+        subscription_seeds,
+        subscription_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let duration_secs = subscription_plan
+        .period_secs
+        .checked_mul(data.periods as i64)
+        .ok_or(DeResearcherError::SizeOverflow)?;
+
+    let price = subscription_plan
+        .price_per_period
+        .checked_mul(data.periods as u64)
+        .ok_or(DeResearcherError::SizeOverflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    if subscription_pda_acc.data_is_empty() {
+        let create_subscription_ix = system_instruction::create_account(
+            subscriber_acc.key,
+            subscription_pda_acc.key,
+            Rent::get()?.minimum_balance(Subscription::size()),
+            Subscription::size() as u64,
+            program_id,
+        );
+
+        invoke_signed(
+            &create_subscription_ix,
+            &[
+                subscriber_acc.clone(),
+                subscription_pda_acc.clone(),
+                system_program_acc.clone(),
+            ],
+            &[&[
+                SUBSCRIPTION_PDA_SEED,
+                subscriber_acc.key.as_ref(),
+                subscription_plan.target_pubkey.as_ref(),
+                &[data.pda_bump],
+            ]],
+        )?;
+
+        Subscription::create_new(
+            subscription_pda_acc,
+            subscriber_acc,
+            subscription_plan_pda_acc,
+            &subscription_plan,
+            data.pda_bump,
+            now,
+            duration_secs,
+        )?;
+    } else {
+        Subscription::renew(subscription_pda_acc, now, duration_secs)?;
+    }
+
+    if price > 0 {
+        invoke(
+            &system_instruction::transfer(subscriber_acc.key, plan_owner_acc.key, price),
+            &[subscriber_acc.clone(), plan_owner_acc.clone()],
+        )?;
+    }
+
+    SubscriptionPlan::add_subscription(subscription_plan_pda_acc)?;
+
+    Ok(())
+}
+
+// Check that a reader holds the paper's research token or an active subscription covering it
+
+pub fn verify_access_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: VerifyAccess,
+) -> ProgramResult {
+    msg!("Instruction: VerifyAccess");
+    let accounts_iter = &mut accounts.iter();
+
+    let reader_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let access_pda_acc = next_account_info(accounts_iter)?;
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound.into());
+    }
+
+    if access_pda_acc.data_is_empty() || access_pda_acc.owner.ne(program_id) {
+        return Err(DeResearcherError::AccessDenied.into());
+    }
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    match data.proof {
+        AccessProof::ResearchToken => {
+            let research_token =
+                ResearchTokenAccount::from_account_data(&access_pda_acc.data.borrow())?;
+
+            let res_token_acc_seeds = vec![
+                RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+                paper_pda_acc.key.as_ref(),
+                reader_acc.key.as_ref(),
+            ];
+
+            validate_pda(
+                res_token_acc_seeds,
+                access_pda_acc.key,
+                research_token.bump,
+                program_id,
+            )?;
+
+            if research_token.is_expired(Clock::get()?.unix_timestamp) {
+                return Err(match research_token.kind {
+                    AccessKind::Licensed => DeResearcherError::InstitutionLicenseExpired,
+                    _ => DeResearcherError::RentalExpired,
+                }
+                .into());
+            }
+        }
+        AccessProof::Subscription => {
+            let subscription = Subscription::try_from_slice(&access_pda_acc.data.borrow())?;
+
+            let subscription_seeds = vec![
+                SUBSCRIPTION_PDA_SEED,
+                reader_acc.key.as_ref(),
+                subscription.target_pubkey.as_ref(),
+            ];
+
+            validate_pda(
+                subscription_seeds,
+                access_pda_acc.key,
+                subscription.bump,
+                program_id,
+            )?;
+
+            // Journal subscriptions cover the journal's papers, author subscriptions the author's
+
+            let covered_pubkey = match subscription.scope {
+                SubscriptionScope::Journal => paper.venue_pubkey,
+                SubscriptionScope::Author => paper.creator_pubkey,
+            };
+
+            if subscription.target_pubkey.ne(&covered_pubkey) {
+                return Err(DeResearcherError::AccessDenied.into());
+            }
+
+            let now = Clock::get()?.unix_timestamp;
+
+            if !subscription.is_active(now) {
+                return Err(DeResearcherError::SubscriptionExpired.into());
+            }
+
+            if !paper.is_published(now) {
+                return Err(DeResearcherError::AccessDenied.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_rental_payment_accounts(
+    researcher_acc: &AccountInfo,
+    research_token_pda_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    fee_receiver_acc: &AccountInfo,
+    reviewer_pool_pda_acc: &AccountInfo,
+) -> Result<(), DeResearcherError> {
+    if !researcher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
+
+    if !research_token_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if reviewer_pool_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewerPoolNotFound);
+    }
+
+    if !fee_receiver_acc.is_writable || !reviewer_pool_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
+
+    Ok(())
+}
+
+// Load a paper that offers rentals together with its reviewer pool
+
+fn load_rental_paper(
+    program_id: &Pubkey,
+    paper_pda_acc: &AccountInfo,
+    fee_receiver_acc: &AccountInfo,
+    reviewer_pool_pda_acc: &AccountInfo,
+) -> Result<ResearchPaper, ProgramError> {
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    if fee_receiver_acc.key.ne(&paper.creator_pubkey) {
+        return Err(DeResearcherError::InvalidFeeReceiver.into());
+    }
+
+    let reviewer_pool = ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.data.borrow())?;
+
+    let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda_acc.key.as_ref()];
+
+    validate_pda(
+        reviewer_pool_seeds,
+        reviewer_pool_pda_acc.key,
+        reviewer_pool.bump,
+        program_id,
+    )?;
+
+    if paper.rental_period_secs == 0 {
+        return Err(DeResearcherError::RentalsDisabled.into());
+    }
+
+    Ok(paper)
+}
+
+// Rent access to a paper for the paper's rental period
+
+pub fn rent_research_paper_ix(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: RentResearchPaper,
+) -> ProgramResult {
+    msg!("Instruction: RentResearchPaper");
+    let accounts_iter = &mut accounts.iter();
+
+    let researcher_acc = next_account_info(accounts_iter)?;
+
+    let research_token_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let fee_receiver_acc = next_account_info(accounts_iter)?;
+
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
+
+    let system_program_acc = next_account_info(accounts_iter)?;
+
+    validate_rental_payment_accounts(
+        researcher_acc,
+        research_token_pda_acc,
+        paper_pda_acc,
+        fee_receiver_acc,
+        reviewer_pool_pda_acc,
+    )?;
+
+    if !research_token_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearchTokenAccountAlreadyExists.into());
+    }
+
+    let paper = load_rental_paper(
+        program_id,
+        paper_pda_acc,
+        fee_receiver_acc,
+        reviewer_pool_pda_acc,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    paper.check_purchasable(now)?;
+
+    let res_token_acc_seeds = vec![
+        RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        researcher_acc.key.as_ref(),
+    ];
+
+    validate_pda(
+        res_token_acc_seeds,
+        research_token_pda_acc.key,
+        data.pda_bump,
+        program_id,
+    )?;
+
+    let rental_fee = paper.current_rental_fee(Clock::get()?.unix_timestamp);
+
+    if rental_fee > data.max_price {
+        return Err(DeResearcherError::MaxPriceExceeded.into());
+    }
+
+    let create_res_token_acc_ix = system_instruction::create_account(
         researcher_acc.key,
-        researcher_profile_pda_acc.key,
-        rent_exempt,
-        ResearcherProfile::size() as u64,
+        research_token_pda_acc.key,
+        Rent::get()?.minimum_balance(ResearchTokenAccount::size()),
+        ResearchTokenAccount::size() as u64,
         program_id,
     );
 
     invoke_signed(
-        &create_researcher_profile_ix,
+        &create_res_token_acc_ix,
         &[
             researcher_acc.clone(),
-            researcher_profile_pda_acc.clone(),
+            research_token_pda_acc.clone(),
             system_program_acc.clone(),
         ],
         &[&[
-            RESEARCHER_PROFILE_PDA_SEED,
+            RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
             researcher_acc.key.as_ref(),
             &[data.pda_bump],
         ]],
     )?;
 
-    ResearcherProfile::create_new(researcher_profile_pda_acc, researcher_acc.key, data)?;
+    pay_access_fee(
+        researcher_acc,
+        fee_receiver_acc,
+        reviewer_pool_pda_acc,
+        &paper,
+        rental_fee,
+    )?;
+
+    ResearchTokenAccount::rent_paper(
+        research_token_pda_acc,
+        researcher_acc,
+        paper_pda_acc,
+        &paper,
+        data.pda_bump,
+        now,
+    )?;
 
     Ok(())
 }
 
-fn validate_create_research_paper_accounts(
-    publisher_acc: &AccountInfo,
-    researcher_profile_pda_acc: &AccountInfo,
-    paper_pda_acc: &AccountInfo,
-) -> Result<(), DeResearcherError> {
-    if !publisher_acc.is_signer {
-        return Err(DeResearcherError::InvalidSigner);
-    }
+// Load the signer's research token and check that it is a rental
 
-    if researcher_profile_pda_acc.data_is_empty() {
-        return Err(DeResearcherError::ResearcherProfileNotFound);
+fn load_rental(
+    program_id: &Pubkey,
+    researcher_acc: &AccountInfo,
+    research_token_pda_acc: &AccountInfo,
+) -> Result<ResearchTokenAccount, ProgramError> {
+    if research_token_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearchTokenAccountNotFound.into());
     }
 
-    if !paper_pda_acc.data_is_empty() {
-        return Err(DeResearcherError::PaperAlreadyExists);
+    let research_token =
+        ResearchTokenAccount::from_account_data(&research_token_pda_acc.data.borrow())?;
+
+    let res_token_acc_seeds = vec![
+        RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+        research_token.paper_pubkey.as_ref(),
+        researcher_acc.key.as_ref(),
+    ];
+
+    validate_pda(
+        res_token_acc_seeds,
+        research_token_pda_acc.key,
+        research_token.bump,
+        program_id,
+    )?;
+
+    if research_token.kind != AccessKind::Rental {
+        return Err(DeResearcherError::NotARental.into());
     }
 
-    Ok(())
+    Ok(research_token)
 }
 
-// Create a new research paper
+// Extend a rental by another rental period at the paper's current rental fee
 
-pub fn create_research_paper_ix(
+pub fn extend_rental_ix(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    data: CreateResearchePaper,
+    data: ExtendRental,
 ) -> ProgramResult {
-    msg!("Instruction: CreateResearchePaper");
+    msg!("Instruction: ExtendRental");
     let accounts_iter = &mut accounts.iter();
 
-    let publisher_acc = next_account_info(accounts_iter)?;
+    let researcher_acc = next_account_info(accounts_iter)?;
 
-    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+    let research_token_pda_acc = next_account_info(accounts_iter)?;
 
     let paper_pda_acc = next_account_info(accounts_iter)?;
 
-    let paper_pda = paper_pda_acc.key;
-
-    let paper_seeds: Vec<&[u8]> = vec![
-        RESEARCH_PAPER_PDA_SEED,
-        data.paper_content_hash[..32].as_ref(),
-        publisher_acc.key.as_ref(),
-    ];
+    let fee_receiver_acc = next_account_info(accounts_iter)?;
 
-    validate_pda(paper_seeds, paper_pda, data.pda_bump, program_id)?;
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
 
-    let researcher_profile_seeds = vec![RESEARCHER_PROFILE_PDA_SEED, publisher_acc.key.as_ref()];
+    validate_rental_payment_accounts(
+        researcher_acc,
+        research_token_pda_acc,
+        paper_pda_acc,
+        fee_receiver_acc,
+        reviewer_pool_pda_acc,
+    )?;
 
-    let researcher_profile_pda = researcher_profile_pda_acc.key;
+    let research_token = load_rental(program_id, researcher_acc, research_token_pda_acc)?;
 
-    let researcher_profile =
-        ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.data.borrow())?;
+    if research_token.paper_pubkey.ne(paper_pda_acc.key) {
+        return Err(DeResearcherError::PaperNotFound.into());
+    }
 
-    validate_pda(
-        researcher_profile_seeds,
-        researcher_profile_pda,
-        researcher_profile.bump,
+    let paper = load_rental_paper(
         program_id,
-    )?;
-
-    validate_create_research_paper_accounts(
-        publisher_acc,
-        researcher_profile_pda_acc,
         paper_pda_acc,
+        fee_receiver_acc,
+        reviewer_pool_pda_acc,
     )?;
 
-    let rent = Rent::get()?;
+    let now = Clock::get()?.unix_timestamp;
 
-    let rent_exempt = rent.minimum_balance(ResearchPaper::size());
+    paper.check_purchasable(now)?;
 
-    let create_researche_paper_ix = system_instruction::create_account(
-        publisher_acc.key,
-        paper_pda_acc.key,
-        rent_exempt,
-        ResearchPaper::size() as u64,
-        program_id,
-    );
+    let rental_fee = paper.current_rental_fee(Clock::get()?.unix_timestamp);
 
-    let system_program_acc = next_account_info(accounts_iter)?;
+    if rental_fee > data.max_price {
+        return Err(DeResearcherError::MaxPriceExceeded.into());
+    }
 
-    invoke_signed(
-        &create_researche_paper_ix,
-        &[
-            publisher_acc.clone(),
-            paper_pda_acc.clone(),
-            system_program_acc.clone(),
-        ],
-        &[&[
-            RESEARCH_PAPER_PDA_SEED,
-            data.paper_content_hash[..32].as_ref(),
-            publisher_acc.key.as_ref(),
-            &[data.pda_bump],
-        ]],
+    pay_access_fee(
+        researcher_acc,
+        fee_receiver_acc,
+        reviewer_pool_pda_acc,
+        &paper,
+        rental_fee,
     )?;
 
-    ResearchPaper::create_new(
-        paper_pda_acc,
-        researcher_profile_pda_acc,
-        publisher_acc,
-        data,
-    )?;
+    ResearchTokenAccount::extend_rental(research_token_pda_acc, paper.rental_period_secs, now)?;
+
     Ok(())
 }
 
-fn validate_publish_paper_accounts(
-    publisher_acc: &AccountInfo,
-    paper_pda_acc: &AccountInfo,
-    paper_pda: &Pubkey,
-) -> Result<(), DeResearcherError> {
-    if !publisher_acc.is_signer {
-        return Err(DeResearcherError::InvalidSigner);
+// Close an expired rental and return its rent to the holder
+
+pub fn close_rental_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: CloseRental");
+    let accounts_iter = &mut accounts.iter();
+
+    let researcher_acc = next_account_info(accounts_iter)?;
+
+    let research_token_pda_acc = next_account_info(accounts_iter)?;
+
+    if !researcher_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner.into());
     }
 
-    if paper_pda_acc.data_is_empty() {
-        return Err(DeResearcherError::PaperNotFound);
+    if !research_token_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount.into());
     }
 
-    if paper_pda.ne(paper_pda_acc.key) {
-        return Err(DeResearcherError::PubkeyMismatch.into());
+    let research_token = load_rental(program_id, researcher_acc, research_token_pda_acc)?;
+
+    if !research_token.is_expired(Clock::get()?.unix_timestamp) {
+        return Err(DeResearcherError::RentalNotExpired.into());
     }
 
+    close_pda_account(research_token_pda_acc, researcher_acc)?;
+
     Ok(())
 }
 
-// Publish a research paper
+// Load a mint escrow and check that it holds the payment for the given research token
 
-pub fn publish_paper_ix(
+fn load_mint_escrow(
     program_id: &Pubkey,
-    accounts: &[AccountInfo],
-    data: PublishPaper,
-) -> ProgramResult {
-    msg!("Instruction: PublishPaper");
-    let accounts_iter = &mut accounts.iter();
-
-    let publisher_acc = next_account_info(accounts_iter)?;
-
-    let paper_pda_acc = next_account_info(accounts_iter)?;
+    mint_escrow_pda_acc: &AccountInfo,
+) -> Result<MintEscrow, ProgramError> {
+    if mint_escrow_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::MintEscrowNotFound.into());
+    }
 
-    let paper_pda = paper_pda_acc.key;
+    if !mint_escrow_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount.into());
+    }
 
-    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+    let mint_escrow = MintEscrow::try_from_slice(&mint_escrow_pda_acc.data.borrow())?;
 
-    let seeds: Vec<&[u8]> = vec![
-        RESEARCH_PAPER_PDA_SEED,
-        paper.paper_content_hash[..32].as_ref(),
-        publisher_acc.key.as_ref(),
+    let mint_escrow_seeds = vec![
+        MINT_ESCROW_PDA_SEED,
+        mint_escrow.research_token_pubkey.as_ref(),
     ];
 
-    validate_pda(seeds, paper_pda, data.pda_bump, program_id)?;
-    validate_publish_paper_accounts(publisher_acc, paper_pda_acc, &paper_pda)?;
-
-    ResearchPaper::publish_paper(paper_pda_acc, publisher_acc)?;
+    validate_pda(
+        mint_escrow_seeds,
+        mint_escrow_pda_acc.key,
+        mint_escrow.bump,
+        program_id,
+    )?;
 
-    Ok(())
+    Ok(mint_escrow)
 }
 
-fn validate_add_peer_review_accounts(
-    reviewer_acc: &AccountInfo,
-    researcher_profile_pda_acc: &AccountInfo,
+fn validate_request_refund_accounts(
+    buyer_acc: &AccountInfo,
+    research_token_pda_acc: &AccountInfo,
     paper_pda_acc: &AccountInfo,
-    peer_review_pda_acc: &AccountInfo,
+    researcher_profile_pda_acc: &AccountInfo,
+    mint_escrow: &MintEscrow,
 ) -> Result<(), DeResearcherError> {
-    if !reviewer_acc.is_signer {
+    if !buyer_acc.is_signer {
         return Err(DeResearcherError::InvalidSigner);
     }
 
-    if researcher_profile_pda_acc.data_is_empty() {
-        return Err(DeResearcherError::ResearcherProfileNotFound);
+    if research_token_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearchTokenAccountNotFound);
     }
 
-    if !peer_review_pda_acc.data_is_empty() {
-        return Err(DeResearcherError::PeerReviewAlreadyExists);
+    if researcher_profile_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ResearcherProfileNotFound);
     }
 
-    if paper_pda_acc.data_is_empty() {
-        return Err(DeResearcherError::PaperNotFound);
+    if !research_token_pda_acc.is_writable
+        || !paper_pda_acc.is_writable
+        || !researcher_profile_pda_acc.is_writable
+    {
+        return Err(DeResearcherError::ImmutableAccount);
     }
 
-    if !peer_review_pda_acc.is_writable {
-        return Err(DeResearcherError::ImmutableAccount);
+    if mint_escrow.buyer_pubkey.ne(buyer_acc.key)
+        || mint_escrow
+            .research_token_pubkey
+            .ne(research_token_pda_acc.key)
+        || mint_escrow.paper_pubkey.ne(paper_pda_acc.key)
+        || mint_escrow
+            .researcher_profile_pubkey
+            .ne(researcher_profile_pda_acc.key)
+    {
+        return Err(DeResearcherError::InvalidMintEscrow);
     }
 
     Ok(())
 }
 
-fn validate_researcher_for_peer_review(
-    _researcher_profile: &ResearcherProfile,
-) -> Result<(), DeResearcherError> {
-    // TODO: add this check
-    // if researcher_profile.state != ResearcherProfileState::Approved {
-    //     return Err(DeResearcherError::NotAllowedForPeerReview);
-    // }
+// Refund an escrowed mint within the refund window, the research token is closed
+
+pub fn request_refund_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: RequestRefund");
+    let accounts_iter = &mut accounts.iter();
+
+    let buyer_acc = next_account_info(accounts_iter)?;
+
+    let research_token_pda_acc = next_account_info(accounts_iter)?;
+
+    let paper_pda_acc = next_account_info(accounts_iter)?;
+
+    let mint_escrow_pda_acc = next_account_info(accounts_iter)?;
+
+    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+
+    let mint_escrow = load_mint_escrow(program_id, mint_escrow_pda_acc)?;
+
+    validate_request_refund_accounts(
+        buyer_acc,
+        research_token_pda_acc,
+        paper_pda_acc,
+        researcher_profile_pda_acc,
+        &mint_escrow,
+    )?;
+
+    if Clock::get()?.unix_timestamp >= mint_escrow.refund_deadline {
+        return Err(DeResearcherError::RefundWindowClosed.into());
+    }
+
+    close_pda_account(mint_escrow_pda_acc, buyer_acc)?;
+
+    close_pda_account(research_token_pda_acc, buyer_acc)?;
+
+    ResearchPaper::refund_mint(paper_pda_acc, researcher_profile_pda_acc)?;
 
     Ok(())
 }
 
-// Add a peer review to a research paper
+fn validate_release_escrow_accounts(
+    author_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    reviewer_pool_pda_acc: &AccountInfo,
+    buyer_acc: &AccountInfo,
+    mint_escrow: &MintEscrow,
+) -> Result<(), DeResearcherError> {
+    if !author_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
+    }
 
-pub fn add_peer_review_ix(
-    program_id: &Pubkey,
-    accounts: &[AccountInfo],
-    data: AddPeerReview,
-) -> ProgramResult {
-    msg!("Instruction: AddPeerReview");
-    let accounts_iter = &mut accounts.iter();
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
+    }
+
+    if reviewer_pool_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewerPoolNotFound);
+    }
 
-    let reviewer_acc = next_account_info(accounts_iter)?;
+    if !reviewer_pool_pda_acc.is_writable || !buyer_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
+    }
 
-    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+    if mint_escrow.paper_pubkey.ne(paper_pda_acc.key) || mint_escrow.buyer_pubkey.ne(buyer_acc.key)
+    {
+        return Err(DeResearcherError::InvalidMintEscrow);
+    }
 
-    let paper_pda_acc = next_account_info(accounts_iter)?;
+    Ok(())
+}
 
-    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+// Pay out an escrowed mint once its refund window has passed
 
-    let researcher_profile_pda = researcher_profile_pda_acc.key;
+pub fn release_escrow_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: ReleaseEscrow");
+    let accounts_iter = &mut accounts.iter();
 
-    let paper_pda = paper_pda_acc.key;
+    let author_acc = next_account_info(accounts_iter)?;
 
-    let researcher_profile =
-        ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.data.borrow())?;
+    let paper_pda_acc = next_account_info(accounts_iter)?;
 
-    validate_researcher_for_peer_review(&researcher_profile)?;
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
 
-    let peer_review_pda = peer_review_pda_acc.key;
+    let buyer_acc = next_account_info(accounts_iter)?;
 
-    let peer_review_seeds = vec![
-        PEER_REVIEW_PDA_SEED,
-        paper_pda_acc.key.as_ref(),
-        reviewer_acc.key.as_ref(),
-    ];
+    let mint_escrow_pda_acc = next_account_info(accounts_iter)?;
 
-    validate_pda(
-        peer_review_seeds,
-        peer_review_pda,
-        data.pda_bump,
-        program_id,
+    let mint_escrow = load_mint_escrow(program_id, mint_escrow_pda_acc)?;
+
+    validate_release_escrow_accounts(
+        author_acc,
+        paper_pda_acc,
+        reviewer_pool_pda_acc,
+        buyer_acc,
+        &mint_escrow,
     )?;
 
     let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
 
-    if paper.creator_pubkey.eq(reviewer_acc.key) {
-        return Err(DeResearcherError::PublisherCannotAddPeerReview.into());
-    }
-
     let paper_seeds = vec![
         RESEARCH_PAPER_PDA_SEED,
         paper.paper_content_hash[..32].as_ref(),
-        paper.creator_pubkey.as_ref(),
+        author_acc.key.as_ref(),
     ];
 
-    validate_pda(paper_seeds, paper_pda, paper.bump, program_id)?;
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
 
-    let researcher_profile_seeds = vec![RESEARCHER_PROFILE_PDA_SEED, reviewer_acc.key.as_ref()];
+    let reviewer_pool = ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.data.borrow())?;
 
-    let researcher_profile =
-        ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.data.borrow())?;
+    let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda_acc.key.as_ref()];
 
     validate_pda(
-        researcher_profile_seeds,
-        researcher_profile_pda,
-        researcher_profile.bump,
+        reviewer_pool_seeds,
+        reviewer_pool_pda_acc.key,
+        reviewer_pool.bump,
         program_id,
     )?;
 
-    validate_add_peer_review_accounts(
-        reviewer_acc,
-        researcher_profile_pda_acc,
-        paper_pda_acc,
-        peer_review_pda_acc,
-    )?;
+    if Clock::get()?.unix_timestamp < mint_escrow.refund_deadline {
+        return Err(DeResearcherError::RefundWindowOpen.into());
+    }
 
-    let rent = Rent::get()?;
+    let reviewer_share = paper.reviewer_share(mint_escrow.amount);
 
-    let rent_exempt = rent.minimum_balance(PeerReview::size());
+    let creator_share = mint_escrow.amount - reviewer_share;
 
-    let create_peer_review_ix = system_instruction::create_account(
-        reviewer_acc.key,
-        peer_review_pda_acc.key,
-        rent_exempt,
-        PeerReview::size() as u64,
-        program_id,
-    );
+    if reviewer_share > 0 {
+        transfer_lamports_from_pda(mint_escrow_pda_acc, reviewer_pool_pda_acc, reviewer_share)?;
 
-    let system_program_acc = next_account_info(accounts_iter)?;
+        ReviewerPool::deposit(reviewer_pool_pda_acc, reviewer_share)?;
+    }
 
-    invoke_signed(
-        &create_peer_review_ix,
-        &[
-            reviewer_acc.clone(),
-            peer_review_pda_acc.clone(),
-            system_program_acc.clone(),
-        ],
-        &[&[
-            PEER_REVIEW_PDA_SEED,
-            paper_pda_acc.key.as_ref(),
-            reviewer_acc.key.as_ref(),
-            &[data.pda_bump],
-        ]],
-    )?;
+    transfer_lamports_from_pda(mint_escrow_pda_acc, author_acc, creator_share)?;
 
-    PeerReview::create_new(
-        peer_review_pda_acc,
-        reviewer_acc,
-        paper_pda_acc,
-        researcher_profile_pda_acc,
-        data,
-    )?;
+    // Only the escrow's rent is left, it goes back to the buyer who paid it
+
+    close_pda_account(mint_escrow_pda_acc, buyer_acc)?;
 
     Ok(())
 }
 
-fn validate_mint_res_paper_accounts(
-    researcher_acc: &AccountInfo,
-    researcher_profile_pda_acc: &AccountInfo,
+// Top up an account being grown to a new layout so it stays rent exempt
+
+fn fund_migration<'a>(
+    payer_acc: &AccountInfo<'a>,
+    pda_acc: &AccountInfo<'a>,
+    size: usize,
+) -> ProgramResult {
+    let shortfall = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(pda_acc.lamports());
+
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer_acc.key, pda_acc.key, shortfall),
+            &[payer_acc.clone(), pda_acc.clone()],
+        )?;
+    }
+
+    Ok(())
+}
+
+fn validate_migrate_research_paper_accounts(
+    publisher_acc: &AccountInfo,
     paper_pda_acc: &AccountInfo,
-    fee_receiver_acc: &AccountInfo,
-    paper: &ResearchPaper,
+    reviewer_pool_pda_acc: &AccountInfo,
+    paper_version_pda_acc: &AccountInfo,
 ) -> Result<(), DeResearcherError> {
-    if !researcher_acc.is_signer {
+    if !publisher_acc.is_signer {
         return Err(DeResearcherError::InvalidSigner);
     }
 
-    if researcher_profile_pda_acc.data_is_empty() {
-        return Err(DeResearcherError::ResearcherProfileNotFound);
-    }
-
     if paper_pda_acc.data_is_empty() {
         return Err(DeResearcherError::PaperNotFound);
     }
 
-    if fee_receiver_acc.key.ne(&paper.creator_pubkey) {
-        return Err(DeResearcherError::InvalidFeeReceiver);
+    if paper_pda_acc.data_len() != ResearchPaper::legacy_size() {
+        return Err(DeResearcherError::AccountAlreadyMigrated);
+    }
+
+    if !reviewer_pool_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::ReviewerPoolAlreadyExists);
+    }
+
+    if !paper_version_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperVersionAlreadyExists);
+    }
+
+    if !paper_pda_acc.is_writable
+        || !reviewer_pool_pda_acc.is_writable
+        || !paper_version_pda_acc.is_writable
+    {
+        return Err(DeResearcherError::ImmutableAccount);
     }
 
     Ok(())
 }
 
-// Mint a research paper
+// Grow a paper created before the current layout, and create the reviewer pool and version
+// record every paper has had since
 
-pub fn mint_res_paper_ix(
+pub fn migrate_research_paper_ix(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    data: MintResearchPaper,
+    data: MigrateResearchPaper,
 ) -> ProgramResult {
-    msg!("Instruction: MintResearchPaper");
+    msg!("Instruction: MigrateResearchPaper");
     let accounts_iter = &mut accounts.iter();
 
-    let researcher_acc = next_account_info(accounts_iter)?;
+    let publisher_acc = next_account_info(accounts_iter)?;
 
-    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+    let paper_pda_acc = next_account_info(accounts_iter)?;
 
-    let research_token_pda_acc = next_account_info(accounts_iter)?;
+    let reviewer_pool_pda_acc = next_account_info(accounts_iter)?;
 
-    let paper_pda_acc = next_account_info(accounts_iter)?;
+    let paper_version_pda_acc = next_account_info(accounts_iter)?;
 
-    let research_token_pda = research_token_pda_acc.key;
+    let system_program_acc = next_account_info(accounts_iter)?;
 
-    let res_token_acc_seeds = vec![
-        RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
-        paper_pda_acc.key.as_ref(),
-        researcher_acc.key.as_ref(),
+    validate_migrate_research_paper_accounts(
+        publisher_acc,
+        paper_pda_acc,
+        reviewer_pool_pda_acc,
+        paper_version_pda_acc,
+    )?;
+
+    if paper_pda_acc.owner.ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let paper = ResearchPaper::from_account_data(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        publisher_acc.key.as_ref(),
     ];
 
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let reviewer_pool_seeds = vec![REVIEWER_POOL_PDA_SEED, paper_pda_acc.key.as_ref()];
+
     validate_pda(
-        res_token_acc_seeds,
-        research_token_pda,
-        data.pda_bump,
+        reviewer_pool_seeds,
+        reviewer_pool_pda_acc.key,
+        data.reviewer_pool_pda_bump,
         program_id,
     )?;
 
-    let fee_receiver_acc = next_account_info(accounts_iter)?;
+    let first_version = [0];
 
-    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+    let paper_version_seeds = vec![
+        PAPER_VERSION_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        first_version.as_ref(),
+    ];
 
-    validate_mint_res_paper_accounts(
-        researcher_acc,
-        researcher_profile_pda_acc,
-        paper_pda_acc,
-        fee_receiver_acc,
-        &paper,
+    validate_pda(
+        paper_version_seeds,
+        paper_version_pda_acc.key,
+        data.paper_version_pda_bump,
+        program_id,
     )?;
 
-    if !research_token_pda_acc.data_is_empty() {
-        return Err(DeResearcherError::ResearchTokenAccountAlreadyExists.into());
-    }
+    fund_migration(publisher_acc, paper_pda_acc, ResearchPaper::size())?;
 
-    let create_res_token_acc_ix = system_instruction::create_account(
-        researcher_acc.key,
-        research_token_pda,
-        Rent::get()?.minimum_balance(ResearchTokenAccount::size() as usize),
-        ResearchTokenAccount::size() as u64,
+    ResearchPaper::migrate(paper_pda_acc)?;
+
+    let rent = Rent::get()?;
+
+    let create_reviewer_pool_ix = system_instruction::create_account(
+        publisher_acc.key,
+        reviewer_pool_pda_acc.key,
+        rent.minimum_balance(ReviewerPool::size()),
+        ReviewerPool::size() as u64,
         program_id,
     );
 
-    let system_program_acc = next_account_info(accounts_iter)?;
     invoke_signed(
-        &create_res_token_acc_ix,
+        &create_reviewer_pool_ix,
         &[
-            researcher_acc.clone(),
-            research_token_pda_acc.clone(),
+            publisher_acc.clone(),
+            reviewer_pool_pda_acc.clone(),
             system_program_acc.clone(),
         ],
         &[&[
-            RESEARCH_TOKEN_ACCOUNT_PDA_SEED,
+            REVIEWER_POOL_PDA_SEED,
             paper_pda_acc.key.as_ref(),
-            researcher_acc.key.as_ref(),
-            &[data.pda_bump],
+            &[data.reviewer_pool_pda_bump],
         ]],
     )?;
 
-    if paper.access_fee > 0 {
-        invoke(
-            &system_instruction::transfer(
-                researcher_acc.key,
-                &paper.creator_pubkey,
-                paper.access_fee as u64,
-            ),
-            &[researcher_acc.clone(), fee_receiver_acc.clone()],
-        )?;
-    }
+    ReviewerPool::create_new(
+        reviewer_pool_pda_acc,
+        paper_pda_acc,
+        data.reviewer_pool_pda_bump,
+    )?;
 
-    ResearchTokenAccount::mint_paper(
-        research_token_pda_acc,
-        researcher_acc,
+    let create_paper_version_ix = system_instruction::create_account(
+        publisher_acc.key,
+        paper_version_pda_acc.key,
+        rent.minimum_balance(PaperVersion::size()),
+        PaperVersion::size() as u64,
+        program_id,
+    );
+
+    invoke_signed(
+        &create_paper_version_ix,
+        &[
+            publisher_acc.clone(),
+            paper_version_pda_acc.clone(),
+            system_program_acc.clone(),
+        ],
+        &[&[
+            PAPER_VERSION_PDA_SEED,
+            paper_pda_acc.key.as_ref(),
+            first_version.as_ref(),
+            &[data.paper_version_pda_bump],
+        ]],
+    )?;
+
+    PaperVersion::create_new(
+        paper_version_pda_acc,
         paper_pda_acc,
-        researcher_profile_pda_acc,
-        data,
+        0,
+        paper.paper_content_hash,
+        paper.meta_data_merkle_root,
+        data.paper_version_pda_bump,
     )?;
 
     Ok(())
 }
 
-pub fn validate_check_and_assign_reputation_accounts(
-    reputation_checker_acc: &AccountInfo,
-    researcher_profile_acc: &AccountInfo,
+fn validate_migrate_peer_review_accounts(
+    payer_acc: &AccountInfo,
+    paper_pda_acc: &AccountInfo,
+    peer_review_pda_acc: &AccountInfo,
 ) -> Result<(), DeResearcherError> {
-    if researcher_profile_acc.data_is_empty() {
-        return Err(DeResearcherError::ResearcherProfileNotFound);
+    if !payer_acc.is_signer {
+        return Err(DeResearcherError::InvalidSigner);
     }
 
-    if !researcher_profile_acc.is_writable {
-        return Err(DeResearcherError::ImmutableAccount);
+    if paper_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PaperNotFound);
     }
 
-    if reputation_checker_acc.key.ne(&REPUTATION_CHECKER_PUBKEY) {
-        return Err(DeResearcherError::InvalidReputationChecker.into());
+    if peer_review_pda_acc.data_is_empty() {
+        return Err(DeResearcherError::PeerReviewNotFound);
     }
 
-    if !reputation_checker_acc.is_signer {
-        return Err(DeResearcherError::InvalidSigner);
+    if peer_review_pda_acc.data_len() != PeerReview::legacy_size() {
+        return Err(DeResearcherError::AccountAlreadyMigrated);
+    }
+
+    if !paper_pda_acc.is_writable || !peer_review_pda_acc.is_writable {
+        return Err(DeResearcherError::ImmutableAccount);
     }
 
     Ok(())
 }
 
-// Check and assign reputation
+// Grow a review added before the current layout, its paper has to be migrated first
 
-pub fn check_and_assign_reputation_ix(
-    accounts: &[AccountInfo],
-    data: CheckAndAssignReputation,
-) -> ProgramResult {
+pub fn migrate_peer_review_ix(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: MigratePeerReview");
     let accounts_iter = &mut accounts.iter();
 
-    let reputation_checker_acc = next_account_info(accounts_iter)?;
+    let payer_acc = next_account_info(accounts_iter)?;
 
-    let researcher_profile_pda_acc = next_account_info(accounts_iter)?;
+    let paper_pda_acc = next_account_info(accounts_iter)?;
 
-    validate_check_and_assign_reputation_accounts(
-        reputation_checker_acc,
-        researcher_profile_pda_acc,
+    let peer_review_pda_acc = next_account_info(accounts_iter)?;
+
+    validate_migrate_peer_review_accounts(payer_acc, paper_pda_acc, peer_review_pda_acc)?;
+
+    if peer_review_pda_acc.owner.ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+    let paper_seeds = vec![
+        RESEARCH_PAPER_PDA_SEED,
+        paper.paper_content_hash[..32].as_ref(),
+        paper.creator_pubkey.as_ref(),
+    ];
+
+    validate_pda(paper_seeds, paper_pda_acc.key, paper.bump, program_id)?;
+
+    let peer_review = PeerReview::from_account_data(&peer_review_pda_acc.data.borrow())?;
+
+    let peer_review_seeds = vec![
+        PEER_REVIEW_PDA_SEED,
+        paper_pda_acc.key.as_ref(),
+        peer_review.reviewer_pubkey.as_ref(),
+    ];
+
+    validate_pda(
+        peer_review_seeds,
+        peer_review_pda_acc.key,
+        peer_review.bump,
+        program_id,
     )?;
 
-    ResearcherProfile::assign_reputation(researcher_profile_pda_acc, data)?;
+    fund_migration(payer_acc, peer_review_pda_acc, PeerReview::size())?;
+
+    PeerReview::migrate(peer_review_pda_acc, paper_pda_acc)?;
 
     Ok(())
 }
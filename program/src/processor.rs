@@ -4,8 +4,20 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubke
 use crate::{
     error::DeResearcherError,
     instruction::{
-        add_peer_review_ix, check_and_assign_reputation_ix, create_research_paper_ix,
-        create_researcher_profile_ix, mint_res_paper_ix, publish_paper_ix, DeResearcherInstruction,
+        add_institution_member_ix, add_peer_review_ix, amend_peer_review_ix, append_erratum_ix,
+        appoint_editor_ix, check_and_assign_reputation_ix, claim_licensed_paper_ix,
+        claim_reviewer_reward_ix, close_rental_ix, commit_review_ix, crank_paper_deadline_ix,
+        create_author_response_ix, create_institution_ix, create_institution_license_ix,
+        create_journal_ix, create_research_paper_ix, create_researcher_profile_ix,
+        create_rubric_ix, create_subscription_plan_ix, declare_conflict_of_interest_ix,
+        desk_reject_ix, expire_dispute_ix, expire_review_commit_ix, extend_rental_ix,
+        finalize_reviews_ix, flag_duplicate_ix, gift_research_paper_ix, invite_reviewer_ix,
+        migrate_peer_review_ix, migrate_research_paper_ix, mint_res_paper_ix, open_dispute_ix,
+        publish_paper_ix, release_escrow_ix, release_review_stake_ix, remove_institution_member_ix,
+        rent_research_paper_ix, request_refund_ix, resolve_dispute_ix, retract_paper_ix,
+        reveal_review_ix, set_arbiters_ix, skip_venue_submission_ix, slash_review_stake_ix,
+        submit_revision_ix, submit_to_journal_ix, subscribe_ix, verify_access_ix,
+        withdraw_paper_ix, DeResearcherInstruction,
     },
 };
 
@@ -39,6 +51,121 @@ impl Processor {
             DeResearcherInstruction::CheckAndAssignReputation(data) => {
                 check_and_assign_reputation_ix(accounts, data)?
             }
+            DeResearcherInstruction::ReleaseReviewStake => {
+                release_review_stake_ix(program_id, accounts)?
+            }
+            DeResearcherInstruction::SlashReviewStake(data) => {
+                slash_review_stake_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::ClaimReviewerReward => {
+                claim_reviewer_reward_ix(program_id, accounts)?
+            }
+            DeResearcherInstruction::FinalizeReviews => finalize_reviews_ix(program_id, accounts)?,
+            DeResearcherInstruction::CommitReview(data) => {
+                commit_review_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::RevealReview(data) => {
+                reveal_review_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::ExpireReviewCommit => {
+                expire_review_commit_ix(program_id, accounts)?
+            }
+            DeResearcherInstruction::CrankPaperDeadline => {
+                crank_paper_deadline_ix(program_id, accounts)?
+            }
+            DeResearcherInstruction::AppointEditor(data) => {
+                appoint_editor_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::InviteReviewer(data) => {
+                invite_reviewer_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::DeskReject(data) => {
+                desk_reject_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::CreateJournal(data) => {
+                create_journal_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::SubmitToJournal => submit_to_journal_ix(program_id, accounts)?,
+            DeResearcherInstruction::CreateRubric(data) => {
+                create_rubric_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::CreateAuthorResponse(data) => {
+                create_author_response_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::AmendPeerReview(data) => {
+                amend_peer_review_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::SetArbiters(data) => {
+                set_arbiters_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::OpenDispute(data) => {
+                open_dispute_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::ResolveDispute(data) => {
+                resolve_dispute_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::DeclareConflictOfInterest(data) => {
+                declare_conflict_of_interest_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::WithdrawPaper(data) => {
+                withdraw_paper_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::RetractPaper(data) => {
+                retract_paper_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::AppendErratum(data) => {
+                append_erratum_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::SubmitRevision(data) => {
+                submit_revision_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::FlagDuplicate(data) => {
+                flag_duplicate_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::GiftResearchPaper(data) => {
+                gift_research_paper_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::CreateInstitution(data) => {
+                create_institution_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::AddInstitutionMember(data) => {
+                add_institution_member_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::RemoveInstitutionMember => {
+                remove_institution_member_ix(program_id, accounts)?
+            }
+            DeResearcherInstruction::CreateInstitutionLicense(data) => {
+                create_institution_license_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::ClaimLicensedPaper(data) => {
+                claim_licensed_paper_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::CreateSubscriptionPlan(data) => {
+                create_subscription_plan_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::Subscribe(data) => subscribe_ix(program_id, accounts, data)?,
+            DeResearcherInstruction::VerifyAccess(data) => {
+                verify_access_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::RentResearchPaper(data) => {
+                rent_research_paper_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::ExtendRental(data) => {
+                extend_rental_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::CloseRental => close_rental_ix(program_id, accounts)?,
+            DeResearcherInstruction::RequestRefund => request_refund_ix(program_id, accounts)?,
+            DeResearcherInstruction::ReleaseEscrow => release_escrow_ix(program_id, accounts)?,
+            DeResearcherInstruction::SkipVenueSubmission => {
+                skip_venue_submission_ix(program_id, accounts)?
+            }
+            DeResearcherInstruction::ExpireDispute => expire_dispute_ix(program_id, accounts)?,
+            DeResearcherInstruction::MigrateResearchPaper(data) => {
+                migrate_research_paper_ix(program_id, accounts, data)?
+            }
+            DeResearcherInstruction::MigratePeerReview => {
+                migrate_peer_review_ix(program_id, accounts)?
+            }
         }
 
         Ok(())
@@ -1,13 +1,24 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, hash::hashv,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
 
 use crate::{
     error::DeResearcherError,
     instruction::{
-        AddPeerReview, CheckAndAssignReputation, CreateResearchePaper, CreateResearcherProfile,
-        MintResearchPaper, MAX_REPUTATION, MAX_STRING_SIZE, MIN_APPROVALS_FOR_PUBLISH,
-        MIN_REPUTATION_FOR_PEER_REVIEW,
+        AddInstitutionMember, AddPeerReview, AmendPeerReview, AppendErratum, AppointEditor,
+        CheckAndAssignReputation, CommitReview, CreateAuthorResponse, CreateInstitution,
+        CreateInstitutionLicense, CreateJournal, CreateResearchePaper, CreateResearcherProfile,
+        CreateRubric, CreateSubscriptionPlan, DeclareConflictOfInterest, DeskReject, FlagDuplicate,
+        InviteReviewer, OpenDispute, RetractPaper, RevealReview, SetArbiters, SlashReviewStake,
+        SubmitRevision, WithdrawPaper, CONSENSUS_REPUTATION_BUMP, CONSENSUS_TOLERANCE,
+        DEFAULT_APPROVAL_THRESHOLD, DEFAULT_REVIEW_WINDOW_SECS, DEFAULT_RUBRIC_DIMENSIONS,
+        DEFAULT_RUBRIC_WEIGHTS, DISPUTE_RESOLUTION_WINDOW_SECS, MAX_ARBITERS, MAX_BASIS_POINTS,
+        MAX_EXCLUDED_REVIEWERS, MAX_PRICE_TIERS, MAX_REPUTATION, MAX_RUBRIC_DIMENSIONS, MAX_SCORE,
+        MAX_STRING_SIZE, MIN_APPROVALS_FOR_PUBLISH, MIN_REPUTATION_FOR_PEER_REVIEW,
+        REVIEW_REVEAL_WINDOW_SECS, SCORE_HISTOGRAM_BUCKETS,
     },
 };
 
@@ -27,6 +38,32 @@ pub fn checked_string_convt_to_64_bytes(
     Ok(data_bytes)
 }
 
+// Median of a histogram of score counts, the two middle values are averaged for an even count
+
+pub fn median_score(score_counts: &[u8]) -> u8 {
+    let total: usize = score_counts.iter().map(|count| *count as usize).sum();
+
+    if total == 0 {
+        return 0;
+    }
+
+    let nth_score = |rank: usize| {
+        let mut seen = 0;
+
+        for (score, count) in score_counts.iter().enumerate() {
+            seen += *count as usize;
+
+            if seen > rank {
+                return score as u16;
+            }
+        }
+
+        0
+    };
+
+    ((nth_score((total - 1) / 2) + nth_score(total / 2)) / 2) as u8
+}
+
 #[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, PartialOrd)]
 #[repr(u8)]
 pub enum PaperState {
@@ -36,6 +73,56 @@ pub enum PaperState {
     RequiresRevision,
     Published,
     Minted,
+    Expired,
+    DeskRejected,
+    Withdrawn,
+    Retracted,
+    ScheduledPublish,
+}
+
+impl PaperState {
+    // Reviews can only be added or revealed while a paper is in one of these states
+    pub fn is_under_review(&self) -> bool {
+        matches!(
+            self,
+            PaperState::AwaitingPeerReview | PaperState::InPeerReview
+        )
+    }
+
+    // Peer review is over once a paper reaches one of these states, none of them can
+    // return to review so the set of approvals is fixed
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            PaperState::Published
+                | PaperState::Minted
+                | PaperState::Expired
+                | PaperState::DeskRejected
+                | PaperState::Withdrawn
+                | PaperState::Retracted
+                | PaperState::ScheduledPublish
+        )
+    }
+
+    // A blind review committed while the paper was under review can still be revealed after
+    // the reveal that reaches the approval threshold, so late reveals aren't locked out
+    pub fn accepts_reveal(&self) -> bool {
+        self.is_under_review() || *self == PaperState::ApprovedToPublish
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, PartialOrd)]
+pub enum FeeDecay {
+    None,
+    Linear,
+    Step,
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, PartialOrd)]
+pub enum PricingMode {
+    Flat,
+    LinearBondingCurve,
+    VolumeTiers,
 }
 
 #[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, PartialOrd)]
@@ -124,8 +211,31 @@ impl ResearcherProfile {
 
         Ok(())
     }
+
+    pub fn reward_consensus(researcher_profile_pda_acc: &AccountInfo) -> ProgramResult {
+        let mut researcher_profile =
+            ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.try_borrow_data()?)?;
+
+        researcher_profile.reputation = researcher_profile
+            .reputation
+            .saturating_add(CONSENSUS_REPUTATION_BUMP)
+            .min(MAX_REPUTATION);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        researcher_profile.serialize(&mut data_bytes)?;
+
+        researcher_profile_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
 }
 
+// Shank only reads literal array lengths, so the rubric arrays are written as [_; 8]
+const _: () = assert!(MAX_RUBRIC_DIMENSIONS == 8);
+
 #[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
 pub struct ResearchPaper {
     pub address: Pubkey,                 // Paper's public key 32 bytes
@@ -135,15 +245,458 @@ pub struct ResearchPaper {
     pub version: u8,                     // Version of the paper 1 byte
     pub paper_content_hash: [u8; 64],    // Hash of the paper's content 64 bytes
     pub total_approvals: u8,             // Total approvals 1 byte
+    pub total_reviews: u8,               // Total peer reviews 1 byte
     pub total_citations: u64,            // Total citations 8 bytes
     pub total_mints: u64,                // Total mints 8 bytes
     pub meta_data_merkle_root: [u8; 64], // Data merkle root 64 bytes
+    pub reviewer_share_bps: u16,         // Share of each access fee paid to reviewers 2 bytes
+    pub blind_review: bool,              // Whether reviews go through commit-reveal 1 byte
+    pub submitted_at: i64,               // Unix timestamp of submission 8 bytes
+    pub review_window_secs: i64,         // Length of the review window 8 bytes
+    pub venue_pubkey: Pubkey,            // Journal the paper was submitted to 32 bytes
+    pub rejection_reason: u8,            // Reason code given on desk rejection 1 byte
+    pub min_approvals: u8,               // Approvals required to publish 1 byte
+    pub rubric_pubkey: Pubkey,           // Rubric reviews are scored against 32 bytes
+    pub rubric_dimension_count: u8,      // Number of scored dimensions 1 byte
+    pub rubric_weights: [u16; 8],        // Weight of each dimension 16 bytes
+    pub approval_threshold: u8,          // Weighted score a review must exceed 1 byte
+    pub scored_reviews: u8,              // Reviews counted in the score statistics 1 byte
+    pub score_sums: [u32; 8],            // Sum of scores per dimension 32 bytes
+    pub score_square_sums: [u32; 8],     // Sum of squared scores per dimension 32 bytes
+    pub weighted_score_sum: u32,         // Sum of weighted scores 4 bytes
+    pub weighted_score_square_sum: u32,  // Sum of squared weighted scores 4 bytes
+    pub excluded_reviewers: [Pubkey; 4], // Reviewers the author excluded on submission 128 bytes
+    pub reason_hash: [u8; 64],           // Hash of the withdrawal or retraction reason 64 bytes
+    pub total_errata: u32,               // Total errata appended after publishing 4 bytes
+    pub latest_content_hash: [u8; 64],   // Content hash of the current version 64 bytes
+    pub total_duplicate_flags: u32,      // Times authors flagged the paper as a duplicate 4 bytes
+    pub publish_at: i64,                 // Unix timestamp the paper is or was released 8 bytes
+    pub fee_decay: FeeDecay,             // How the access fee falls after publishing 1 byte
+    pub fee_decay_step_secs: i64,        // Interval between step reductions 8 bytes
+    pub fee_decay_step_bps: u16,         // Share of the access fee removed each step 2 bytes
+    pub open_access_after_secs: i64,     // Time after publishing the paper becomes free 8 bytes
+    pub pricing_mode: PricingMode,       // How the access fee responds to total mints 1 byte
+    pub price_slope: u32,                // Lamports added per mint on the bonding curve 4 bytes
+    pub tier_mint_thresholds: [u32; 4],  // Mints at which each volume tier starts 16 bytes
+    pub tier_fees: [u32; 4],             // Access fee of each volume tier 16 bytes
+    pub max_mints: u64,                  // Edition supply cap, 0 for unlimited 8 bytes
+    pub reserved_mints: u64,             // Editions held back for gifting 8 bytes
+    pub gifted_mints: u64,               // Reserved editions already gifted 8 bytes
+    pub rental_fee: u32,                 // Fee for renting the paper for one period 4 bytes
+    pub rental_period_secs: i64,         // Length of a rental, 0 if rentals are disabled 8 bytes
+    pub refund_window_secs: i64,         // Time mint payments stay refundable in escrow 8 bytes
+    pub pending_commitments: u8,         // Blind reviews committed but not yet revealed 1 byte
+    pub awaiting_venue: bool,            // Whether reviews wait for a venue submission 1 byte
     pub bump: u8,                        // Bump seed 1 byte
 }
 
 impl ResearchPaper {
     pub fn size() -> usize {
-        32 + 32 + 1 + 4 + 1 + 64 + 1 + 8 + 8 + 64 + 1 //208
+        32 + 32 + 1 + 4 + 1 + 64 + 1 + 1 + 8 + 8 + 64 // 216
+            + 2 + 1 + 8 + 8 + 32 + 1 + 1 + 32 + 1 + 16 + 1 // 319
+            + 1 + 32 + 32 + 4 + 4 + 128 // 520
+            + 64 + 4 + 64 + 4 + 8 + 1 + 8 + 2 + 8 // 683
+            + 1 + 4 + 16 + 16 // 720
+            + 8 + 8 + 8 // 744
+            + 4 + 8 + 8 + 1 + 1 + 1 // 767
+    }
+
+    pub fn legacy_size() -> usize {
+        32 + 32 + 1 + 4 + 1 + 64 + 1 + 8 + 8 + 64 + 1 // 216
+    }
+
+    // Papers created before reviews were scored against rubrics keep their approvals and
+    // counters, everything added since starts from the defaults. They had no review deadline,
+    // and their reviews are counted back in as each of them is migrated
+
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::legacy_size() {
+            return Ok(Self::try_from_slice(data)?);
+        }
+
+        let mut legacy_data = data;
+
+        let address = Pubkey::deserialize(&mut legacy_data)?;
+        let creator_pubkey = Pubkey::deserialize(&mut legacy_data)?;
+        let state = PaperState::deserialize(&mut legacy_data)?;
+        let access_fee = u32::deserialize(&mut legacy_data)?;
+        let version = u8::deserialize(&mut legacy_data)?;
+        let paper_content_hash = <[u8; 64]>::deserialize(&mut legacy_data)?;
+        let total_approvals = u8::deserialize(&mut legacy_data)?;
+        let total_citations = u64::deserialize(&mut legacy_data)?;
+        let total_mints = u64::deserialize(&mut legacy_data)?;
+        let meta_data_merkle_root = <[u8; 64]>::deserialize(&mut legacy_data)?;
+        let bump = u8::deserialize(&mut legacy_data)?;
+
+        Ok(Self {
+            address,
+            creator_pubkey,
+            state,
+            access_fee,
+            version,
+            paper_content_hash,
+            total_approvals,
+            total_reviews: 0,
+            total_citations,
+            total_mints,
+            meta_data_merkle_root,
+            reviewer_share_bps: 0,
+            blind_review: false,
+            submitted_at: 0,
+            review_window_secs: i64::MAX,
+            venue_pubkey: Pubkey::default(),
+            rejection_reason: 0,
+            min_approvals: MIN_APPROVALS_FOR_PUBLISH,
+            rubric_pubkey: Pubkey::default(),
+            rubric_dimension_count: DEFAULT_RUBRIC_DIMENSIONS,
+            rubric_weights: DEFAULT_RUBRIC_WEIGHTS,
+            approval_threshold: DEFAULT_APPROVAL_THRESHOLD,
+            scored_reviews: 0,
+            score_sums: [0; MAX_RUBRIC_DIMENSIONS],
+            score_square_sums: [0; MAX_RUBRIC_DIMENSIONS],
+            weighted_score_sum: 0,
+            weighted_score_square_sum: 0,
+            excluded_reviewers: [Pubkey::default(); MAX_EXCLUDED_REVIEWERS],
+            reason_hash: [0; 64],
+            total_errata: 0,
+            latest_content_hash: paper_content_hash,
+            total_duplicate_flags: 0,
+            publish_at: 0,
+            fee_decay: FeeDecay::None,
+            fee_decay_step_secs: 0,
+            fee_decay_step_bps: 0,
+            open_access_after_secs: 0,
+            pricing_mode: PricingMode::Flat,
+            price_slope: 0,
+            tier_mint_thresholds: [0; MAX_PRICE_TIERS],
+            tier_fees: [0; MAX_PRICE_TIERS],
+            max_mints: 0,
+            reserved_mints: 0,
+            gifted_mints: 0,
+            rental_fee: 0,
+            rental_period_secs: 0,
+            refund_window_secs: 0,
+            pending_commitments: 0,
+            awaiting_venue: false,
+            bump,
+        })
+    }
+
+    // The account has to hold rent for the new size before it is grown
+
+    pub fn migrate(paper_pda_acc: &AccountInfo) -> ProgramResult {
+        let paper = Self::from_account_data(&paper_pda_acc.try_borrow_data()?)?;
+
+        paper_pda_acc.realloc(Self::size(), false)?;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // Commitments on a superseded version were already cleared by the revision
+
+    pub fn settle_commitment(paper_pda_acc: &AccountInfo, review_version: u8) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        if review_version != paper.version {
+            return Ok(());
+        }
+
+        paper.pending_commitments = paper.pending_commitments.saturating_sub(1);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // A review needs one score out of 100 for every dimension of the paper's rubric
+
+    pub fn validate_scores(&self, scores: &[u8]) -> Result<(), DeResearcherError> {
+        if scores.len() != self.rubric_dimension_count as usize
+            || scores.iter().any(|score| *score > MAX_SCORE)
+        {
+            return Err(DeResearcherError::InvalidRubricScores);
+        }
+
+        Ok(())
+    }
+
+    pub fn weighted_score(&self, scores: &[u8]) -> u8 {
+        let (weighted_sum, total_weight) = scores.iter().zip(self.rubric_weights.iter()).fold(
+            (0u32, 0u32),
+            |(sum, total), (score, weight)| {
+                (sum + *score as u32 * *weight as u32, total + *weight as u32)
+            },
+        );
+
+        if total_weight == 0 {
+            return 0;
+        }
+
+        (weighted_sum / total_weight) as u8
+    }
+
+    // Fold a review's scores into the running sums readers derive averages and variance from,
+    // the averages themselves are only computed off chain
+
+    pub fn add_review_stats(&mut self, scores: &[u8]) -> Result<(), DeResearcherError> {
+        for (dimension, score) in scores.iter().enumerate() {
+            self.score_sums[dimension] = self.score_sums[dimension]
+                .checked_add(*score as u32)
+                .ok_or(DeResearcherError::SizeOverflow)?;
+            self.score_square_sums[dimension] = self.score_square_sums[dimension]
+                .checked_add(*score as u32 * *score as u32)
+                .ok_or(DeResearcherError::SizeOverflow)?;
+        }
+
+        let weighted_score = self.weighted_score(scores) as u32;
+
+        self.weighted_score_sum = self
+            .weighted_score_sum
+            .checked_add(weighted_score)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+        self.weighted_score_square_sum = self
+            .weighted_score_square_sum
+            .checked_add(weighted_score * weighted_score)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+        self.scored_reviews = self
+            .scored_reviews
+            .checked_add(1)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn remove_review_stats(&mut self, scores: &[u8]) -> Result<(), DeResearcherError> {
+        for (dimension, score) in scores.iter().enumerate() {
+            self.score_sums[dimension] = self.score_sums[dimension]
+                .checked_sub(*score as u32)
+                .ok_or(DeResearcherError::SizeOverflow)?;
+            self.score_square_sums[dimension] = self.score_square_sums[dimension]
+                .checked_sub(*score as u32 * *score as u32)
+                .ok_or(DeResearcherError::SizeOverflow)?;
+        }
+
+        let weighted_score = self.weighted_score(scores) as u32;
+
+        self.weighted_score_sum = self
+            .weighted_score_sum
+            .checked_sub(weighted_score)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+        self.weighted_score_square_sum = self
+            .weighted_score_square_sum
+            .checked_sub(weighted_score * weighted_score)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+        self.scored_reviews = self
+            .scored_reviews
+            .checked_sub(1)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+
+        Ok(())
+    }
+
+    // Move between approved and in review as approvals are added or taken away, any other
+    // state was reached through a separate lifecycle action and is left untouched
+
+    pub fn update_approval_state(&mut self) {
+        let is_approved = self.total_approvals >= self.min_approvals;
+
+        match self.state {
+            PaperState::AwaitingPeerReview | PaperState::InPeerReview if is_approved => {
+                self.state = PaperState::ApprovedToPublish;
+            }
+            PaperState::ApprovedToPublish if !is_approved => {
+                self.state = PaperState::InPeerReview;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn is_excluded_reviewer(&self, reviewer: &Pubkey) -> bool {
+        reviewer.ne(&Pubkey::default()) && self.excluded_reviewers.contains(reviewer)
+    }
+
+    // A scheduled paper counts as published once its embargo has passed, without a state change
+
+    pub fn is_published(&self, now: i64) -> bool {
+        match self.state {
+            PaperState::Published | PaperState::Minted => true,
+            PaperState::ScheduledPublish => now >= self.publish_at,
+            _ => false,
+        }
+    }
+
+    // Price of the next mint before any time decay is applied
+
+    pub fn base_access_fee(&self) -> u64 {
+        let access_fee = self.access_fee as u64;
+
+        match self.pricing_mode {
+            PricingMode::Flat => access_fee,
+            PricingMode::LinearBondingCurve => access_fee
+                .saturating_add((self.price_slope as u64).saturating_mul(self.total_mints)),
+            PricingMode::VolumeTiers => self
+                .tier_mint_thresholds
+                .iter()
+                .zip(self.tier_fees.iter())
+                .rev()
+                .find(|(threshold, _)| **threshold > 0 && self.total_mints >= **threshold as u64)
+                .map_or(access_fee, |(_, tier_fee)| *tier_fee as u64),
+        }
+    }
+
+    pub fn reviewer_share(&self, amount: u64) -> u64 {
+        (amount as u128 * self.reviewer_share_bps as u128 / MAX_BASIS_POINTS as u128) as u64
+    }
+
+    // A refunded mint frees its edition and no longer counts as a citation of the paper
+    // or of the profile the mint credited
+
+    pub fn refund_mint(
+        paper_pda_acc: &AccountInfo,
+        researcher_profile_pda_acc: &AccountInfo,
+    ) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        paper.total_mints = paper.total_mints.saturating_sub(1);
+
+        paper.total_citations = paper.total_citations.saturating_sub(1);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut researcher_profile =
+            ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.try_borrow_data()?)?;
+
+        researcher_profile.total_citations = researcher_profile.total_citations.saturating_sub(1);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        researcher_profile.serialize(&mut data_bytes)?;
+
+        researcher_profile_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // Access can only be bought for a published paper that was not retracted
+
+    pub fn check_purchasable(&self, now: i64) -> Result<(), DeResearcherError> {
+        if self.state == PaperState::Retracted {
+            return Err(DeResearcherError::PaperRetracted);
+        }
+
+        if self.state == PaperState::ScheduledPublish && now < self.publish_at {
+            return Err(DeResearcherError::PaperEmbargoed);
+        }
+
+        if !self.is_published(now) {
+            return Err(DeResearcherError::InvalidState);
+        }
+
+        Ok(())
+    }
+
+    // Gifts draw from the reserved editions, sales from whatever supply is left after them
+
+    pub fn check_edition_supply(&self, is_gift: bool) -> Result<(), DeResearcherError> {
+        if is_gift {
+            if self.gifted_mints >= self.reserved_mints {
+                return Err(DeResearcherError::NoReservedEditions);
+            }
+
+            return Ok(());
+        }
+
+        let sold_mints = self.total_mints - self.gifted_mints;
+
+        if self.max_mints > 0 && sold_mints >= self.max_mints - self.reserved_mints {
+            return Err(DeResearcherError::SoldOut);
+        }
+
+        Ok(())
+    }
+
+    // A rental never costs more than buying permanent access at the current price
+
+    pub fn current_rental_fee(&self, now: i64) -> u64 {
+        (self.rental_fee as u64).min(self.current_access_fee(now))
+    }
+
+    // The access fee decays from the moment of publication and drops to zero at open access
+
+    pub fn current_access_fee(&self, now: i64) -> u64 {
+        let access_fee = self.base_access_fee();
+
+        let elapsed = (now - self.publish_at).max(0);
+
+        if self.open_access_after_secs > 0 && elapsed >= self.open_access_after_secs {
+            return 0;
+        }
+
+        match self.fee_decay {
+            FeeDecay::None => access_fee,
+            FeeDecay::Linear => {
+                let remaining = (self.open_access_after_secs - elapsed) as u128;
+
+                (access_fee as u128 * remaining / self.open_access_after_secs as u128) as u64
+            }
+            FeeDecay::Step => {
+                let steps = (elapsed / self.fee_decay_step_secs.max(1)) as u64;
+
+                let reduction_bps = steps
+                    .saturating_mul(self.fee_decay_step_bps as u64)
+                    .min(MAX_BASIS_POINTS as u64);
+
+                (access_fee as u128 * (MAX_BASIS_POINTS as u64 - reduction_bps) as u128
+                    / MAX_BASIS_POINTS as u128) as u64
+            }
+        }
+    }
+
+    pub fn has_venue(&self) -> bool {
+        self.venue_pubkey.ne(&Pubkey::default())
+    }
+
+    // Reviews, or blind review commitments, are accepted until this timestamp
+
+    pub fn review_deadline(&self) -> i64 {
+        self.submitted_at.saturating_add(self.review_window_secs)
+    }
+
+    pub fn reveal_deadline(&self) -> i64 {
+        self.review_deadline()
+            .saturating_add(REVIEW_REVEAL_WINDOW_SECS)
+    }
+
+    // Blind reviews keep the review open until every commitment had its chance to be revealed
+
+    pub fn review_closes_at(&self) -> i64 {
+        if self.blind_review {
+            self.reveal_deadline()
+        } else {
+            self.review_deadline()
+        }
     }
 
     pub fn create_new(
@@ -156,6 +709,24 @@ impl ResearchPaper {
 
         let merkle_root_bytes = checked_string_convt_to_64_bytes(&data.meta_data_merkle_root)?;
 
+        if data.excluded_reviewers.len() > MAX_EXCLUDED_REVIEWERS {
+            return Err(DeResearcherError::TooManyExcludedReviewers.into());
+        }
+
+        let mut tier_mint_thresholds = [0; MAX_PRICE_TIERS];
+
+        tier_mint_thresholds[..data.tier_mint_thresholds.len()]
+            .copy_from_slice(&data.tier_mint_thresholds);
+
+        let mut tier_fees = [0; MAX_PRICE_TIERS];
+
+        tier_fees[..data.tier_fees.len()].copy_from_slice(&data.tier_fees);
+
+        let mut excluded_reviewers = [Pubkey::default(); MAX_EXCLUDED_REVIEWERS];
+
+        excluded_reviewers[..data.excluded_reviewers.len()]
+            .copy_from_slice(&data.excluded_reviewers);
+
         let research_paper = Self {
             address: *research_paper_pda_acc.key,
             creator_pubkey: *publisher_acc.key,
@@ -164,9 +735,52 @@ impl ResearchPaper {
             version: 0,
             paper_content_hash: content_hash_bytes,
             total_approvals: 0,
+            total_reviews: 0,
             total_citations: 0,
             total_mints: 0,
             meta_data_merkle_root: merkle_root_bytes,
+            reviewer_share_bps: data.reviewer_share_bps,
+            blind_review: data.blind_review,
+            submitted_at: Clock::get()?.unix_timestamp,
+            review_window_secs: if data.review_window_secs == 0 {
+                DEFAULT_REVIEW_WINDOW_SECS
+            } else {
+                data.review_window_secs
+            },
+            venue_pubkey: Pubkey::default(),
+            rejection_reason: 0,
+            min_approvals: MIN_APPROVALS_FOR_PUBLISH,
+            rubric_pubkey: Pubkey::default(),
+            rubric_dimension_count: DEFAULT_RUBRIC_DIMENSIONS,
+            rubric_weights: DEFAULT_RUBRIC_WEIGHTS,
+            approval_threshold: DEFAULT_APPROVAL_THRESHOLD,
+            scored_reviews: 0,
+            score_sums: [0; MAX_RUBRIC_DIMENSIONS],
+            score_square_sums: [0; MAX_RUBRIC_DIMENSIONS],
+            weighted_score_sum: 0,
+            weighted_score_square_sum: 0,
+            excluded_reviewers,
+            reason_hash: [0; 64],
+            total_errata: 0,
+            latest_content_hash: content_hash_bytes,
+            total_duplicate_flags: 0,
+            publish_at: 0,
+            fee_decay: data.fee_decay,
+            fee_decay_step_secs: data.fee_decay_step_secs,
+            fee_decay_step_bps: data.fee_decay_step_bps,
+            open_access_after_secs: data.open_access_after_secs,
+            pricing_mode: data.pricing_mode,
+            price_slope: data.price_slope,
+            tier_mint_thresholds,
+            tier_fees,
+            max_mints: data.max_mints,
+            reserved_mints: data.reserved_mints,
+            gifted_mints: 0,
+            rental_fee: data.rental_fee,
+            rental_period_secs: data.rental_period_secs,
+            refund_window_secs: data.refund_window_secs,
+            pending_commitments: 0,
+            awaiting_venue: data.awaiting_venue,
             bump: data.pda_bump,
         };
 
@@ -197,6 +811,8 @@ impl ResearchPaper {
     pub fn publish_paper(
         paper_pda_acc: &AccountInfo,
         publisher_acc: &AccountInfo,
+        publish_at: Option<i64>,
+        now: i64,
     ) -> ProgramResult {
         let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
 
@@ -208,7 +824,25 @@ impl ResearchPaper {
             return Err(DeResearcherError::InvalidState.into());
         }
 
-        paper.state = PaperState::Published;
+        // Publishing would fix the approvals before every committed review had its reveal
+
+        if paper.pending_commitments > 0 {
+            return Err(DeResearcherError::CommitmentsPending.into());
+        }
+
+        match publish_at {
+            Some(publish_at) if publish_at <= now => {
+                return Err(DeResearcherError::InvalidEmbargo.into());
+            }
+            Some(publish_at) => {
+                paper.state = PaperState::ScheduledPublish;
+                paper.publish_at = publish_at;
+            }
+            None => {
+                paper.state = PaperState::Published;
+                paper.publish_at = now;
+            }
+        }
 
         let mut data_bytes: Vec<u8> = Vec::new();
 
@@ -220,68 +854,52 @@ impl ResearchPaper {
 
         Ok(())
     }
-}
-
-#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
-pub struct PeerReview {
-    pub address: Pubkey,         // Peer Review Entry's public key 32 bytes
-    pub reviewer_pubkey: Pubkey, // Reviewer's public key 32 bytes
-    pub paper_pubkey: Pubkey,    // Paper's public key 32 bytes
-    pub quality_of_research: u8, // Rating for quality of research (out of 100)
-    pub potential_for_real_world_use_case: u8, // Rating for potential real-world use case (out of 100)
-    pub domain_knowledge: u8,                  // Rating for domain knowledge (out of 100)
-    pub practicality_of_result_obtained: u8,   // Rating for practicality of the result (out of 100)
-    pub meta_data_merkle_root: [u8; 64],       // Data merkle root 64 bytes
-    pub bump: u8,                              // Bump seed 1 byte
-}
 
-impl PeerReview {
-    pub fn size() -> usize {
-        32 + 32 + 32 + 1 + 1 + 1 + 1 + 64 + 1 //165
-    }
+    // The journal's approval threshold and rubric replace the defaults, its review window starts now
 
-    pub fn create_new(
-        peer_review_pda_acc: &AccountInfo,
-        reviewer_acc: &AccountInfo,
+    pub fn submit_to_journal(
         paper_pda_acc: &AccountInfo,
-        researcher_profile_pda_acc: &AccountInfo,
-        data: AddPeerReview,
+        publisher_acc: &AccountInfo,
+        journal_pda_acc: &AccountInfo,
+        journal: &Journal,
+        rubric: &Rubric,
+        now: i64,
     ) -> ProgramResult {
-        let merkle_root_bytes = checked_string_convt_to_64_bytes(&data.meta_data_merkle_root)?;
-
-        let peer_review = Self {
-            address: *peer_review_pda_acc.key,
-            reviewer_pubkey: *reviewer_acc.key,
-            paper_pubkey: *paper_pda_acc.key,
-            quality_of_research: data.quality_of_research,
-            potential_for_real_world_use_case: data.potential_for_real_world_use_case,
-            domain_knowledge: data.domain_knowledge,
-            practicality_of_result_obtained: data.practicality_of_result_obtained,
-            meta_data_merkle_root: merkle_root_bytes,
-            bump: data.pda_bump,
-        };
-
-        let cumulative_score = peer_review.quality_of_research as u16
-            + peer_review.potential_for_real_world_use_case as u16
-            + peer_review.domain_knowledge as u16
-            + peer_review.practicality_of_result_obtained as u16;
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
 
-        let avg_score = cumulative_score / 4;
-        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+        if paper.creator_pubkey.ne(publisher_acc.key) {
+            return Err(DeResearcherError::PubkeyMismatch.into());
+        }
 
-        if paper.state == PaperState::AwaitingPeerReview {
-            paper.state = PaperState::InPeerReview;
+        if paper.has_venue() {
+            return Err(DeResearcherError::PaperAlreadySubmitted.into());
         }
 
-        if avg_score > 50 {
-            paper.total_approvals += 1;
+        if paper.state != PaperState::AwaitingPeerReview || paper.total_reviews > 0 {
+            return Err(DeResearcherError::InvalidState.into());
         }
 
-        if paper.total_approvals >= MIN_APPROVALS_FOR_PUBLISH {
-            paper.state = PaperState::ApprovedToPublish;
+        // A commitment hashes scores for the current rubric, so the rubric can't change under it
+
+        if paper.pending_commitments > 0 {
+            return Err(DeResearcherError::CommitmentsPending.into());
         }
 
-        paper.total_citations += 1;
+        paper.venue_pubkey = *journal_pda_acc.key;
+
+        paper.awaiting_venue = false;
+
+        paper.min_approvals = journal.min_approvals;
+
+        paper.rubric_pubkey = rubric.address;
+
+        paper.rubric_dimension_count = rubric.dimension_count;
+
+        paper.rubric_weights = rubric.weights;
+
+        paper.approval_threshold = rubric.approval_threshold;
+
+        paper.submitted_at = now;
 
         let mut data_bytes: Vec<u8> = Vec::new();
 
@@ -291,24 +909,187 @@ impl PeerReview {
             .try_borrow_mut_data()?
             .copy_from_slice(&data_bytes);
 
-        let mut data_bytes: Vec<u8> = Vec::new();
-
-        peer_review.serialize(&mut data_bytes)?;
+        Ok(())
+    }
 
-        peer_review_pda_acc
+    // Without a venue the paper is open to peer review, and its review window starts now
+
+    pub fn skip_venue_submission(paper_pda_acc: &AccountInfo, now: i64) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        if !paper.awaiting_venue || paper.state != PaperState::AwaitingPeerReview {
+            return Err(DeResearcherError::InvalidState.into());
+        }
+
+        paper.awaiting_venue = false;
+
+        paper.submitted_at = now;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
             .try_borrow_mut_data()?
             .copy_from_slice(&data_bytes);
 
-        let mut researcher_profile =
-            ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.try_borrow_data()?)?;
+        Ok(())
+    }
 
-        researcher_profile.total_reviews += 1;
+    pub fn withdraw(paper_pda_acc: &AccountInfo, data: WithdrawPaper, now: i64) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        if paper.is_published(now)
+            || paper.state == PaperState::Withdrawn
+            || paper.state == PaperState::Retracted
+        {
+            return Err(DeResearcherError::InvalidState.into());
+        }
+
+        // Withdrawing during the reveal window would leave committed reviewers unable to reveal
+
+        if paper.pending_commitments > 0 {
+            return Err(DeResearcherError::CommitmentsPending.into());
+        }
+
+        paper.state = PaperState::Withdrawn;
+
+        paper.reason_hash = checked_string_convt_to_64_bytes(&data.reason_hash)?;
 
         let mut data_bytes: Vec<u8> = Vec::new();
 
-        researcher_profile.serialize(&mut data_bytes)?;
+        paper.serialize(&mut data_bytes)?;
 
-        researcher_profile_pda_acc
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn retract(paper_pda_acc: &AccountInfo, data: RetractPaper, now: i64) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        if !paper.is_published(now) {
+            return Err(DeResearcherError::InvalidState.into());
+        }
+
+        paper.state = PaperState::Retracted;
+
+        paper.reason_hash = checked_string_convt_to_64_bytes(&data.reason_hash)?;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // Close the superseded version's record and open a fresh review round for the revised content
+
+    pub fn submit_revision(
+        paper_pda_acc: &AccountInfo,
+        paper_version_pda_acc: &AccountInfo,
+        new_paper_version_pda_acc: &AccountInfo,
+        data: SubmitRevision,
+        now: i64,
+    ) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        if paper.state != PaperState::RequiresRevision {
+            return Err(DeResearcherError::InvalidState.into());
+        }
+
+        PaperVersion::record_approvals(paper_version_pda_acc, &paper, now)?;
+
+        paper.version = paper
+            .version
+            .checked_add(1)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+        paper.latest_content_hash = checked_string_convt_to_64_bytes(&data.paper_content_hash)?;
+        paper.meta_data_merkle_root =
+            checked_string_convt_to_64_bytes(&data.meta_data_merkle_root)?;
+        paper.state = PaperState::AwaitingPeerReview;
+        paper.submitted_at = now;
+        paper.total_approvals = 0;
+        paper.total_reviews = 0;
+        paper.scored_reviews = 0;
+        paper.pending_commitments = 0;
+        paper.score_sums = [0; MAX_RUBRIC_DIMENSIONS];
+        paper.score_square_sums = [0; MAX_RUBRIC_DIMENSIONS];
+        paper.weighted_score_sum = 0;
+        paper.weighted_score_square_sum = 0;
+
+        PaperVersion::create_new(
+            new_paper_version_pda_acc,
+            paper_pda_acc,
+            paper.version,
+            paper.latest_content_hash,
+            paper.meta_data_merkle_root,
+            data.pda_bump,
+        )?;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn desk_reject(paper_pda_acc: &AccountInfo, data: DeskReject) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        if paper.state != PaperState::AwaitingPeerReview {
+            return Err(DeResearcherError::InvalidState.into());
+        }
+
+        paper.state = PaperState::DeskRejected;
+
+        paper.rejection_reason = data.reason_code;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // Papers that never got a review expire, papers still short of approvals need revision
+
+    pub fn expire_review(paper_pda_acc: &AccountInfo, now: i64) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        if paper.awaiting_venue {
+            return Err(DeResearcherError::VenueSubmissionPending.into());
+        }
+
+        if now < paper.review_closes_at() {
+            return Err(DeResearcherError::ReviewWindowOpen.into());
+        }
+
+        paper.state = match paper.state {
+            PaperState::AwaitingPeerReview => PaperState::Expired,
+            PaperState::InPeerReview => PaperState::RequiresRevision,
+            _ => return Err(DeResearcherError::InvalidState.into()),
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
             .try_borrow_mut_data()?
             .copy_from_slice(&data_bytes);
 
@@ -317,49 +1098,191 @@ impl PeerReview {
 }
 
 #[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
-pub struct ResearchTokenAccount {
-    pub address: Pubkey,           // Mint Collection's public key 32 bytes
-    pub paper_pubkey: Pubkey,      // Paper's public key 32 bytes
-    pub researcher_pubkey: Pubkey, // Owner's public key 32 bytes
-    pub bump: u8,                  // Bump seed 1 byte
+pub struct PeerReview {
+    pub address: Pubkey,                 // Peer Review Entry's public key 32 bytes
+    pub reviewer_pubkey: Pubkey,         // Reviewer's public key 32 bytes
+    pub paper_pubkey: Pubkey,            // Paper's public key 32 bytes
+    pub score_count: u8,                 // Number of rubric dimensions scored 1 byte
+    pub scores: [u8; 8],                 // Rating for each rubric dimension (out of 100) 8 bytes
+    pub meta_data_merkle_root: [u8; 64], // Data merkle root 64 bytes
+    pub is_approval: bool,               // Whether the review counted as an approval 1 byte
+    pub reward_claimed: u64,             // Lamports claimed from the reviewer pool 8 bytes
+    pub consensus_weight: u8,            // Agreement with the median scores (out of 100)
+    pub is_revealed: bool,               // Whether the scores are public 1 byte
+    pub commitment: [u8; 32],            // Hash of blind scores and salt 32 bytes
+    pub is_amended: bool,                // Whether the scores were revised after a rebuttal 1 byte
+    pub is_invalidated: bool,            // Whether arbiters upheld a dispute against it 1 byte
+    pub paper_version: u8,               // Version of the paper that was reviewed 1 byte
+    pub is_tallied: bool,                // Whether FinalizeReviews counted its scores 1 byte
+    pub is_weighted: bool,               // Whether FinalizeReviews weighted it 1 byte
+    pub bump: u8,                        // Bump seed 1 byte
 }
 
-impl ResearchTokenAccount {
+impl PeerReview {
     pub fn size() -> usize {
-        32 + 32 + 32 + 1 //97
+        32 + 32 + 32 + 1 + 8 + 64 + 1 + 8 + 1 + 1 + 32 + 1 + 1 + 1 + 1 + 1 + 1 //218
     }
 
-    pub fn mint_paper(
-        research_token_pda_acc: &AccountInfo,
-        researcher_acc: &AccountInfo,
+    pub fn legacy_size() -> usize {
+        32 + 32 + 32 + 1 + 1 + 1 + 1 + 64 + 1 //165
+    }
+
+    // Reviews added before rubrics rated four fixed dimensions in the open, and approved the
+    // paper when their average was above 50
+
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::legacy_size() {
+            return Ok(Self::try_from_slice(data)?);
+        }
+
+        let mut legacy_data = data;
+
+        let address = Pubkey::deserialize(&mut legacy_data)?;
+        let reviewer_pubkey = Pubkey::deserialize(&mut legacy_data)?;
+        let paper_pubkey = Pubkey::deserialize(&mut legacy_data)?;
+        let scores = <[u8; 4]>::deserialize(&mut legacy_data)?;
+        let meta_data_merkle_root = <[u8; 64]>::deserialize(&mut legacy_data)?;
+        let bump = u8::deserialize(&mut legacy_data)?;
+
+        let average_score = scores.iter().map(|score| *score as u16).sum::<u16>() / 4;
+
+        Ok(Self {
+            address,
+            reviewer_pubkey,
+            paper_pubkey,
+            score_count: scores.len() as u8,
+            scores: Self::scores_from_slice(&scores),
+            meta_data_merkle_root,
+            is_approval: average_score > 50,
+            reward_claimed: 0,
+            consensus_weight: 0,
+            is_revealed: true,
+            commitment: [0; 32],
+            is_amended: false,
+            is_invalidated: false,
+            paper_version: 0,
+            is_tallied: false,
+            is_weighted: false,
+            bump,
+        })
+    }
+
+    // A migrated review is counted into the already migrated paper's reviews and statistics,
+    // its approval was counted when it was added
+
+    pub fn migrate(
+        peer_review_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+    ) -> ProgramResult {
+        let peer_review = Self::from_account_data(&peer_review_pda_acc.try_borrow_data()?)?;
+
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        paper.total_reviews = paper
+            .total_reviews
+            .checked_add(1)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+
+        paper.add_review_stats(peer_review.dimension_scores())?;
+
+        peer_review_pda_acc.realloc(Self::size(), false)?;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        peer_review.serialize(&mut data_bytes)?;
+
+        peer_review_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn dimension_scores(&self) -> &[u8] {
+        &self.scores[..self.score_count as usize]
+    }
+
+    // Only blind reviews carry a commitment, open reviews leave it zeroed
+
+    pub fn is_blind(&self) -> bool {
+        self.commitment != [0; 32]
+    }
+
+    fn scores_from_slice(scores: &[u8]) -> [u8; MAX_RUBRIC_DIMENSIONS] {
+        let mut padded_scores = [0; MAX_RUBRIC_DIMENSIONS];
+
+        padded_scores[..scores.len()].copy_from_slice(scores);
+
+        padded_scores
+    }
+
+    pub fn create_new(
+        peer_review_pda_acc: &AccountInfo,
+        reviewer_acc: &AccountInfo,
         paper_pda_acc: &AccountInfo,
         researcher_profile_pda_acc: &AccountInfo,
-        data: MintResearchPaper,
+        data: AddPeerReview,
     ) -> ProgramResult {
-        let research_token_acc = Self {
-            address: *research_token_pda_acc.key,
+        let merkle_root_bytes = checked_string_convt_to_64_bytes(&data.meta_data_merkle_root)?;
+
+        let paper_version =
+            ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?.version;
+
+        let mut peer_review = Self {
+            address: *peer_review_pda_acc.key,
+            reviewer_pubkey: *reviewer_acc.key,
             paper_pubkey: *paper_pda_acc.key,
-            researcher_pubkey: *researcher_acc.key,
+            score_count: data.scores.len() as u8,
+            scores: Self::scores_from_slice(&data.scores),
+            meta_data_merkle_root: merkle_root_bytes,
+            is_approval: false,
+            reward_claimed: 0,
+            consensus_weight: 0,
+            is_revealed: true,
+            commitment: [0; 32],
+            is_amended: false,
+            is_invalidated: false,
+            paper_version,
+            is_tallied: false,
+            is_weighted: false,
             bump: data.pda_bump,
         };
 
+        peer_review.record(paper_pda_acc, researcher_profile_pda_acc)?;
+
         let mut data_bytes: Vec<u8> = Vec::new();
 
-        research_token_acc.serialize(&mut data_bytes)?;
+        peer_review.serialize(&mut data_bytes)?;
 
-        research_token_pda_acc
+        peer_review_pda_acc
             .try_borrow_mut_data()?
             .copy_from_slice(&data_bytes);
 
-        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+        Ok(())
+    }
 
-        if paper.state != PaperState::Published {
-            return Err(DeResearcherError::InvalidState.into());
-        }
+    // Blind reviews start out as a commitment with empty scores
 
-        paper.total_citations += 1;
+    pub fn create_commitment(
+        peer_review_pda_acc: &AccountInfo,
+        reviewer_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        data: CommitReview,
+    ) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
 
-        paper.total_mints += 1;
+        paper.pending_commitments = paper
+            .pending_commitments
+            .checked_add(1)
+            .ok_or(DeResearcherError::SizeOverflow)?;
 
         let mut data_bytes: Vec<u8> = Vec::new();
 
@@ -369,19 +1292,2550 @@ impl ResearchTokenAccount {
             .try_borrow_mut_data()?
             .copy_from_slice(&data_bytes);
 
-        let mut researcher_profile =
-            ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.try_borrow_data()?)?;
+        let paper_version = paper.version;
 
-        researcher_profile.total_citations += 1;
+        let peer_review = Self {
+            address: *peer_review_pda_acc.key,
+            reviewer_pubkey: *reviewer_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            score_count: 0,
+            scores: [0; 8],
+            meta_data_merkle_root: [0; 64],
+            is_approval: false,
+            reward_claimed: 0,
+            consensus_weight: 0,
+            is_revealed: false,
+            commitment: data.commitment,
+            is_amended: false,
+            is_invalidated: false,
+            paper_version,
+            is_tallied: false,
+            is_weighted: false,
+            bump: data.pda_bump,
+        };
 
         let mut data_bytes: Vec<u8> = Vec::new();
 
-        researcher_profile.serialize(&mut data_bytes)?;
+        peer_review.serialize(&mut data_bytes)?;
 
-        researcher_profile_pda_acc
+        peer_review_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // The commitment is the hash of the reviewer, paper, paper version, the rubric scores
+    // in order and the salt, so a commitment can't be replayed by another reviewer
+
+    pub fn reveal(
+        peer_review_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        researcher_profile_pda_acc: &AccountInfo,
+        data: RevealReview,
+    ) -> ProgramResult {
+        let mut peer_review = PeerReview::try_from_slice(&peer_review_pda_acc.try_borrow_data()?)?;
+
+        if peer_review.is_revealed {
+            return Err(DeResearcherError::PeerReviewAlreadyRevealed.into());
+        }
+
+        let commitment = hashv(&[
+            peer_review.reviewer_pubkey.as_ref(),
+            peer_review.paper_pubkey.as_ref(),
+            &[peer_review.paper_version],
+            &data.scores,
+            &data.salt,
+        ]);
+
+        if commitment.to_bytes() != peer_review.commitment {
+            return Err(DeResearcherError::CommitmentMismatch.into());
+        }
+
+        ResearchPaper::settle_commitment(paper_pda_acc, peer_review.paper_version)?;
+
+        peer_review.score_count = data.scores.len() as u8;
+        peer_review.scores = Self::scores_from_slice(&data.scores);
+        peer_review.meta_data_merkle_root =
+            checked_string_convt_to_64_bytes(&data.meta_data_merkle_root)?;
+        peer_review.is_revealed = true;
+
+        peer_review.record(paper_pda_acc, researcher_profile_pda_acc)?;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        peer_review.serialize(&mut data_bytes)?;
+
+        peer_review_pda_acc
             .try_borrow_mut_data()?
             .copy_from_slice(&data_bytes);
 
         Ok(())
     }
+
+    // Tally the review's scores into the paper's approvals and the reviewer's profile
+
+    fn record(
+        &mut self,
+        paper_pda_acc: &AccountInfo,
+        researcher_profile_pda_acc: &AccountInfo,
+    ) -> ProgramResult {
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+        if self.paper_version != paper.version {
+            return Err(DeResearcherError::ReviewVersionMismatch.into());
+        }
+
+        let is_open = if self.is_blind() {
+            paper.state.accepts_reveal()
+        } else {
+            paper.state.is_under_review()
+        };
+
+        if !is_open {
+            return Err(DeResearcherError::InvalidState.into());
+        }
+
+        let weighted_score = paper.weighted_score(self.dimension_scores());
+
+        if paper.state == PaperState::AwaitingPeerReview {
+            paper.state = PaperState::InPeerReview;
+        }
+
+        paper.total_reviews = paper
+            .total_reviews
+            .checked_add(1)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+
+        // Approvals never outnumber reviews, so they can't overflow once the review count didn't
+
+        if weighted_score > paper.approval_threshold {
+            paper.total_approvals += 1;
+            self.is_approval = true;
+        }
+
+        paper.add_review_stats(self.dimension_scores())?;
+
+        paper.update_approval_state();
+
+        paper.total_citations += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut researcher_profile =
+            ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.try_borrow_data()?)?;
+
+        researcher_profile.total_reviews += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        researcher_profile.serialize(&mut data_bytes)?;
+
+        researcher_profile_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // Swap the review's old scores for the amended ones in the paper's tally and statistics
+
+    pub fn amend(
+        peer_review_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        data: AmendPeerReview,
+    ) -> ProgramResult {
+        let mut peer_review = PeerReview::try_from_slice(&peer_review_pda_acc.try_borrow_data()?)?;
+
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        if peer_review.paper_version != paper.version {
+            return Err(DeResearcherError::ReviewVersionMismatch.into());
+        }
+
+        paper.remove_review_stats(peer_review.dimension_scores())?;
+
+        if peer_review.is_approval {
+            paper.total_approvals -= 1;
+        }
+
+        peer_review.score_count = data.scores.len() as u8;
+        peer_review.scores = Self::scores_from_slice(&data.scores);
+        peer_review.meta_data_merkle_root =
+            checked_string_convt_to_64_bytes(&data.meta_data_merkle_root)?;
+        peer_review.is_approval =
+            paper.weighted_score(peer_review.dimension_scores()) > paper.approval_threshold;
+        peer_review.is_amended = true;
+
+        if peer_review.is_approval {
+            paper.total_approvals += 1;
+        }
+
+        paper.add_review_stats(peer_review.dimension_scores())?;
+
+        paper.update_approval_state();
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        peer_review.serialize(&mut data_bytes)?;
+
+        peer_review_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // An invalidated review no longer counts towards the paper's approvals, statistics or
+    // reward split, reviews of a superseded version already stopped counting when the paper
+    // was revised
+
+    pub fn invalidate(
+        peer_review_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        reviewer_pool_pda_acc: &AccountInfo,
+    ) -> ProgramResult {
+        let mut peer_review = PeerReview::try_from_slice(&peer_review_pda_acc.try_borrow_data()?)?;
+
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        let mut reviewer_pool =
+            ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.try_borrow_data()?)?;
+
+        if peer_review.paper_version == paper.version {
+            paper.remove_review_stats(peer_review.dimension_scores())?;
+
+            if peer_review.is_approval {
+                paper.total_approvals -= 1;
+
+                if peer_review.is_weighted {
+                    reviewer_pool.total_consensus_weight = reviewer_pool
+                        .total_consensus_weight
+                        .checked_sub(peer_review.consensus_weight as u64)
+                        .ok_or(DeResearcherError::SizeOverflow)?;
+                }
+            }
+
+            paper.update_approval_state();
+        }
+
+        peer_review.is_approval = false;
+        peer_review.is_invalidated = true;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        reviewer_pool.serialize(&mut data_bytes)?;
+
+        reviewer_pool_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        peer_review.serialize(&mut data_bytes)?;
+
+        peer_review_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // Weight is 100 minus the mean distance from the median across all dimensions,
+    // returns whether the review is within tolerance of the consensus
+
+    pub fn record_consensus(&mut self, medians: &[u8]) -> bool {
+        let total_deviation: u16 = self
+            .dimension_scores()
+            .iter()
+            .zip(medians.iter())
+            .map(|(score, median)| score.abs_diff(*median) as u16)
+            .sum();
+
+        let mean_deviation = (total_deviation / self.score_count.max(1) as u16).min(100) as u8;
+
+        self.consensus_weight = 100 - mean_deviation;
+
+        mean_deviation <= CONSENSUS_TOLERANCE
+    }
+
+    // Every approving review is entitled to a share of everything deposited so far in
+    // proportion to its consensus weight. If no approval carries any weight the deposits are
+    // split equally between the approvals instead of being stranded in the pool
+
+    pub fn claim_reward(
+        peer_review_pda_acc: &AccountInfo,
+        paper: &ResearchPaper,
+        reviewer_pool: &ReviewerPool,
+    ) -> Result<u64, ProgramError> {
+        let mut peer_review = PeerReview::try_from_slice(&peer_review_pda_acc.try_borrow_data()?)?;
+
+        if peer_review.paper_version != paper.version {
+            return Err(DeResearcherError::ReviewVersionMismatch.into());
+        }
+
+        if !peer_review.is_approval {
+            return Err(DeResearcherError::PeerReviewNotApproved.into());
+        }
+
+        // Claims wait for the reviews to be finalized, which fixes the set of approvals and
+        // their consensus weights, so every claim is measured against the same split
+
+        if !paper.state.is_final() || !reviewer_pool.is_finalized {
+            return Err(DeResearcherError::ReviewsNotFinalized.into());
+        }
+
+        let entitled = if reviewer_pool.total_consensus_weight > 0 {
+            (reviewer_pool.total_deposited as u128 * peer_review.consensus_weight as u128
+                / reviewer_pool.total_consensus_weight as u128) as u64
+        } else {
+            reviewer_pool.total_deposited / paper.total_approvals.max(1) as u64
+        };
+
+        // Approvals invalidated after claiming leave the split short, so a claim never takes
+        // more than what is left in the pool
+
+        let reward = entitled.saturating_sub(peer_review.reward_claimed).min(
+            reviewer_pool
+                .total_deposited
+                .saturating_sub(reviewer_pool.total_claimed),
+        );
+
+        if reward == 0 {
+            return Err(DeResearcherError::NoRewardToClaim.into());
+        }
+
+        peer_review.reward_claimed += reward;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        peer_review.serialize(&mut data_bytes)?;
+
+        peer_review_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(reward)
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, PartialOrd)]
+pub enum AccessKind {
+    Permanent,
+    Rental,
+    Licensed,
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct ResearchTokenAccount {
+    pub address: Pubkey,           // Mint Collection's public key 32 bytes
+    pub paper_pubkey: Pubkey,      // Paper's public key 32 bytes
+    pub researcher_pubkey: Pubkey, // Owner's public key 32 bytes
+    pub kind: AccessKind,          // Permanent, rented or licensed access 1 byte
+    pub expires_at: i64,           // Time access ends, 0 if permanent 8 bytes
+    pub bump: u8,                  // Bump seed 1 byte
+}
+
+impl ResearchTokenAccount {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 1 + 8 + 1 // 106
+    }
+
+    pub fn legacy_size() -> usize {
+        32 + 32 + 32 + 1 // 97
+    }
+
+    // Tokens minted before rentals existed have no kind or expiry and grant permanent access
+
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::legacy_size() {
+            return Ok(Self::try_from_slice(data)?);
+        }
+
+        let mut legacy_data = data;
+
+        Ok(Self {
+            address: Pubkey::deserialize(&mut legacy_data)?,
+            paper_pubkey: Pubkey::deserialize(&mut legacy_data)?,
+            researcher_pubkey: Pubkey::deserialize(&mut legacy_data)?,
+            kind: AccessKind::Permanent,
+            expires_at: 0,
+            bump: u8::deserialize(&mut legacy_data)?,
+        })
+    }
+
+    // Rented and licensed access both stop at expires_at
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.kind != AccessKind::Permanent && now >= self.expires_at
+    }
+
+    pub fn mint_paper(
+        research_token_pda_acc: &AccountInfo,
+        owner: &Pubkey,
+        paper_pda_acc: &AccountInfo,
+        researcher_profile_pda_acc: &AccountInfo,
+        pda_bump: u8,
+        is_gift: bool,
+    ) -> ProgramResult {
+        let research_token_acc = Self {
+            address: *research_token_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            researcher_pubkey: *owner,
+            kind: AccessKind::Permanent,
+            expires_at: 0,
+            bump: pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        research_token_acc.serialize(&mut data_bytes)?;
+
+        research_token_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow())?;
+
+        paper.check_purchasable(Clock::get()?.unix_timestamp)?;
+
+        // The first mint after the embargo records the release
+
+        if paper.state == PaperState::ScheduledPublish {
+            paper.state = PaperState::Published;
+        }
+
+        paper.check_edition_supply(is_gift)?;
+
+        if is_gift {
+            paper.gifted_mints += 1;
+        }
+
+        paper.total_citations += 1;
+
+        paper.total_mints += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut researcher_profile =
+            ResearcherProfile::try_from_slice(&researcher_profile_pda_acc.try_borrow_data()?)?;
+
+        researcher_profile.total_citations += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        researcher_profile.serialize(&mut data_bytes)?;
+
+        researcher_profile_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // The institution pays for licensed access, so like a rental a claim takes no edition,
+    // doesn't move the price along the curve or tiers and isn't counted as a citation
+
+    pub fn claim_licensed_paper(
+        research_token_pda_acc: &AccountInfo,
+        member_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        pda_bump: u8,
+        expires_at: i64,
+    ) -> ProgramResult {
+        let research_token_acc = Self {
+            address: *research_token_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            researcher_pubkey: *member_acc.key,
+            kind: AccessKind::Licensed,
+            expires_at,
+            bump: pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        research_token_acc.serialize(&mut data_bytes)?;
+
+        research_token_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn rent_paper(
+        research_token_pda_acc: &AccountInfo,
+        researcher_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        paper: &ResearchPaper,
+        pda_bump: u8,
+        now: i64,
+    ) -> ProgramResult {
+        let research_token_acc = Self {
+            address: *research_token_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            researcher_pubkey: *researcher_acc.key,
+            kind: AccessKind::Rental,
+            expires_at: now
+                .checked_add(paper.rental_period_secs)
+                .ok_or(DeResearcherError::SizeOverflow)?,
+            bump: pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        research_token_acc.serialize(&mut data_bytes)?;
+
+        research_token_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // An expired rental is extended from now rather than from its old expiry
+
+    pub fn extend_rental(
+        research_token_pda_acc: &AccountInfo,
+        rental_period_secs: i64,
+        now: i64,
+    ) -> ProgramResult {
+        let mut research_token =
+            ResearchTokenAccount::from_account_data(&research_token_pda_acc.try_borrow_data()?)?;
+
+        research_token.expires_at = research_token
+            .expires_at
+            .max(now)
+            .checked_add(rental_period_secs)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        research_token.serialize(&mut data_bytes)?;
+
+        research_token_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct ReviewStake {
+    pub address: Pubkey,            // Stake vault's public key 32 bytes
+    pub peer_review_pubkey: Pubkey, // Peer review's public key 32 bytes
+    pub reviewer_pubkey: Pubkey,    // Reviewer's public key 32 bytes
+    pub paper_pubkey: Pubkey,       // Paper's public key 32 bytes
+    pub amount: u64,                // Lamports currently locked 8 bytes
+    pub slashed_amount: u64,        // Lamports slashed into the treasury 8 bytes
+    pub bump: u8,                   // Bump seed 1 byte
+}
+
+impl ReviewStake {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 32 + 8 + 8 + 1 // 145
+    }
+
+    pub fn create_new(
+        review_stake_pda_acc: &AccountInfo,
+        peer_review_pda_acc: &AccountInfo,
+        reviewer_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        amount: u64,
+        bump: u8,
+    ) -> ProgramResult {
+        let review_stake = Self {
+            address: *review_stake_pda_acc.key,
+            peer_review_pubkey: *peer_review_pda_acc.key,
+            reviewer_pubkey: *reviewer_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            amount,
+            slashed_amount: 0,
+            bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        review_stake.serialize(&mut data_bytes)?;
+
+        review_stake_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // Returns the lamports to move into the treasury, a stake can only be slashed once
+
+    pub fn slash(
+        review_stake_pda_acc: &AccountInfo,
+        data: SlashReviewStake,
+    ) -> Result<u64, ProgramError> {
+        let mut review_stake =
+            ReviewStake::try_from_slice(&review_stake_pda_acc.try_borrow_data()?)?;
+
+        if review_stake.slashed_amount > 0 {
+            return Err(DeResearcherError::ReviewStakeAlreadySlashed.into());
+        }
+
+        if data.slash_bps == 0 || data.slash_bps > MAX_BASIS_POINTS {
+            return Err(DeResearcherError::InvalidSlashAmount.into());
+        }
+
+        let slashed_amount = (review_stake.amount as u128 * data.slash_bps as u128
+            / MAX_BASIS_POINTS as u128) as u64;
+
+        review_stake.amount -= slashed_amount;
+
+        review_stake.slashed_amount = slashed_amount;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        review_stake.serialize(&mut data_bytes)?;
+
+        review_stake_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(slashed_amount)
+    }
+}
+
+// Shank only reads literal array lengths, so the histogram size is checked here instead
+const _: () = assert!(MAX_RUBRIC_DIMENSIONS * SCORE_HISTOGRAM_BUCKETS == 808);
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct ReviewerPool {
+    pub address: Pubkey,             // Reviewer pool's public key 32 bytes
+    pub paper_pubkey: Pubkey,        // Paper's public key 32 bytes
+    pub total_deposited: u64,        // Lamports deposited from access fees 8 bytes
+    pub total_claimed: u64,          // Lamports claimed by reviewers 8 bytes
+    pub is_finalized: bool,          // Whether reviews were finalized 1 byte
+    pub total_consensus_weight: u64, // Sum of approving reviews' consensus weights 8 bytes
+    pub tallied_reviews: u8,         // Reviews counted into the score histogram 1 byte
+    pub weighted_reviews: u8,        // Reviews weighted against the median scores 1 byte
+    pub score_histogram: [u8; 808],  // Count of each score per rubric dimension 808 bytes
+    pub bump: u8,                    // Bump seed 1 byte
+}
+
+impl ReviewerPool {
+    pub fn size() -> usize {
+        32 + 32 + 8 + 8 + 1 + 8 + 1 + 1 + 808 + 1 // 900
+    }
+
+    pub fn median_scores(&self, dimension_count: u8) -> [u8; MAX_RUBRIC_DIMENSIONS] {
+        let mut medians = [0; MAX_RUBRIC_DIMENSIONS];
+
+        for (median, score_counts) in medians
+            .iter_mut()
+            .zip(self.score_histogram.chunks(SCORE_HISTOGRAM_BUCKETS))
+            .take(dimension_count as usize)
+        {
+            *median = median_score(score_counts);
+        }
+
+        medians
+    }
+
+    pub fn create_new(
+        reviewer_pool_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        pda_bump: u8,
+    ) -> ProgramResult {
+        let reviewer_pool = Self {
+            address: *reviewer_pool_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            total_deposited: 0,
+            total_claimed: 0,
+            is_finalized: false,
+            total_consensus_weight: 0,
+            tallied_reviews: 0,
+            weighted_reviews: 0,
+            score_histogram: [0; 808],
+            bump: pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        reviewer_pool.serialize(&mut data_bytes)?;
+
+        reviewer_pool_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn deposit(reviewer_pool_pda_acc: &AccountInfo, amount: u64) -> ProgramResult {
+        let mut reviewer_pool =
+            ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.try_borrow_data()?)?;
+
+        reviewer_pool.total_deposited += amount;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        reviewer_pool.serialize(&mut data_bytes)?;
+
+        reviewer_pool_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn withdraw(reviewer_pool_pda_acc: &AccountInfo, amount: u64) -> ProgramResult {
+        let mut reviewer_pool =
+            ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.try_borrow_data()?)?;
+
+        reviewer_pool.total_claimed += amount;
+
+        if reviewer_pool.total_claimed > reviewer_pool.total_deposited {
+            return Err(DeResearcherError::NoRewardToClaim.into());
+        }
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        reviewer_pool.serialize(&mut data_bytes)?;
+
+        reviewer_pool_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // First pass of FinalizeReviews, invalidated reviews are counted towards the cursor
+    // but left out of the medians
+
+    pub fn tally_review(
+        reviewer_pool_pda_acc: &AccountInfo,
+        peer_review_pda_acc: &AccountInfo,
+        dimension_count: u8,
+    ) -> ProgramResult {
+        let mut reviewer_pool =
+            ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.try_borrow_data()?)?;
+
+        let mut peer_review = PeerReview::try_from_slice(&peer_review_pda_acc.try_borrow_data()?)?;
+
+        if peer_review.is_tallied {
+            return Err(DeResearcherError::PeerReviewAlreadyFinalized.into());
+        }
+
+        if !peer_review.is_invalidated {
+            for (dimension, score) in peer_review
+                .scores
+                .iter()
+                .enumerate()
+                .take(dimension_count as usize)
+            {
+                let bucket = dimension * SCORE_HISTOGRAM_BUCKETS + *score as usize;
+
+                reviewer_pool.score_histogram[bucket] += 1;
+            }
+        }
+
+        peer_review.is_tallied = true;
+
+        reviewer_pool.tallied_reviews += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        reviewer_pool.serialize(&mut data_bytes)?;
+
+        reviewer_pool_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        peer_review.serialize(&mut data_bytes)?;
+
+        peer_review_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // Second pass of FinalizeReviews, the pool is finalized once its last review is weighted
+
+    pub fn weigh_review(
+        reviewer_pool_pda_acc: &AccountInfo,
+        peer_review_pda_acc: &AccountInfo,
+        researcher_profile_pda_acc: &AccountInfo,
+        paper_version_pda_acc: &AccountInfo,
+        paper: &ResearchPaper,
+    ) -> ProgramResult {
+        let mut reviewer_pool =
+            ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.try_borrow_data()?)?;
+
+        let mut peer_review = PeerReview::try_from_slice(&peer_review_pda_acc.try_borrow_data()?)?;
+
+        if !peer_review.is_tallied || peer_review.is_weighted {
+            return Err(DeResearcherError::PeerReviewAlreadyFinalized.into());
+        }
+
+        if !peer_review.is_invalidated {
+            let medians = reviewer_pool.median_scores(paper.rubric_dimension_count);
+
+            if peer_review.record_consensus(&medians) {
+                ResearcherProfile::reward_consensus(researcher_profile_pda_acc)?;
+            }
+
+            if peer_review.is_approval {
+                reviewer_pool.total_consensus_weight += peer_review.consensus_weight as u64;
+            }
+        }
+
+        peer_review.is_weighted = true;
+
+        reviewer_pool.weighted_reviews += 1;
+
+        reviewer_pool.is_finalized = reviewer_pool.weighted_reviews >= paper.total_reviews;
+
+        if reviewer_pool.is_finalized {
+            PaperVersion::record_approvals(paper_version_pda_acc, paper, 0)?;
+        }
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        reviewer_pool.serialize(&mut data_bytes)?;
+
+        reviewer_pool_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        peer_review.serialize(&mut data_bytes)?;
+
+        peer_review_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // A revised paper is reviewed again, so its reviews have to be finalized again
+
+    pub fn reopen(reviewer_pool_pda_acc: &AccountInfo) -> ProgramResult {
+        let mut reviewer_pool =
+            ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.try_borrow_data()?)?;
+
+        reviewer_pool.is_finalized = false;
+
+        reviewer_pool.total_consensus_weight = 0;
+
+        reviewer_pool.tallied_reviews = 0;
+
+        reviewer_pool.weighted_reviews = 0;
+
+        reviewer_pool.score_histogram = [0; 808];
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        reviewer_pool.serialize(&mut data_bytes)?;
+
+        reviewer_pool_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct Editor {
+    pub address: Pubkey,       // Editor PDA's public key 32 bytes
+    pub venue_pubkey: Pubkey,  // Journal the editor works for 32 bytes
+    pub editor_pubkey: Pubkey, // Editor's public key 32 bytes
+    pub bump: u8,              // Bump seed 1 byte
+}
+
+impl Editor {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 1 // 97
+    }
+
+    pub fn create_new(
+        editor_pda_acc: &AccountInfo,
+        journal_pda_acc: &AccountInfo,
+        editor_acc: &AccountInfo,
+        data: AppointEditor,
+    ) -> ProgramResult {
+        let editor = Self {
+            address: *editor_pda_acc.key,
+            venue_pubkey: *journal_pda_acc.key,
+            editor_pubkey: *editor_acc.key,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        editor.serialize(&mut data_bytes)?;
+
+        editor_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct ReviewInvitation {
+    pub address: Pubkey,         // Invitation's public key 32 bytes
+    pub paper_pubkey: Pubkey,    // Paper's public key 32 bytes
+    pub reviewer_pubkey: Pubkey, // Invited reviewer's public key 32 bytes
+    pub editor_pubkey: Pubkey,   // Inviting editor's public key 32 bytes
+    pub bump: u8,                // Bump seed 1 byte
+}
+
+impl ReviewInvitation {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 32 + 1 // 129
+    }
+
+    pub fn create_new(
+        review_invitation_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        reviewer_acc: &AccountInfo,
+        editor_acc: &AccountInfo,
+        data: InviteReviewer,
+    ) -> ProgramResult {
+        let review_invitation = Self {
+            address: *review_invitation_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            reviewer_pubkey: *reviewer_acc.key,
+            editor_pubkey: *editor_acc.key,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        review_invitation.serialize(&mut data_bytes)?;
+
+        review_invitation_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct Journal {
+    pub address: Pubkey,          // Journal's public key 32 bytes
+    pub authority_pubkey: Pubkey, // Authority managing the journal 32 bytes
+    pub name: [u8; 64],           // Journal's name 64 bytes
+    pub submission_fee: u64,      // Lamports paid to the authority on submission 8 bytes
+    pub min_approvals: u8,        // Approvals required to publish 1 byte
+    pub rubric_pubkey: Pubkey,    // Rubric submissions are scored against 32 bytes
+    pub total_editors: u32,       // Total editors appointed 4 bytes
+    pub total_papers: u64,        // Total papers submitted 8 bytes
+    pub bump: u8,                 // Bump seed 1 byte
+}
+
+impl Journal {
+    pub fn size() -> usize {
+        32 + 32 + 64 + 8 + 1 + 32 + 4 + 8 + 1 // 182
+    }
+
+    pub fn create_new(
+        journal_pda_acc: &AccountInfo,
+        authority_acc: &AccountInfo,
+        rubric_pda_acc: &AccountInfo,
+        data: CreateJournal,
+    ) -> ProgramResult {
+        let name_bytes = checked_string_convt_to_64_bytes(&data.name)?;
+
+        let journal = Self {
+            address: *journal_pda_acc.key,
+            authority_pubkey: *authority_acc.key,
+            name: name_bytes,
+            submission_fee: data.submission_fee,
+            min_approvals: data.min_approvals,
+            rubric_pubkey: *rubric_pda_acc.key,
+            total_editors: 0,
+            total_papers: 0,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        journal.serialize(&mut data_bytes)?;
+
+        journal_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn add_editor(journal_pda_acc: &AccountInfo) -> ProgramResult {
+        let mut journal = Journal::try_from_slice(&journal_pda_acc.try_borrow_data()?)?;
+
+        journal.total_editors += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        journal.serialize(&mut data_bytes)?;
+
+        journal_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn add_paper(journal_pda_acc: &AccountInfo) -> ProgramResult {
+        let mut journal = Journal::try_from_slice(&journal_pda_acc.try_borrow_data()?)?;
+
+        journal.total_papers += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        journal.serialize(&mut data_bytes)?;
+
+        journal_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct Rubric {
+    pub address: Pubkey,            // Rubric's public key 32 bytes
+    pub authority_pubkey: Pubkey,   // Authority that defined the rubric 32 bytes
+    pub name: [u8; 64],             // Rubric's name 64 bytes
+    pub dimension_count: u8,        // Number of scored dimensions 1 byte
+    pub dimension_names: [u8; 512], // 64 byte name of each dimension 512 bytes
+    pub weights: [u16; 8],          // Weight of each dimension 16 bytes
+    pub approval_threshold: u8,     // Weighted score a review must exceed 1 byte
+    pub bump: u8,                   // Bump seed 1 byte
+}
+
+impl Rubric {
+    pub fn size() -> usize {
+        32 + 32 + 64 + 1 + 512 + 16 + 1 + 1 // 659
+    }
+
+    pub fn create_new(
+        rubric_pda_acc: &AccountInfo,
+        authority_acc: &AccountInfo,
+        data: CreateRubric,
+    ) -> ProgramResult {
+        let name_bytes = checked_string_convt_to_64_bytes(&data.name)?;
+
+        let mut dimension_names = [0; MAX_RUBRIC_DIMENSIONS * MAX_STRING_SIZE];
+
+        for (dimension_name, name) in dimension_names
+            .chunks_mut(MAX_STRING_SIZE)
+            .zip(data.dimension_names.iter())
+        {
+            dimension_name.copy_from_slice(&checked_string_convt_to_64_bytes(name)?);
+        }
+
+        let mut weights = [0; MAX_RUBRIC_DIMENSIONS];
+
+        weights[..data.weights.len()].copy_from_slice(&data.weights);
+
+        let rubric = Self {
+            address: *rubric_pda_acc.key,
+            authority_pubkey: *authority_acc.key,
+            name: name_bytes,
+            dimension_count: data.dimension_names.len() as u8,
+            dimension_names,
+            weights,
+            approval_threshold: data.approval_threshold,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        rubric.serialize(&mut data_bytes)?;
+
+        rubric_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct AuthorResponse {
+    pub address: Pubkey,                 // Author response's public key 32 bytes
+    pub peer_review_pubkey: Pubkey,      // Peer review being responded to 32 bytes
+    pub paper_pubkey: Pubkey,            // Paper's public key 32 bytes
+    pub author_pubkey: Pubkey,           // Paper author's public key 32 bytes
+    pub meta_data_merkle_root: [u8; 64], // Data merkle root 64 bytes
+    pub created_at: i64,                 // Unix timestamp of the response 8 bytes
+    pub bump: u8,                        // Bump seed 1 byte
+}
+
+impl AuthorResponse {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 32 + 64 + 8 + 1 // 201
+    }
+
+    pub fn create_new(
+        author_response_pda_acc: &AccountInfo,
+        peer_review_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        author_acc: &AccountInfo,
+        data: CreateAuthorResponse,
+    ) -> ProgramResult {
+        let merkle_root_bytes = checked_string_convt_to_64_bytes(&data.meta_data_merkle_root)?;
+
+        let author_response = Self {
+            address: *author_response_pda_acc.key,
+            peer_review_pubkey: *peer_review_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            author_pubkey: *author_acc.key,
+            meta_data_merkle_root: merkle_root_bytes,
+            created_at: Clock::get()?.unix_timestamp,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        author_response.serialize(&mut data_bytes)?;
+
+        author_response_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct ArbiterSet {
+    pub address: Pubkey,       // Arbiter set's public key 32 bytes
+    pub arbiters: [Pubkey; 5], // Arbiters' public keys 160 bytes
+    pub arbiter_count: u8,     // Number of arbiters 1 byte
+    pub quorum: u8,            // Votes needed to resolve a dispute 1 byte
+    pub bump: u8,              // Bump seed 1 byte
+}
+
+impl ArbiterSet {
+    pub fn size() -> usize {
+        32 + 160 + 1 + 1 + 1 // 195
+    }
+
+    pub fn is_arbiter(&self, arbiter: &Pubkey) -> bool {
+        self.arbiters[..self.arbiter_count as usize].contains(arbiter)
+    }
+
+    pub fn set(arbiter_set_pda_acc: &AccountInfo, data: SetArbiters) -> ProgramResult {
+        let mut arbiters = [Pubkey::default(); MAX_ARBITERS];
+
+        arbiters[..data.arbiters.len()].copy_from_slice(&data.arbiters);
+
+        let arbiter_set = Self {
+            address: *arbiter_set_pda_acc.key,
+            arbiters,
+            arbiter_count: data.arbiters.len() as u8,
+            quorum: data.quorum,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        arbiter_set.serialize(&mut data_bytes)?;
+
+        arbiter_set_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, PartialOrd)]
+pub enum DisputeState {
+    Open,
+    Upheld,
+    Rejected,
+    Expired,
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct Dispute {
+    pub address: Pubkey,                 // Dispute's public key 32 bytes
+    pub paper_pubkey: Pubkey,            // Paper's public key 32 bytes
+    pub peer_review_pubkey: Pubkey,      // Disputed peer review's public key 32 bytes
+    pub author_pubkey: Pubkey,           // Author who opened the dispute 32 bytes
+    pub state: DisputeState,             // Current state of the dispute 1 byte
+    pub bond: u64,                       // Lamports bonded by the author 8 bytes
+    pub meta_data_merkle_root: [u8; 64], // Data merkle root 64 bytes
+    pub opened_at: i64,                  // Unix timestamp the dispute was opened 8 bytes
+    pub votes_to_uphold: u8,             // Arbiter votes to invalidate the review 1 byte
+    pub votes_to_reject: u8,             // Arbiter votes to keep the review 1 byte
+    pub voted_arbiters: u8,              // Bitmap of arbiters that voted 1 byte
+    pub arbiters: [Pubkey; 5],           // Arbiters when the dispute was opened 160 bytes
+    pub arbiter_count: u8,               // Number of arbiters 1 byte
+    pub quorum: u8,                      // Votes needed to resolve the dispute 1 byte
+    pub expires_at: i64,                 // Deadline for the arbiters to resolve it 8 bytes
+    pub bump: u8,                        // Bump seed 1 byte
+}
+
+impl Dispute {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 32 + 1 + 8 + 64 + 8 + 1 + 1 + 1 // 212
+            + 160 + 1 + 1 + 8 + 1 // 383
+    }
+
+    // voted_arbiters is a bitmap over arbiter positions, so the set is fixed when the dispute opens
+
+    pub fn arbiter_index(&self, arbiter: &Pubkey) -> Option<usize> {
+        self.arbiters[..self.arbiter_count as usize]
+            .iter()
+            .position(|candidate| candidate.eq(arbiter))
+    }
+
+    pub fn create_new(
+        dispute_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        peer_review_pda_acc: &AccountInfo,
+        author_acc: &AccountInfo,
+        arbiter_set: &ArbiterSet,
+        bond: u64,
+        data: OpenDispute,
+    ) -> ProgramResult {
+        let merkle_root_bytes = checked_string_convt_to_64_bytes(&data.meta_data_merkle_root)?;
+
+        let opened_at = Clock::get()?.unix_timestamp;
+
+        let dispute = Self {
+            address: *dispute_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            peer_review_pubkey: *peer_review_pda_acc.key,
+            author_pubkey: *author_acc.key,
+            state: DisputeState::Open,
+            bond,
+            meta_data_merkle_root: merkle_root_bytes,
+            opened_at,
+            votes_to_uphold: 0,
+            votes_to_reject: 0,
+            voted_arbiters: 0,
+            arbiters: arbiter_set.arbiters,
+            arbiter_count: arbiter_set.arbiter_count,
+            quorum: arbiter_set.quorum,
+            expires_at: opened_at.saturating_add(DISPUTE_RESOLUTION_WINDOW_SECS),
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        dispute.serialize(&mut data_bytes)?;
+
+        dispute_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // Returns the dispute's state after the vote, it resolves as soon as either side reaches quorum
+
+    pub fn vote(
+        dispute_pda_acc: &AccountInfo,
+        arbiter_index: usize,
+        uphold: bool,
+    ) -> Result<DisputeState, ProgramError> {
+        let mut dispute = Dispute::try_from_slice(&dispute_pda_acc.try_borrow_data()?)?;
+
+        let arbiter_bit = 1 << arbiter_index;
+
+        if dispute.voted_arbiters & arbiter_bit != 0 {
+            return Err(DeResearcherError::ArbiterAlreadyVoted.into());
+        }
+
+        dispute.voted_arbiters |= arbiter_bit;
+
+        if uphold {
+            dispute.votes_to_uphold += 1;
+        } else {
+            dispute.votes_to_reject += 1;
+        }
+
+        if dispute.votes_to_uphold >= dispute.quorum {
+            dispute.state = DisputeState::Upheld;
+        } else if dispute.votes_to_reject >= dispute.quorum {
+            dispute.state = DisputeState::Rejected;
+        }
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        dispute.serialize(&mut data_bytes)?;
+
+        dispute_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(dispute.state)
+    }
+
+    // A dispute the arbiters never resolve expires, and the author gets the bond back
+
+    pub fn expire(dispute_pda_acc: &AccountInfo, now: i64) -> ProgramResult {
+        let mut dispute = Dispute::try_from_slice(&dispute_pda_acc.try_borrow_data()?)?;
+
+        if dispute.state != DisputeState::Open {
+            return Err(DeResearcherError::DisputeAlreadyResolved.into());
+        }
+
+        if now < dispute.expires_at {
+            return Err(DeResearcherError::DisputeWindowOpen.into());
+        }
+
+        dispute.state = DisputeState::Expired;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        dispute.serialize(&mut data_bytes)?;
+
+        dispute_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, PartialOrd)]
+pub enum ConflictKind {
+    CoAuthor,
+    Institution,
+    Advisor,
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct ConflictOfInterest {
+    pub address: Pubkey,             // Conflict record's public key 32 bytes
+    pub declarer_pubkey: Pubkey,     // Researcher that declared the conflict 32 bytes
+    pub counterparty_pubkey: Pubkey, // Researcher the declarer is conflicted with 32 bytes
+    pub kind: ConflictKind,          // Nature of the conflict 1 byte
+    pub declared_at: i64,            // Unix timestamp of the declaration 8 bytes
+    pub is_mutual: bool,             // Whether both researchers signed the declaration 1 byte
+    pub bump: u8,                    // Bump seed 1 byte
+}
+
+impl ConflictOfInterest {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 1 + 8 + 1 + 1 // 107
+    }
+
+    pub fn create_new(
+        conflict_of_interest_pda_acc: &AccountInfo,
+        declarer_acc: &AccountInfo,
+        counterparty_acc: &AccountInfo,
+        data: DeclareConflictOfInterest,
+    ) -> ProgramResult {
+        let conflict_of_interest = Self {
+            address: *conflict_of_interest_pda_acc.key,
+            declarer_pubkey: *declarer_acc.key,
+            counterparty_pubkey: *counterparty_acc.key,
+            kind: data.kind,
+            declared_at: Clock::get()?.unix_timestamp,
+            is_mutual: counterparty_acc.is_signer,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        conflict_of_interest.serialize(&mut data_bytes)?;
+
+        conflict_of_interest_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // A one-sided declaration only recuses the declarer, a mutual one bars both researchers
+
+    pub fn bars(&self, researcher: &Pubkey) -> bool {
+        self.is_mutual || self.declarer_pubkey.eq(researcher)
+    }
+
+    pub fn make_mutual(conflict_of_interest_pda_acc: &AccountInfo) -> ProgramResult {
+        let mut conflict_of_interest =
+            ConflictOfInterest::try_from_slice(&conflict_of_interest_pda_acc.try_borrow_data()?)?;
+
+        conflict_of_interest.is_mutual = true;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        conflict_of_interest.serialize(&mut data_bytes)?;
+
+        conflict_of_interest_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct Erratum {
+    pub address: Pubkey,        // Erratum's public key 32 bytes
+    pub paper_pubkey: Pubkey,   // Paper's public key 32 bytes
+    pub author_pubkey: Pubkey,  // Author who appended the erratum 32 bytes
+    pub index: u32,             // Position in the paper's errata 4 bytes
+    pub content_hash: [u8; 64], // Hash of the correction's content 64 bytes
+    pub created_at: i64,        // Unix timestamp of the erratum 8 bytes
+    pub bump: u8,               // Bump seed 1 byte
+}
+
+impl Erratum {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 4 + 64 + 8 + 1 // 173
+    }
+
+    pub fn create_new(
+        erratum_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        author_acc: &AccountInfo,
+        data: AppendErratum,
+    ) -> ProgramResult {
+        let content_hash_bytes = checked_string_convt_to_64_bytes(&data.content_hash)?;
+
+        let mut paper = ResearchPaper::try_from_slice(&paper_pda_acc.try_borrow_data()?)?;
+
+        let erratum = Self {
+            address: *erratum_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            author_pubkey: *author_acc.key,
+            index: paper.total_errata,
+            content_hash: content_hash_bytes,
+            created_at: Clock::get()?.unix_timestamp,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        erratum.serialize(&mut data_bytes)?;
+
+        erratum_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        paper.total_errata += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper.serialize(&mut data_bytes)?;
+
+        paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct PaperVersion {
+    pub address: Pubkey,                 // Paper version's public key 32 bytes
+    pub paper_pubkey: Pubkey,            // Paper's public key 32 bytes
+    pub version: u8,                     // Version index 1 byte
+    pub content_hash: [u8; 64],          // Hash of the version's content 64 bytes
+    pub meta_data_merkle_root: [u8; 64], // Data merkle root 64 bytes
+    pub submitted_at: i64,               // Unix timestamp the version was submitted 8 bytes
+    pub superseded_at: i64,              // Unix timestamp the version was revised 8 bytes
+    pub total_approvals: u8,             // Approvals the version received 1 byte
+    pub total_reviews: u8,               // Peer reviews the version received 1 byte
+    pub bump: u8,                        // Bump seed 1 byte
+}
+
+impl PaperVersion {
+    pub fn size() -> usize {
+        32 + 32 + 1 + 64 + 64 + 8 + 8 + 1 + 1 + 1 // 212
+    }
+
+    pub fn create_new(
+        paper_version_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        version: u8,
+        content_hash: [u8; 64],
+        meta_data_merkle_root: [u8; 64],
+        pda_bump: u8,
+    ) -> ProgramResult {
+        let paper_version = Self {
+            address: *paper_version_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            version,
+            content_hash,
+            meta_data_merkle_root,
+            submitted_at: Clock::get()?.unix_timestamp,
+            superseded_at: 0,
+            total_approvals: 0,
+            total_reviews: 0,
+            bump: pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper_version.serialize(&mut data_bytes)?;
+
+        paper_version_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    // A version's approvals are recorded once it is superseded or its reviews are finalized
+
+    pub fn record_approvals(
+        paper_version_pda_acc: &AccountInfo,
+        paper: &ResearchPaper,
+        superseded_at: i64,
+    ) -> ProgramResult {
+        let mut paper_version =
+            PaperVersion::try_from_slice(&paper_version_pda_acc.try_borrow_data()?)?;
+
+        paper_version.total_approvals = paper.total_approvals;
+        paper_version.total_reviews = paper.total_reviews;
+        paper_version.superseded_at = superseded_at;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        paper_version.serialize(&mut data_bytes)?;
+
+        paper_version_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct ContentHashRecord {
+    pub address: Pubkey,        // Content hash record's public key 32 bytes
+    pub content_hash: [u8; 64], // Registered content hash 64 bytes
+    pub paper_pubkey: Pubkey,   // Paper that registered the hash 32 bytes
+    pub creator_pubkey: Pubkey, // Creator of that paper 32 bytes
+    pub registered_at: i64,     // Unix timestamp of registration 8 bytes
+    pub bump: u8,               // Bump seed 1 byte
+}
+
+impl ContentHashRecord {
+    pub fn size() -> usize {
+        32 + 64 + 32 + 32 + 8 + 1 // 169
+    }
+
+    pub fn create_new(
+        content_hash_record_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        publisher_acc: &AccountInfo,
+        content_hash: [u8; 64],
+        pda_bump: u8,
+    ) -> ProgramResult {
+        let content_hash_record = Self {
+            address: *content_hash_record_pda_acc.key,
+            content_hash,
+            paper_pubkey: *paper_pda_acc.key,
+            creator_pubkey: *publisher_acc.key,
+            registered_at: Clock::get()?.unix_timestamp,
+            bump: pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        content_hash_record.serialize(&mut data_bytes)?;
+
+        content_hash_record_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct DuplicateFlag {
+    pub address: Pubkey,               // Duplicate flag's public key 32 bytes
+    pub original_paper_pubkey: Pubkey, // Flagging author's paper 32 bytes
+    pub suspect_paper_pubkey: Pubkey,  // Paper suspected of duplicating it 32 bytes
+    pub flagger_pubkey: Pubkey,        // Author who raised the flag 32 bytes
+    pub evidence_hash: [u8; 64],       // Hash of the supporting evidence 64 bytes
+    pub flagged_at: i64,               // Unix timestamp of the flag 8 bytes
+    pub bump: u8,                      // Bump seed 1 byte
+}
+
+impl DuplicateFlag {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 32 + 64 + 8 + 1 // 201
+    }
+
+    pub fn create_new(
+        duplicate_flag_pda_acc: &AccountInfo,
+        original_paper_pda_acc: &AccountInfo,
+        suspect_paper_pda_acc: &AccountInfo,
+        author_acc: &AccountInfo,
+        data: FlagDuplicate,
+    ) -> ProgramResult {
+        let evidence_hash_bytes = checked_string_convt_to_64_bytes(&data.evidence_hash)?;
+
+        let duplicate_flag = Self {
+            address: *duplicate_flag_pda_acc.key,
+            original_paper_pubkey: *original_paper_pda_acc.key,
+            suspect_paper_pubkey: *suspect_paper_pda_acc.key,
+            flagger_pubkey: *author_acc.key,
+            evidence_hash: evidence_hash_bytes,
+            flagged_at: Clock::get()?.unix_timestamp,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        duplicate_flag.serialize(&mut data_bytes)?;
+
+        duplicate_flag_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        let mut suspect_paper =
+            ResearchPaper::try_from_slice(&suspect_paper_pda_acc.try_borrow_data()?)?;
+
+        suspect_paper.total_duplicate_flags += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        suspect_paper.serialize(&mut data_bytes)?;
+
+        suspect_paper_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct Institution {
+    pub address: Pubkey,      // Institution's public key 32 bytes
+    pub admin_pubkey: Pubkey, // Admin managing members and licenses 32 bytes
+    pub name: [u8; 64],       // Institution's name 64 bytes
+    pub total_members: u32,   // Current number of members 4 bytes
+    pub total_licenses: u32,  // Total licenses bought 4 bytes
+    pub bump: u8,             // Bump seed 1 byte
+}
+
+impl Institution {
+    pub fn size() -> usize {
+        32 + 32 + 64 + 4 + 4 + 1 // 137
+    }
+
+    pub fn create_new(
+        institution_pda_acc: &AccountInfo,
+        admin_acc: &AccountInfo,
+        data: CreateInstitution,
+    ) -> ProgramResult {
+        let name_bytes = checked_string_convt_to_64_bytes(&data.name)?;
+
+        let institution = Self {
+            address: *institution_pda_acc.key,
+            admin_pubkey: *admin_acc.key,
+            name: name_bytes,
+            total_members: 0,
+            total_licenses: 0,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        institution.serialize(&mut data_bytes)?;
+
+        institution_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn add_member(institution_pda_acc: &AccountInfo) -> ProgramResult {
+        let mut institution = Institution::try_from_slice(&institution_pda_acc.try_borrow_data()?)?;
+
+        institution.total_members += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        institution.serialize(&mut data_bytes)?;
+
+        institution_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn remove_member(institution_pda_acc: &AccountInfo) -> ProgramResult {
+        let mut institution = Institution::try_from_slice(&institution_pda_acc.try_borrow_data()?)?;
+
+        institution.total_members = institution.total_members.saturating_sub(1);
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        institution.serialize(&mut data_bytes)?;
+
+        institution_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn add_license(institution_pda_acc: &AccountInfo) -> ProgramResult {
+        let mut institution = Institution::try_from_slice(&institution_pda_acc.try_borrow_data()?)?;
+
+        institution.total_licenses += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        institution.serialize(&mut data_bytes)?;
+
+        institution_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct InstitutionMember {
+    pub address: Pubkey,            // Member PDA's public key 32 bytes
+    pub institution_pubkey: Pubkey, // Institution the member belongs to 32 bytes
+    pub member_pubkey: Pubkey,      // Member's public key 32 bytes
+    pub bump: u8,                   // Bump seed 1 byte
+}
+
+impl InstitutionMember {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 1 // 97
+    }
+
+    pub fn create_new(
+        institution_member_pda_acc: &AccountInfo,
+        institution_pda_acc: &AccountInfo,
+        member_acc: &AccountInfo,
+        data: AddInstitutionMember,
+    ) -> ProgramResult {
+        let institution_member = Self {
+            address: *institution_member_pda_acc.key,
+            institution_pubkey: *institution_pda_acc.key,
+            member_pubkey: *member_acc.key,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        institution_member.serialize(&mut data_bytes)?;
+
+        institution_member_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, PartialOrd)]
+pub enum LicenseScope {
+    Paper,
+    Journal,
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct InstitutionLicense {
+    pub address: Pubkey,            // License's public key 32 bytes
+    pub institution_pubkey: Pubkey, // Licensed institution 32 bytes
+    pub licensed_pubkey: Pubkey,    // Paper or journal covered by the license 32 bytes
+    pub scope: LicenseScope,        // Whether a paper or a journal is licensed 1 byte
+    pub fee_paid: u64,              // Lamports paid to the licensor 8 bytes
+    pub expires_at: i64,            // Time after which members can no longer claim 8 bytes
+    pub total_claims: u64,          // Total papers claimed by members 8 bytes
+    pub bump: u8,                   // Bump seed 1 byte
+}
+
+impl InstitutionLicense {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 1 + 8 + 8 + 8 + 1 // 122
+    }
+
+    pub fn create_new(
+        institution_license_pda_acc: &AccountInfo,
+        institution_pda_acc: &AccountInfo,
+        licensed_pda_acc: &AccountInfo,
+        data: CreateInstitutionLicense,
+    ) -> ProgramResult {
+        let institution_license = Self {
+            address: *institution_license_pda_acc.key,
+            institution_pubkey: *institution_pda_acc.key,
+            licensed_pubkey: *licensed_pda_acc.key,
+            scope: data.scope,
+            fee_paid: data.fee,
+            expires_at: data.expires_at,
+            total_claims: 0,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        institution_license.serialize(&mut data_bytes)?;
+
+        institution_license_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn claim(institution_license_pda_acc: &AccountInfo, now: i64) -> ProgramResult {
+        let mut institution_license =
+            InstitutionLicense::try_from_slice(&institution_license_pda_acc.try_borrow_data()?)?;
+
+        if now >= institution_license.expires_at {
+            return Err(DeResearcherError::InstitutionLicenseExpired.into());
+        }
+
+        institution_license.total_claims += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        institution_license.serialize(&mut data_bytes)?;
+
+        institution_license_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, Clone, Copy, PartialEq, PartialOrd)]
+pub enum SubscriptionScope {
+    Journal,
+    Author,
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, PartialEq, PartialOrd)]
+pub enum AccessProof {
+    ResearchToken,
+    Subscription,
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct SubscriptionPlan {
+    pub address: Pubkey,          // Plan's public key 32 bytes
+    pub owner_pubkey: Pubkey,     // Journal authority or author paid for subscriptions 32 bytes
+    pub target_pubkey: Pubkey,    // Journal or author the plan covers 32 bytes
+    pub scope: SubscriptionScope, // Whether a journal or an author is covered 1 byte
+    pub price_per_period: u64,    // Lamports charged per period 8 bytes
+    pub period_secs: i64,         // Length of a period 8 bytes
+    pub total_subscriptions: u64, // Total subscriptions and renewals sold 8 bytes
+    pub bump: u8,                 // Bump seed 1 byte
+}
+
+impl SubscriptionPlan {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 1 + 8 + 8 + 8 + 1 // 122
+    }
+
+    pub fn create_new(
+        subscription_plan_pda_acc: &AccountInfo,
+        owner_acc: &AccountInfo,
+        target_acc: &AccountInfo,
+        data: CreateSubscriptionPlan,
+    ) -> ProgramResult {
+        let subscription_plan = Self {
+            address: *subscription_plan_pda_acc.key,
+            owner_pubkey: *owner_acc.key,
+            target_pubkey: *target_acc.key,
+            scope: data.scope,
+            price_per_period: data.price_per_period,
+            period_secs: data.period_secs,
+            total_subscriptions: 0,
+            bump: data.pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        subscription_plan.serialize(&mut data_bytes)?;
+
+        subscription_plan_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn add_subscription(subscription_plan_pda_acc: &AccountInfo) -> ProgramResult {
+        let mut subscription_plan =
+            SubscriptionPlan::try_from_slice(&subscription_plan_pda_acc.try_borrow_data()?)?;
+
+        subscription_plan.total_subscriptions += 1;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        subscription_plan.serialize(&mut data_bytes)?;
+
+        subscription_plan_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct Subscription {
+    pub address: Pubkey,           // Subscription's public key 32 bytes
+    pub subscriber_pubkey: Pubkey, // Subscriber's public key 32 bytes
+    pub plan_pubkey: Pubkey,       // Plan the subscription was bought from 32 bytes
+    pub target_pubkey: Pubkey,     // Journal or author the subscription covers 32 bytes
+    pub scope: SubscriptionScope,  // Whether a journal or an author is covered 1 byte
+    pub started_at: i64,           // Time the subscription was first bought 8 bytes
+    pub expires_at: i64,           // Time the subscription stops granting access 8 bytes
+    pub bump: u8,                  // Bump seed 1 byte
+}
+
+impl Subscription {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 32 + 1 + 8 + 8 + 1 // 146
+    }
+
+    pub fn create_new(
+        subscription_pda_acc: &AccountInfo,
+        subscriber_acc: &AccountInfo,
+        subscription_plan_pda_acc: &AccountInfo,
+        subscription_plan: &SubscriptionPlan,
+        pda_bump: u8,
+        now: i64,
+        duration_secs: i64,
+    ) -> ProgramResult {
+        let subscription = Self {
+            address: *subscription_pda_acc.key,
+            subscriber_pubkey: *subscriber_acc.key,
+            plan_pubkey: *subscription_plan_pda_acc.key,
+            target_pubkey: subscription_plan.target_pubkey,
+            scope: subscription_plan.scope,
+            started_at: now,
+            expires_at: now
+                .checked_add(duration_secs)
+                .ok_or(DeResearcherError::SizeOverflow)?,
+            bump: pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        subscription.serialize(&mut data_bytes)?;
+
+        subscription_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+
+    // A lapsed subscription restarts from now instead of back-filling the gap
+
+    pub fn renew(
+        subscription_pda_acc: &AccountInfo,
+        now: i64,
+        duration_secs: i64,
+    ) -> ProgramResult {
+        let mut subscription =
+            Subscription::try_from_slice(&subscription_pda_acc.try_borrow_data()?)?;
+
+        subscription.expires_at = subscription
+            .expires_at
+            .max(now)
+            .checked_add(duration_secs)
+            .ok_or(DeResearcherError::SizeOverflow)?;
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        subscription.serialize(&mut data_bytes)?;
+
+        subscription_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, BorshDeserialize, BorshSerialize, ShankAccount)]
+pub struct MintEscrow {
+    pub address: Pubkey,                   // Escrow's public key 32 bytes
+    pub paper_pubkey: Pubkey,              // Paper that was minted 32 bytes
+    pub buyer_pubkey: Pubkey,              // Account that paid for the mint 32 bytes
+    pub research_token_pubkey: Pubkey,     // Research token bought with the payment 32 bytes
+    pub researcher_profile_pubkey: Pubkey, // Profile credited with the mint's citation 32 bytes
+    pub amount: u64,                       // Lamports held for the author and reviewers 8 bytes
+    pub refund_deadline: i64,              // Time after which the payment can be released 8 bytes
+    pub bump: u8,                          // Bump seed 1 byte
+}
+
+impl MintEscrow {
+    pub fn size() -> usize {
+        32 + 32 + 32 + 32 + 32 + 8 + 8 + 1 // 177
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_new(
+        mint_escrow_pda_acc: &AccountInfo,
+        paper_pda_acc: &AccountInfo,
+        buyer_acc: &AccountInfo,
+        research_token_pda_acc: &AccountInfo,
+        researcher_profile_pda_acc: &AccountInfo,
+        amount: u64,
+        refund_deadline: i64,
+        pda_bump: u8,
+    ) -> ProgramResult {
+        let mint_escrow = Self {
+            address: *mint_escrow_pda_acc.key,
+            paper_pubkey: *paper_pda_acc.key,
+            buyer_pubkey: *buyer_acc.key,
+            research_token_pubkey: *research_token_pda_acc.key,
+            researcher_profile_pubkey: *researcher_profile_pda_acc.key,
+            amount,
+            refund_deadline,
+            bump: pda_bump,
+        };
+
+        let mut data_bytes: Vec<u8> = Vec::new();
+
+        mint_escrow.serialize(&mut data_bytes)?;
+
+        mint_escrow_pda_acc
+            .try_borrow_mut_data()?
+            .copy_from_slice(&data_bytes);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zeroed<T: BorshDeserialize>(size: usize) -> T {
+        T::try_from_slice(&vec![0; size]).unwrap()
+    }
+
+    fn paper() -> ResearchPaper {
+        let mut paper: ResearchPaper = zeroed(ResearchPaper::size());
+
+        paper.access_fee = 100;
+        paper.min_approvals = MIN_APPROVALS_FOR_PUBLISH;
+        paper.rubric_dimension_count = DEFAULT_RUBRIC_DIMENSIONS;
+        paper.rubric_weights = DEFAULT_RUBRIC_WEIGHTS;
+        paper.publish_at = 1_000;
+
+        paper
+    }
+
+    fn peer_review(scores: &[u8]) -> PeerReview {
+        let mut peer_review: PeerReview = zeroed(PeerReview::size());
+
+        peer_review.score_count = scores.len() as u8;
+        peer_review.scores = PeerReview::scores_from_slice(scores);
+
+        peer_review
+    }
+
+    #[test]
+    fn sizes_match_borsh_layout() {
+        let paper = paper();
+
+        assert_eq!(borsh::to_vec(&paper).unwrap().len(), ResearchPaper::size());
+
+        let peer_review = peer_review(&[]);
+
+        assert_eq!(
+            borsh::to_vec(&peer_review).unwrap().len(),
+            PeerReview::size()
+        );
+
+        let reviewer_pool: ReviewerPool = zeroed(ReviewerPool::size());
+
+        assert_eq!(
+            borsh::to_vec(&reviewer_pool).unwrap().len(),
+            ReviewerPool::size()
+        );
+    }
+
+    #[test]
+    fn median_score_averages_the_middle_values() {
+        let mut score_counts = [0u8; SCORE_HISTOGRAM_BUCKETS];
+
+        assert_eq!(median_score(&score_counts), 0);
+
+        score_counts[10] = 1;
+        score_counts[20] = 1;
+
+        assert_eq!(median_score(&score_counts), 15);
+
+        score_counts[90] = 1;
+
+        assert_eq!(median_score(&score_counts), 20);
+    }
+
+    #[test]
+    fn median_scores_per_dimension() {
+        let mut reviewer_pool: ReviewerPool = zeroed(ReviewerPool::size());
+
+        for (dimension, score) in [40usize, 60, 80].into_iter().enumerate() {
+            reviewer_pool.score_histogram[dimension * SCORE_HISTOGRAM_BUCKETS + score] = 2;
+        }
+
+        assert_eq!(reviewer_pool.median_scores(2), [40, 60, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn base_access_fee_follows_pricing_mode() {
+        let mut paper = paper();
+
+        paper.total_mints = 3;
+
+        assert_eq!(paper.base_access_fee(), 100);
+
+        paper.pricing_mode = PricingMode::LinearBondingCurve;
+        paper.price_slope = 10;
+
+        assert_eq!(paper.base_access_fee(), 130);
+
+        paper.pricing_mode = PricingMode::VolumeTiers;
+        paper.tier_mint_thresholds = [10, 20, 0, 0];
+        paper.tier_fees = [80, 60, 0, 0];
+
+        assert_eq!(paper.base_access_fee(), 100);
+
+        paper.total_mints = 10;
+
+        assert_eq!(paper.base_access_fee(), 80);
+
+        paper.total_mints = 25;
+
+        assert_eq!(paper.base_access_fee(), 60);
+    }
+
+    #[test]
+    fn current_access_fee_decays_to_open_access() {
+        let mut paper = paper();
+
+        paper.open_access_after_secs = 100;
+
+        assert_eq!(paper.current_access_fee(500), 100);
+        assert_eq!(paper.current_access_fee(1_050), 100);
+        assert_eq!(paper.current_access_fee(1_100), 0);
+
+        paper.fee_decay = FeeDecay::Linear;
+
+        assert_eq!(paper.current_access_fee(1_025), 75);
+
+        paper.fee_decay = FeeDecay::Step;
+        paper.fee_decay_step_secs = 10;
+        paper.fee_decay_step_bps = 1_000;
+        paper.open_access_after_secs = 0;
+
+        assert_eq!(paper.current_access_fee(1_025), 80);
+        assert_eq!(paper.current_access_fee(5_000), 0);
+    }
+
+    #[test]
+    fn rental_fee_is_capped_by_access_fee() {
+        let mut paper = paper();
+
+        paper.rental_fee = 40;
+
+        assert_eq!(paper.current_rental_fee(1_000), 40);
+
+        paper.rental_fee = 400;
+
+        assert_eq!(paper.current_rental_fee(1_000), 100);
+    }
+
+    #[test]
+    fn edition_supply_keeps_reserved_editions_for_gifts() {
+        let mut paper = paper();
+
+        assert_eq!(paper.check_edition_supply(false), Ok(()));
+
+        paper.max_mints = 10;
+        paper.reserved_mints = 2;
+        paper.total_mints = 7;
+
+        assert_eq!(paper.check_edition_supply(false), Ok(()));
+
+        paper.total_mints = 8;
+
+        assert_eq!(
+            paper.check_edition_supply(false),
+            Err(DeResearcherError::SoldOut)
+        );
+        assert_eq!(paper.check_edition_supply(true), Ok(()));
+
+        paper.gifted_mints = 2;
+        paper.total_mints = 10;
+
+        assert_eq!(
+            paper.check_edition_supply(true),
+            Err(DeResearcherError::NoReservedEditions)
+        );
+    }
+
+    #[test]
+    fn approval_state_follows_approvals() {
+        let mut paper = paper();
+
+        paper.update_approval_state();
+
+        assert_eq!(paper.state, PaperState::AwaitingPeerReview);
+
+        paper.total_approvals = 1;
+        paper.update_approval_state();
+
+        assert_eq!(paper.state, PaperState::ApprovedToPublish);
+
+        paper.total_approvals = 0;
+        paper.update_approval_state();
+
+        assert_eq!(paper.state, PaperState::InPeerReview);
+
+        paper.state = PaperState::Published;
+        paper.update_approval_state();
+
+        assert_eq!(paper.state, PaperState::Published);
+    }
+
+    #[test]
+    fn review_stats_cannot_underflow() {
+        let mut paper = paper();
+
+        paper.add_review_stats(&[80, 60, 40, 20]).unwrap();
+
+        assert_eq!(
+            paper.remove_review_stats(&[90, 60, 40, 20]),
+            Err(DeResearcherError::SizeOverflow)
+        );
+
+        paper.remove_review_stats(&[80, 60, 40, 20]).unwrap();
+
+        assert_eq!(paper.score_sums, [0; MAX_RUBRIC_DIMENSIONS]);
+        assert_eq!(paper.weighted_score_sum, 0);
+        assert_eq!(paper.scored_reviews, 0);
+    }
+
+    #[test]
+    fn approved_and_revision_states_are_not_final() {
+        assert!(!PaperState::ApprovedToPublish.is_final());
+        assert!(!PaperState::RequiresRevision.is_final());
+        assert!(PaperState::Published.is_final());
+        assert!(PaperState::Retracted.is_final());
+    }
+
+    #[test]
+    fn consensus_weight_is_distance_from_median() {
+        let mut peer_review = peer_review(&[50, 50, 50, 50]);
+
+        assert!(peer_review.record_consensus(&[55, 45, 50, 50]));
+        assert_eq!(peer_review.consensus_weight, 98);
+
+        assert!(!peer_review.record_consensus(&[90, 90, 90, 90]));
+        assert_eq!(peer_review.consensus_weight, 60);
+    }
+
+    #[test]
+    fn rewards_split_by_consensus_weight() {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 0;
+
+        let mut peer_review = peer_review(&[80, 80, 80, 80]);
+
+        peer_review.is_approval = true;
+        peer_review.consensus_weight = 100;
+
+        let mut data = borsh::to_vec(&peer_review).unwrap();
+
+        let peer_review_pda_acc = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        let mut paper = paper();
+
+        let mut reviewer_pool: ReviewerPool = zeroed(ReviewerPool::size());
+
+        reviewer_pool.total_deposited = 1_000;
+        reviewer_pool.total_consensus_weight = 150;
+
+        paper.state = PaperState::ApprovedToPublish;
+        reviewer_pool.is_finalized = true;
+
+        assert_eq!(
+            PeerReview::claim_reward(&peer_review_pda_acc, &paper, &reviewer_pool),
+            Err(DeResearcherError::ReviewsNotFinalized.into())
+        );
+
+        paper.state = PaperState::Published;
+
+        assert_eq!(
+            PeerReview::claim_reward(&peer_review_pda_acc, &paper, &reviewer_pool),
+            Ok(666)
+        );
+        assert_eq!(
+            PeerReview::claim_reward(&peer_review_pda_acc, &paper, &reviewer_pool),
+            Err(DeResearcherError::NoRewardToClaim.into())
+        );
+
+        reviewer_pool.total_deposited = 1_300;
+
+        assert_eq!(
+            PeerReview::claim_reward(&peer_review_pda_acc, &paper, &reviewer_pool),
+            Ok(200)
+        );
+    }
+
+    #[test]
+    fn rewards_split_equally_without_consensus_weight() {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 0;
+
+        let mut peer_review = peer_review(&[80, 80, 80, 80]);
+
+        peer_review.is_approval = true;
+
+        let mut data = borsh::to_vec(&peer_review).unwrap();
+
+        let peer_review_pda_acc = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        let mut paper = paper();
+
+        let mut reviewer_pool: ReviewerPool = zeroed(ReviewerPool::size());
+
+        paper.state = PaperState::Published;
+        paper.total_approvals = 2;
+        reviewer_pool.is_finalized = true;
+        reviewer_pool.total_deposited = 1_000;
+        reviewer_pool.total_claimed = 900;
+
+        // Capped at what is left in the pool
+
+        assert_eq!(
+            PeerReview::claim_reward(&peer_review_pda_acc, &paper, &reviewer_pool),
+            Ok(100)
+        );
+
+        reviewer_pool.total_claimed = 100;
+
+        assert_eq!(
+            PeerReview::claim_reward(&peer_review_pda_acc, &paper, &reviewer_pool),
+            Ok(400)
+        );
+    }
+
+    #[test]
+    fn invalidated_approval_loses_its_consensus_weight() {
+        let owner = Pubkey::new_unique();
+
+        let mut peer_review = peer_review(&[80, 80, 80, 80]);
+
+        peer_review.is_approval = true;
+        peer_review.is_weighted = true;
+        peer_review.consensus_weight = 90;
+
+        let mut paper = paper();
+
+        paper
+            .add_review_stats(peer_review.dimension_scores())
+            .unwrap();
+        paper.total_approvals = 2;
+        paper.state = PaperState::ApprovedToPublish;
+
+        let mut reviewer_pool: ReviewerPool = zeroed(ReviewerPool::size());
+
+        reviewer_pool.total_consensus_weight = 150;
+
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let mut lamports = [0; 3];
+        let mut peer_review_data = borsh::to_vec(&peer_review).unwrap();
+        let mut paper_data = borsh::to_vec(&paper).unwrap();
+        let mut reviewer_pool_data = borsh::to_vec(&reviewer_pool).unwrap();
+        let [peer_review_lamports, paper_lamports, reviewer_pool_lamports] = &mut lamports;
+
+        let peer_review_pda_acc = AccountInfo::new(
+            &keys[0],
+            false,
+            true,
+            peer_review_lamports,
+            &mut peer_review_data,
+            &owner,
+            false,
+            0,
+        );
+
+        let paper_pda_acc = AccountInfo::new(
+            &keys[1],
+            false,
+            true,
+            paper_lamports,
+            &mut paper_data,
+            &owner,
+            false,
+            0,
+        );
+
+        let reviewer_pool_pda_acc = AccountInfo::new(
+            &keys[2],
+            false,
+            true,
+            reviewer_pool_lamports,
+            &mut reviewer_pool_data,
+            &owner,
+            false,
+            0,
+        );
+
+        PeerReview::invalidate(&peer_review_pda_acc, &paper_pda_acc, &reviewer_pool_pda_acc)
+            .unwrap();
+
+        let reviewer_pool =
+            ReviewerPool::try_from_slice(&reviewer_pool_pda_acc.data.borrow()).unwrap();
+        let paper = ResearchPaper::try_from_slice(&paper_pda_acc.data.borrow()).unwrap();
+
+        assert_eq!(reviewer_pool.total_consensus_weight, 60);
+        assert_eq!(paper.total_approvals, 1);
+        assert_eq!(paper.scored_reviews, 0);
+    }
+
+    #[test]
+    fn legacy_tokens_decode_as_permanent() {
+        let mut data = vec![7; ResearchTokenAccount::legacy_size()];
+
+        data[96] = 254;
+
+        let research_token = ResearchTokenAccount::from_account_data(&data).unwrap();
+
+        assert_eq!(research_token.kind, AccessKind::Permanent);
+        assert_eq!(research_token.expires_at, 0);
+        assert_eq!(research_token.bump, 254);
+        assert!(!research_token.is_expired(i64::MAX));
+
+        let mut research_token: ResearchTokenAccount = zeroed(ResearchTokenAccount::size());
+
+        research_token.kind = AccessKind::Rental;
+        research_token.expires_at = 50;
+
+        let data = borsh::to_vec(&research_token).unwrap();
+
+        let research_token = ResearchTokenAccount::from_account_data(&data).unwrap();
+
+        assert_eq!(research_token.kind, AccessKind::Rental);
+        assert!(!research_token.is_expired(49));
+        assert!(research_token.is_expired(50));
+    }
+
+    #[test]
+    fn legacy_papers_decode_without_a_review_deadline() {
+        let mut data = vec![0; ResearchPaper::legacy_size()];
+
+        data[64] = 2; // ApprovedToPublish
+        data[134] = 1; // total_approvals
+        data[215] = 253; // bump
+
+        let paper = ResearchPaper::from_account_data(&data).unwrap();
+
+        assert_eq!(paper.state, PaperState::ApprovedToPublish);
+        assert_eq!(paper.total_approvals, 1);
+        assert_eq!(paper.total_reviews, 0);
+        assert_eq!(paper.min_approvals, MIN_APPROVALS_FOR_PUBLISH);
+        assert_eq!(paper.review_deadline(), i64::MAX);
+        assert_eq!(paper.bump, 253);
+        assert_eq!(borsh::to_vec(&paper).unwrap().len(), ResearchPaper::size());
+    }
+
+    #[test]
+    fn legacy_reviews_decode_as_open_reviews() {
+        let mut data = vec![0; PeerReview::legacy_size()];
+
+        data[96..100].copy_from_slice(&[60, 60, 40, 40]);
+        data[164] = 252;
+
+        let peer_review = PeerReview::from_account_data(&data).unwrap();
+
+        assert_eq!(peer_review.dimension_scores(), &[60, 60, 40, 40]);
+        assert!(!peer_review.is_blind());
+        assert!(peer_review.is_revealed);
+        assert!(!peer_review.is_approval);
+        assert_eq!(peer_review.bump, 252);
+
+        data[99] = 48;
+
+        assert!(PeerReview::from_account_data(&data).unwrap().is_approval);
+    }
 }
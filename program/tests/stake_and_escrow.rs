@@ -0,0 +1,2216 @@
+// Drives the processor through the runtime's input serialization, with the system program
+// and sysvars stubbed, to cover the flows that move lamports between accounts
+
+use std::{cell::Cell, collections::HashMap, mem::size_of};
+
+use borsh::BorshDeserialize;
+use deresearcher_program::{
+    error::DeResearcherError,
+    instruction::{
+        AddInstitutionMember, AddPeerReview, AmendPeerReview, AppointEditor, ClaimLicensedPaper,
+        CommitReview, CreateAuthorResponse, CreateInstitution, CreateInstitutionLicense,
+        CreateJournal, CreateResearchePaper, CreateResearcherProfile, CreateRubric,
+        CreateSubscriptionPlan, DeResearcherInstruction, DeclareConflictOfInterest, DeskReject,
+        InviteReviewer, MigrateResearchPaper, MintResearchPaper, OpenDispute, PublishPaper,
+        ResolveDispute, RevealReview, SetArbiters, SlashReviewStake, SubmitRevision, Subscribe,
+        VerifyAccess, WithdrawPaper, DISPUTE_BOND_LAMPORTS, DISPUTE_RESOLUTION_WINDOW_SECS,
+        GOVERNANCE_AUTHORITY_PUBKEY, REVIEW_STAKE_LAMPORTS, REVISION_STAKE_LOCK_SECS,
+        TREASURY_PUBKEY,
+    },
+    processor::Processor,
+    state::{
+        AccessKind, AccessProof, ConflictKind, ConflictOfInterest, FeeDecay, LicenseScope,
+        MintEscrow, PaperState, PaperVersion, PeerReview, PricingMode, ResearchPaper,
+        ResearchTokenAccount, ReviewStake, ReviewerPool, Subscription, SubscriptionPlan,
+        SubscriptionScope,
+    },
+};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::{deserialize, ProgramResult, MAX_PERMITTED_DATA_INCREASE, SUCCESS},
+    hash::hashv,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program,
+};
+
+const CONTENT_HASH: &str = "0a69c09f7c1eca87a0a6fb108e3aeb1929a2e4bb732a021612730325fd5875b2";
+
+const REVISED_CONTENT_HASH: &str =
+    "5d1b0f2c3c9e8a7b6d4f1e0a9b8c7d6e5f4a3b2c1d0e9f8a7b6c5d4e3f2a1b0c";
+
+const ACCESS_FEE: u32 = 1_000_000;
+
+const REVIEWER_SHARE_BPS: u16 = 2_000;
+
+const REFUND_WINDOW_SECS: i64 = 3_600;
+
+const STARTING_LAMPORTS: u64 = 10_000_000_000;
+
+thread_local! {
+    static NOW: Cell<i64> = const { Cell::new(1_000_000) };
+}
+
+fn set_now(now: i64) {
+    NOW.with(|cell| cell.set(now));
+}
+
+fn rent(size: usize) -> u64 {
+    Rent::default().minimum_balance(size)
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW.with(Cell::get),
+            ..Clock::default()
+        };
+
+        unsafe { *(var_addr as *mut Clock) = clock };
+
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+
+        SUCCESS
+    }
+
+    // Only the system program is invoked, create_account and transfer are all it uses
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        assert_eq!(instruction.program_id, system_program::ID);
+
+        let find = |key: &Pubkey| {
+            account_infos
+                .iter()
+                .find(|account_info| account_info.key.eq(key))
+                .unwrap()
+        };
+
+        let from = find(&instruction.accounts[0].pubkey);
+
+        let to = find(&instruction.accounts[1].pubkey);
+
+        let data = &instruction.data;
+
+        let lamports = u64::from_le_bytes(data[4..12].try_into().unwrap());
+
+        match u32::from_le_bytes(data[..4].try_into().unwrap()) {
+            0 => {
+                if to.lamports() > 0 || !to.data_is_empty() {
+                    return Err(ProgramError::AccountAlreadyInitialized);
+                }
+
+                let space = u64::from_le_bytes(data[12..20].try_into().unwrap());
+
+                let owner = Pubkey::try_from(&data[20..52]).unwrap();
+
+                move_lamports(from, to, lamports)?;
+
+                to.realloc(space as usize, true)?;
+
+                to.assign(&owner);
+            }
+            2 => move_lamports(from, to, lamports)?,
+            kind => panic!("unexpected system instruction {kind}"),
+        }
+
+        Ok(())
+    }
+}
+
+fn move_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    if from.lamports() < lamports {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    **from.try_borrow_mut_lamports()? -= lamports;
+
+    **to.try_borrow_mut_lamports()? += lamports;
+
+    Ok(())
+}
+
+#[derive(Clone, Default)]
+struct Account {
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+}
+
+#[derive(Default)]
+struct Bank {
+    accounts: HashMap<Pubkey, Account>,
+}
+
+impl Bank {
+    fn new() -> Self {
+        set_syscall_stubs(Box::new(Stubs));
+
+        Self::default()
+    }
+
+    fn fund(&mut self, key: &Pubkey) {
+        self.accounts.insert(
+            *key,
+            Account {
+                lamports: STARTING_LAMPORTS,
+                ..Account::default()
+            },
+        );
+    }
+
+    fn account(&self, key: &Pubkey) -> Account {
+        self.accounts.get(key).cloned().unwrap_or_default()
+    }
+
+    fn lamports(&self, key: &Pubkey) -> u64 {
+        self.account(key).lamports
+    }
+
+    fn state<T: BorshDeserialize>(&self, key: &Pubkey) -> T {
+        T::try_from_slice(&self.account(key).data).unwrap()
+    }
+
+    // Serialize the accounts the way the runtime lays out a program's input, so that
+    // realloc and assign find the memory they expect around each account
+
+    fn process(&mut self, metas: Vec<AccountMeta>, ix: DeResearcherInstruction) -> ProgramResult {
+        let program_id = deresearcher_program::id();
+
+        let ix_data = borsh::to_vec(&ix).unwrap();
+
+        let mut input: Vec<u8> = Vec::new();
+
+        input.extend_from_slice(&(metas.len() as u64).to_le_bytes());
+
+        for (index, meta) in metas.iter().enumerate() {
+            if let Some(first) = metas[..index]
+                .iter()
+                .position(|m| m.pubkey.eq(&meta.pubkey))
+            {
+                input.push(first as u8);
+                input.extend_from_slice(&[0; 7]);
+                continue;
+            }
+
+            let account = self.account(&meta.pubkey);
+
+            input.push(u8::MAX);
+            input.push(meta.is_signer as u8);
+            input.push(meta.is_writable as u8);
+            input.push(0);
+            input.extend_from_slice(&[0; 4]);
+            input.extend_from_slice(meta.pubkey.as_ref());
+            input.extend_from_slice(account.owner.as_ref());
+            input.extend_from_slice(&account.lamports.to_le_bytes());
+            input.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            input.extend_from_slice(&account.data);
+            input.resize(input.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            input.resize(input.len().next_multiple_of(8), 0);
+            input.extend_from_slice(&0u64.to_le_bytes());
+        }
+
+        input.extend_from_slice(&(ix_data.len() as u64).to_le_bytes());
+        input.extend_from_slice(&ix_data);
+        input.extend_from_slice(program_id.as_ref());
+
+        // Backed by u64s so the buffer has the alignment deserialize assumes
+
+        let mut aligned = vec![0u64; input.len().div_ceil(size_of::<u64>())];
+
+        let buffer = aligned.as_mut_ptr() as *mut u8;
+
+        unsafe { std::ptr::copy_nonoverlapping(input.as_ptr(), buffer, input.len()) };
+
+        let (program_id, account_infos, instruction_data) = unsafe { deserialize(buffer) };
+
+        Processor::process_ix(program_id, &account_infos, instruction_data)?;
+
+        for account_info in account_infos.iter() {
+            self.accounts.insert(
+                *account_info.key,
+                Account {
+                    lamports: account_info.lamports(),
+                    data: account_info.data.borrow().to_vec(),
+                    owner: *account_info.owner,
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &deresearcher_program::id())
+}
+
+fn researcher_profile_pda(researcher: &Pubkey) -> (Pubkey, u8) {
+    pda(&[b"deres_researcher_profile", researcher.as_ref()])
+}
+
+struct Paper {
+    author: Pubkey,
+    reviewer: Pubkey,
+    paper: Pubkey,
+    paper_bump: u8,
+    reviewer_pool: Pubkey,
+    peer_review: Pubkey,
+    peer_review_bump: u8,
+    review_stake: Pubkey,
+    review_stake_bump: u8,
+}
+
+fn create_profile(bank: &mut Bank, researcher: &Pubkey) {
+    let (researcher_profile, bump) = researcher_profile_pda(researcher);
+
+    bank.process(
+        vec![
+            AccountMeta::new(*researcher, true),
+            AccountMeta::new(researcher_profile, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::CreateResearcherProfile(CreateResearcherProfile {
+            name: "researcher".to_string(),
+            meta_data_merkle_root: CONTENT_HASH.to_string(),
+            pda_bump: bump,
+        }),
+    )
+    .unwrap();
+}
+
+// The same paper seen from a fresh reviewer, with the accounts its review would use
+
+fn with_reviewer(bank: &mut Bank, paper: &Paper) -> Paper {
+    let reviewer = Pubkey::new_unique();
+
+    bank.fund(&reviewer);
+
+    create_profile(bank, &reviewer);
+
+    let (peer_review, peer_review_bump) = pda(&[
+        b"deres_peer_review",
+        paper.paper.as_ref(),
+        reviewer.as_ref(),
+        &[0],
+    ]);
+
+    let (review_stake, review_stake_bump) = pda(&[b"deres_review_stake", peer_review.as_ref()]);
+
+    Paper {
+        reviewer,
+        peer_review,
+        peer_review_bump,
+        review_stake,
+        review_stake_bump,
+        ..*paper
+    }
+}
+
+// The same reviewer reviewing a later version of the paper
+
+fn for_version(paper: &Paper, version: u8) -> Paper {
+    let (peer_review, peer_review_bump) = pda(&[
+        b"deres_peer_review",
+        paper.paper.as_ref(),
+        paper.reviewer.as_ref(),
+        &[version],
+    ]);
+
+    let (review_stake, review_stake_bump) = pda(&[b"deres_review_stake", peer_review.as_ref()]);
+
+    Paper {
+        peer_review,
+        peer_review_bump,
+        review_stake,
+        review_stake_bump,
+        ..*paper
+    }
+}
+
+// A paper created by a fresh author, with the accounts of the reviewer who will review it
+
+fn new_paper(bank: &mut Bank, refund_window_secs: i64, blind_review: bool) -> Paper {
+    let author = Pubkey::new_unique();
+
+    bank.fund(&author);
+
+    create_profile(bank, &author);
+
+    let (paper, paper_bump) = pda(&[
+        b"deres_research_paper",
+        &CONTENT_HASH.as_bytes()[..32],
+        author.as_ref(),
+    ]);
+
+    let (reviewer_pool, reviewer_pool_bump) = pda(&[b"deres_reviewer_pool", paper.as_ref()]);
+
+    let (content_hash_record, content_hash_record_bump) = pda(&[
+        b"deres_content_hash",
+        &CONTENT_HASH.as_bytes()[..32],
+        &CONTENT_HASH.as_bytes()[32..],
+    ]);
+
+    let (paper_version, paper_version_bump) = pda(&[b"deres_paper_version", paper.as_ref(), &[0]]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(author, true),
+            AccountMeta::new(researcher_profile_pda(&author).0, false),
+            AccountMeta::new(paper, false),
+            AccountMeta::new(reviewer_pool, false),
+            AccountMeta::new(content_hash_record, false),
+            AccountMeta::new(paper_version, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::CreateResearchePaper(CreateResearchePaper {
+            access_fee: ACCESS_FEE,
+            paper_content_hash: CONTENT_HASH.to_string(),
+            meta_data_merkle_root: CONTENT_HASH.to_string(),
+            pda_bump: paper_bump,
+            reviewer_share_bps: REVIEWER_SHARE_BPS,
+            reviewer_pool_pda_bump: reviewer_pool_bump,
+            blind_review,
+            review_window_secs: 0,
+            excluded_reviewers: vec![],
+            content_hash_record_pda_bump: content_hash_record_bump,
+            fee_decay: FeeDecay::None,
+            fee_decay_step_secs: 0,
+            fee_decay_step_bps: 0,
+            open_access_after_secs: 0,
+            pricing_mode: PricingMode::Flat,
+            price_slope: 0,
+            tier_mint_thresholds: vec![],
+            tier_fees: vec![],
+            max_mints: 0,
+            reserved_mints: 0,
+            rental_fee: 0,
+            rental_period_secs: 0,
+            refund_window_secs,
+            paper_version_pda_bump: paper_version_bump,
+            awaiting_venue: false,
+        }),
+    )
+    .unwrap();
+
+    let paper = Paper {
+        author,
+        reviewer: Pubkey::default(),
+        paper,
+        paper_bump,
+        reviewer_pool,
+        peer_review: Pubkey::default(),
+        peer_review_bump: 0,
+        review_stake: Pubkey::default(),
+        review_stake_bump: 0,
+    };
+
+    with_reviewer(bank, &paper)
+}
+
+// The accounts shared by AddPeerReview and CommitReview
+
+fn review_metas(paper: &Paper) -> Vec<AccountMeta> {
+    let review_invitation = pda(&[
+        b"deres_review_invitation",
+        paper.paper.as_ref(),
+        paper.reviewer.as_ref(),
+    ])
+    .0;
+
+    vec![
+        AccountMeta::new(paper.reviewer, true),
+        AccountMeta::new(researcher_profile_pda(&paper.reviewer).0, false),
+        AccountMeta::new(paper.paper, false),
+        AccountMeta::new(paper.peer_review, false),
+        AccountMeta::new(paper.review_stake, false),
+        AccountMeta::new_readonly(review_invitation, false),
+        AccountMeta::new_readonly(
+            conflict_of_interest_pda(&paper.reviewer, &paper.author),
+            false,
+        ),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ]
+}
+
+fn add_review(bank: &mut Bank, paper: &Paper, scores: &[u8]) {
+    try_add_review(bank, paper, scores).unwrap();
+}
+
+fn try_add_review(bank: &mut Bank, paper: &Paper, scores: &[u8]) -> ProgramResult {
+    bank.process(
+        review_metas(paper),
+        DeResearcherInstruction::AddPeerReview(AddPeerReview {
+            scores: scores.to_vec(),
+            meta_data_merkle_root: CONTENT_HASH.to_string(),
+            pda_bump: paper.peer_review_bump,
+            stake_pda_bump: paper.review_stake_bump,
+        }),
+    )
+}
+
+// A paper approved by a single staked review
+
+fn reviewed_paper(bank: &mut Bank, refund_window_secs: i64) -> Paper {
+    let paper = new_paper(bank, refund_window_secs, false);
+
+    add_review(bank, &paper, &[90, 90, 90, 90]);
+
+    paper
+}
+
+fn commitment(paper: &Paper, scores: &[u8], salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[
+        paper.reviewer.as_ref(),
+        paper.paper.as_ref(),
+        &[0],
+        scores,
+        salt,
+    ])
+    .to_bytes()
+}
+
+fn commit_review(bank: &mut Bank, paper: &Paper, scores: &[u8], salt: &[u8; 32]) {
+    bank.process(
+        review_metas(paper),
+        DeResearcherInstruction::CommitReview(CommitReview {
+            commitment: commitment(paper, scores, salt),
+            pda_bump: paper.peer_review_bump,
+            stake_pda_bump: paper.review_stake_bump,
+        }),
+    )
+    .unwrap();
+}
+
+fn reveal_review(bank: &mut Bank, paper: &Paper, scores: &[u8], salt: &[u8; 32]) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new(paper.reviewer, true),
+            AccountMeta::new(researcher_profile_pda(&paper.reviewer).0, false),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(paper.peer_review, false),
+        ],
+        DeResearcherInstruction::RevealReview(RevealReview {
+            scores: scores.to_vec(),
+            meta_data_merkle_root: CONTENT_HASH.to_string(),
+            salt: *salt,
+        }),
+    )
+}
+
+fn expire_review_commit(bank: &mut Bank, paper: &Paper) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new(paper.reviewer, false),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(paper.peer_review, false),
+            AccountMeta::new(paper.review_stake, false),
+            AccountMeta::new(TREASURY_PUBKEY, false),
+        ],
+        DeResearcherInstruction::ExpireReviewCommit,
+    )
+}
+
+fn publish(bank: &mut Bank, paper: &Paper) {
+    try_publish(bank, paper).unwrap();
+}
+
+fn try_publish(bank: &mut Bank, paper: &Paper) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new(paper.author, true),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(paper_version_pda(paper, 0).0, false),
+        ],
+        DeResearcherInstruction::PublishPaper(PublishPaper {
+            pda_bump: paper.paper_bump,
+            publish_at: None,
+        }),
+    )
+}
+
+fn withdraw(bank: &mut Bank, paper: &Paper) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new(paper.author, true),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(paper_version_pda(paper, 0).0, false),
+        ],
+        DeResearcherInstruction::WithdrawPaper(WithdrawPaper {
+            reason_hash: CONTENT_HASH.to_string(),
+        }),
+    )
+}
+
+// Names are zero padded to 64 bytes and seeds use the first 32
+
+fn name_seed(name: &str) -> [u8; 32] {
+    let mut seed = [0; 32];
+
+    seed[..name.len()].copy_from_slice(name.as_bytes());
+
+    seed
+}
+
+fn conflict_of_interest_pda(a: &Pubkey, b: &Pubkey) -> Pubkey {
+    let (first, second) = if a < b { (a, b) } else { (b, a) };
+
+    pda(&[
+        b"deres_conflict_of_interest",
+        first.as_ref(),
+        second.as_ref(),
+    ])
+    .0
+}
+
+fn declare_conflict(
+    bank: &mut Bank,
+    declarer: &Pubkey,
+    counterparty: &Pubkey,
+    mutual: bool,
+) -> ProgramResult {
+    let conflict_of_interest = conflict_of_interest_pda(declarer, counterparty);
+
+    let (first, second) = if declarer < counterparty {
+        (declarer, counterparty)
+    } else {
+        (counterparty, declarer)
+    };
+
+    let (_, bump) = pda(&[
+        b"deres_conflict_of_interest",
+        first.as_ref(),
+        second.as_ref(),
+    ]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(*declarer, true),
+            AccountMeta::new_readonly(*counterparty, mutual),
+            AccountMeta::new(conflict_of_interest, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::DeclareConflictOfInterest(DeclareConflictOfInterest {
+            kind: ConflictKind::CoAuthor,
+            pda_bump: bump,
+        }),
+    )
+}
+
+struct Journal {
+    authority: Pubkey,
+    journal: Pubkey,
+    rubric: Pubkey,
+    editor: Pubkey,
+    editor_pda: Pubkey,
+}
+
+// A journal scoring against a rubric that weighs novelty double, with a single appointed editor
+
+fn new_journal(bank: &mut Bank) -> Journal {
+    let authority = Pubkey::new_unique();
+    let editor = Pubkey::new_unique();
+
+    bank.fund(&authority);
+    bank.fund(&editor);
+
+    let (rubric, rubric_bump) = pda(&[b"deres_rubric", authority.as_ref(), &name_seed("rubric")]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(rubric, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::CreateRubric(CreateRubric {
+            name: "rubric".to_string(),
+            dimension_names: ["novelty", "rigor", "clarity"].map(String::from).to_vec(),
+            weights: vec![2, 1, 1],
+            approval_threshold: 60,
+            pda_bump: rubric_bump,
+        }),
+    )
+    .unwrap();
+
+    let (journal, journal_bump) =
+        pda(&[b"deres_journal", authority.as_ref(), &name_seed("journal")]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(journal, false),
+            AccountMeta::new_readonly(rubric, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::CreateJournal(CreateJournal {
+            name: "journal".to_string(),
+            submission_fee: 0,
+            min_approvals: 1,
+            pda_bump: journal_bump,
+        }),
+    )
+    .unwrap();
+
+    let (editor_pda, editor_bump) = pda(&[b"deres_editor", journal.as_ref(), editor.as_ref()]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(journal, false),
+            AccountMeta::new_readonly(editor, false),
+            AccountMeta::new(editor_pda, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::AppointEditor(AppointEditor {
+            pda_bump: editor_bump,
+        }),
+    )
+    .unwrap();
+
+    Journal {
+        authority,
+        journal,
+        rubric,
+        editor,
+        editor_pda,
+    }
+}
+
+fn submit_to_journal(bank: &mut Bank, journal: &Journal, paper: &Paper) {
+    bank.process(
+        vec![
+            AccountMeta::new(paper.author, true),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(journal.journal, false),
+            AccountMeta::new_readonly(journal.rubric, false),
+            AccountMeta::new(journal.authority, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::SubmitToJournal,
+    )
+    .unwrap();
+}
+
+fn invite_reviewer(bank: &mut Bank, journal: &Journal, paper: &Paper) {
+    let (review_invitation, review_invitation_bump) = pda(&[
+        b"deres_review_invitation",
+        paper.paper.as_ref(),
+        paper.reviewer.as_ref(),
+    ]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(journal.editor, true),
+            AccountMeta::new_readonly(journal.editor_pda, false),
+            AccountMeta::new_readonly(paper.paper, false),
+            AccountMeta::new_readonly(paper.reviewer, false),
+            AccountMeta::new(review_invitation, false),
+            AccountMeta::new_readonly(
+                conflict_of_interest_pda(&journal.editor, &paper.author),
+                false,
+            ),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::InviteReviewer(InviteReviewer {
+            pda_bump: review_invitation_bump,
+        }),
+    )
+    .unwrap();
+}
+
+fn desk_reject(bank: &mut Bank, journal: &Journal, paper: &Paper) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new(journal.editor, true),
+            AccountMeta::new_readonly(journal.editor_pda, false),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new_readonly(
+                conflict_of_interest_pda(&journal.editor, &paper.author),
+                false,
+            ),
+        ],
+        DeResearcherInstruction::DeskReject(DeskReject { reason_code: 1 }),
+    )
+}
+
+fn release_review_stake(bank: &mut Bank, paper: &Paper) -> ProgramResult {
+    let dispute = pda(&[b"deres_dispute", paper.peer_review.as_ref()]).0;
+
+    bank.process(
+        vec![
+            AccountMeta::new(paper.reviewer, true),
+            AccountMeta::new_readonly(paper.paper, false),
+            AccountMeta::new_readonly(paper.peer_review, false),
+            AccountMeta::new(paper.review_stake, false),
+            AccountMeta::new_readonly(dispute, false),
+            AccountMeta::new_readonly(paper.reviewer_pool, false),
+        ],
+        DeResearcherInstruction::ReleaseReviewStake,
+    )
+}
+
+fn paper_version_pda(paper: &Paper, version: u8) -> (Pubkey, u8) {
+    pda(&[b"deres_paper_version", paper.paper.as_ref(), &[version]])
+}
+
+// Every review is tallied on a first pass and weighted on a second, one crank call each
+
+fn finalize_reviews(bank: &mut Bank, reviews: &[&Paper]) {
+    for _ in 0..2 {
+        for review in reviews {
+            bank.process(
+                vec![
+                    AccountMeta::new_readonly(review.paper, false),
+                    AccountMeta::new(review.reviewer_pool, false),
+                    AccountMeta::new(review.peer_review, false),
+                    AccountMeta::new(researcher_profile_pda(&review.reviewer).0, false),
+                    AccountMeta::new(paper_version_pda(review, 0).0, false),
+                ],
+                DeResearcherInstruction::FinalizeReviews,
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn crank_paper_deadline(bank: &mut Bank, paper: &Paper) {
+    bank.process(
+        vec![AccountMeta::new(paper.paper, false)],
+        DeResearcherInstruction::CrankPaperDeadline,
+    )
+    .unwrap();
+}
+
+fn submit_revision(bank: &mut Bank, paper: &Paper) -> ProgramResult {
+    let (content_hash_record, content_hash_record_bump) = pda(&[
+        b"deres_content_hash",
+        &REVISED_CONTENT_HASH.as_bytes()[..32],
+        &REVISED_CONTENT_HASH.as_bytes()[32..],
+    ]);
+
+    let (new_paper_version, new_paper_version_bump) = paper_version_pda(paper, 1);
+
+    bank.process(
+        vec![
+            AccountMeta::new(paper.author, true),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(paper_version_pda(paper, 0).0, false),
+            AccountMeta::new(new_paper_version, false),
+            AccountMeta::new(paper.reviewer_pool, false),
+            AccountMeta::new(content_hash_record, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::SubmitRevision(SubmitRevision {
+            paper_content_hash: REVISED_CONTENT_HASH.to_string(),
+            meta_data_merkle_root: CONTENT_HASH.to_string(),
+            pda_bump: new_paper_version_bump,
+            content_hash_record_pda_bump: content_hash_record_bump,
+        }),
+    )
+}
+
+fn author_response_pda(paper: &Paper) -> (Pubkey, u8) {
+    pda(&[b"deres_author_response", paper.peer_review.as_ref()])
+}
+
+fn respond_to_review(bank: &mut Bank, author: &Pubkey, paper: &Paper) -> ProgramResult {
+    let (author_response, author_response_bump) = author_response_pda(paper);
+
+    bank.process(
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new_readonly(paper.paper, false),
+            AccountMeta::new_readonly(paper.peer_review, false),
+            AccountMeta::new(author_response, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::CreateAuthorResponse(CreateAuthorResponse {
+            meta_data_merkle_root: CONTENT_HASH.to_string(),
+            pda_bump: author_response_bump,
+        }),
+    )
+}
+
+fn amend_review(bank: &mut Bank, paper: &Paper, scores: &[u8]) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new_readonly(paper.reviewer, true),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(paper.peer_review, false),
+            AccountMeta::new_readonly(author_response_pda(paper).0, false),
+        ],
+        DeResearcherInstruction::AmendPeerReview(AmendPeerReview {
+            scores: scores.to_vec(),
+            meta_data_merkle_root: CONTENT_HASH.to_string(),
+        }),
+    )
+}
+
+fn slash_review_stake(
+    bank: &mut Bank,
+    slasher: &Pubkey,
+    paper: &Paper,
+    slash_bps: u16,
+) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new_readonly(*slasher, true),
+            AccountMeta::new(paper.review_stake, false),
+            AccountMeta::new(TREASURY_PUBKEY, false),
+            AccountMeta::new_readonly(arbiter_set_pda().0, false),
+        ],
+        DeResearcherInstruction::SlashReviewStake(SlashReviewStake { slash_bps }),
+    )
+}
+
+fn arbiter_set_pda() -> (Pubkey, u8) {
+    pda(&[b"deres_arbiter_set"])
+}
+
+// A single arbiter resolving disputes on its own
+
+fn set_arbiter(bank: &mut Bank) -> Pubkey {
+    let arbiter = Pubkey::new_unique();
+
+    let (arbiter_set, arbiter_set_bump) = arbiter_set_pda();
+
+    bank.fund(&GOVERNANCE_AUTHORITY_PUBKEY);
+
+    bank.process(
+        vec![
+            AccountMeta::new(GOVERNANCE_AUTHORITY_PUBKEY, true),
+            AccountMeta::new(arbiter_set, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::SetArbiters(SetArbiters {
+            arbiters: vec![arbiter],
+            quorum: 1,
+            pda_bump: arbiter_set_bump,
+        }),
+    )
+    .unwrap();
+
+    arbiter
+}
+
+fn dispute_pda(paper: &Paper) -> (Pubkey, u8) {
+    pda(&[b"deres_dispute", paper.peer_review.as_ref()])
+}
+
+fn open_dispute(bank: &mut Bank, paper: &Paper) -> ProgramResult {
+    let (dispute, dispute_bump) = dispute_pda(paper);
+
+    bank.process(
+        vec![
+            AccountMeta::new(paper.author, true),
+            AccountMeta::new_readonly(paper.paper, false),
+            AccountMeta::new_readonly(paper.peer_review, false),
+            AccountMeta::new(dispute, false),
+            AccountMeta::new_readonly(arbiter_set_pda().0, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::OpenDispute(OpenDispute {
+            meta_data_merkle_root: CONTENT_HASH.to_string(),
+            pda_bump: dispute_bump,
+        }),
+    )
+}
+
+fn resolve_dispute(
+    bank: &mut Bank,
+    arbiter: &Pubkey,
+    paper: &Paper,
+    uphold: bool,
+) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new_readonly(*arbiter, true),
+            AccountMeta::new(dispute_pda(paper).0, false),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(paper.peer_review, false),
+            AccountMeta::new(paper.author, false),
+            AccountMeta::new(paper.reviewer, false),
+            AccountMeta::new(paper.review_stake, false),
+            AccountMeta::new(TREASURY_PUBKEY, false),
+            AccountMeta::new(paper.reviewer_pool, false),
+        ],
+        DeResearcherInstruction::ResolveDispute(ResolveDispute { uphold }),
+    )
+}
+
+struct Mint {
+    buyer: Pubkey,
+    research_token: Pubkey,
+    mint_escrow: Pubkey,
+}
+
+fn mint(bank: &mut Bank, paper: &Paper) -> Mint {
+    let buyer = Pubkey::new_unique();
+
+    bank.fund(&buyer);
+
+    create_profile(bank, &buyer);
+
+    let (research_token, research_token_bump) =
+        pda(&[b"deres_token_account", paper.paper.as_ref(), buyer.as_ref()]);
+
+    let (mint_escrow, mint_escrow_bump) = pda(&[b"deres_mint_escrow", research_token.as_ref()]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(buyer, true),
+            AccountMeta::new(researcher_profile_pda(&buyer).0, false),
+            AccountMeta::new(research_token, false),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(paper.author, false),
+            AccountMeta::new(paper.reviewer_pool, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(mint_escrow, false),
+        ],
+        DeResearcherInstruction::MintResearchPaper(MintResearchPaper {
+            pda_bump: research_token_bump,
+            max_price: ACCESS_FEE as u64,
+            recipient: None,
+            escrow_pda_bump: mint_escrow_bump,
+        }),
+    )
+    .unwrap();
+
+    Mint {
+        buyer,
+        research_token,
+        mint_escrow,
+    }
+}
+
+// A member of a fresh institution claims the paper under a paper license
+
+fn claim_licensed_paper(bank: &mut Bank, paper: &Paper, expires_at: i64) -> Pubkey {
+    let admin = Pubkey::new_unique();
+    let member = Pubkey::new_unique();
+
+    bank.fund(&admin);
+    bank.fund(&member);
+
+    let (institution, institution_bump) = pda(&[
+        b"deres_institution",
+        admin.as_ref(),
+        &name_seed("institution"),
+    ]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(institution, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::CreateInstitution(CreateInstitution {
+            name: "institution".to_string(),
+            pda_bump: institution_bump,
+        }),
+    )
+    .unwrap();
+
+    let (institution_member, institution_member_bump) = pda(&[
+        b"deres_institution_member",
+        institution.as_ref(),
+        member.as_ref(),
+    ]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(institution, false),
+            AccountMeta::new_readonly(member, false),
+            AccountMeta::new(institution_member, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::AddInstitutionMember(AddInstitutionMember {
+            pda_bump: institution_member_bump,
+        }),
+    )
+    .unwrap();
+
+    let (institution_license, institution_license_bump) = pda(&[
+        b"deres_institution_license",
+        institution.as_ref(),
+        paper.paper.as_ref(),
+    ]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(institution, false),
+            AccountMeta::new(paper.author, true),
+            AccountMeta::new_readonly(paper.paper, false),
+            AccountMeta::new(institution_license, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::CreateInstitutionLicense(CreateInstitutionLicense {
+            scope: LicenseScope::Paper,
+            fee: ACCESS_FEE as u64,
+            expires_at,
+            pda_bump: institution_license_bump,
+        }),
+    )
+    .unwrap();
+
+    let (research_token, research_token_bump) = pda(&[
+        b"deres_token_account",
+        paper.paper.as_ref(),
+        member.as_ref(),
+    ]);
+
+    bank.process(
+        vec![
+            AccountMeta::new(member, true),
+            AccountMeta::new(research_token, false),
+            AccountMeta::new_readonly(paper.paper, false),
+            AccountMeta::new_readonly(institution_member, false),
+            AccountMeta::new(institution_license, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::ClaimLicensedPaper(ClaimLicensedPaper {
+            pda_bump: research_token_bump,
+        }),
+    )
+    .unwrap();
+
+    research_token
+}
+
+fn subscription_plan_pda(target: &Pubkey) -> (Pubkey, u8) {
+    pda(&[b"deres_subscription_plan", target.as_ref()])
+}
+
+fn create_author_plan(bank: &mut Bank, paper: &Paper, price_per_period: u64, period_secs: i64) {
+    let (subscription_plan, subscription_plan_bump) = subscription_plan_pda(&paper.author);
+
+    bank.process(
+        vec![
+            AccountMeta::new(paper.author, true),
+            AccountMeta::new_readonly(paper.author, false),
+            AccountMeta::new(subscription_plan, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::CreateSubscriptionPlan(CreateSubscriptionPlan {
+            scope: SubscriptionScope::Author,
+            price_per_period,
+            period_secs,
+            pda_bump: subscription_plan_bump,
+        }),
+    )
+    .unwrap();
+}
+
+fn subscription_pda(subscriber: &Pubkey, target: &Pubkey) -> (Pubkey, u8) {
+    pda(&[b"deres_subscription", subscriber.as_ref(), target.as_ref()])
+}
+
+fn subscribe(bank: &mut Bank, subscriber: &Pubkey, paper: &Paper, periods: u32) {
+    let (subscription, subscription_bump) = subscription_pda(subscriber, &paper.author);
+
+    bank.process(
+        vec![
+            AccountMeta::new(*subscriber, true),
+            AccountMeta::new_readonly(subscription_plan_pda(&paper.author).0, false),
+            AccountMeta::new(paper.author, false),
+            AccountMeta::new(subscription, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::Subscribe(Subscribe {
+            periods,
+            pda_bump: subscription_bump,
+        }),
+    )
+    .unwrap();
+}
+
+fn verify_subscription(bank: &mut Bank, reader: &Pubkey, paper: &Paper) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new_readonly(*reader, false),
+            AccountMeta::new_readonly(paper.paper, false),
+            AccountMeta::new_readonly(subscription_pda(reader, &paper.author).0, false),
+        ],
+        DeResearcherInstruction::VerifyAccess(VerifyAccess {
+            proof: AccessProof::Subscription,
+        }),
+    )
+}
+
+fn request_refund(bank: &mut Bank, mint: &Mint, paper: &Paper) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new(mint.buyer, true),
+            AccountMeta::new(mint.research_token, false),
+            AccountMeta::new(paper.paper, false),
+            AccountMeta::new(mint.mint_escrow, false),
+            AccountMeta::new(researcher_profile_pda(&mint.buyer).0, false),
+        ],
+        DeResearcherInstruction::RequestRefund,
+    )
+}
+
+fn release_escrow(bank: &mut Bank, mint: &Mint, paper: &Paper) -> ProgramResult {
+    bank.process(
+        vec![
+            AccountMeta::new(paper.author, true),
+            AccountMeta::new_readonly(paper.paper, false),
+            AccountMeta::new(paper.reviewer_pool, false),
+            AccountMeta::new(mint.buyer, false),
+            AccountMeta::new(mint.mint_escrow, false),
+        ],
+        DeResearcherInstruction::ReleaseEscrow,
+    )
+}
+
+#[test]
+fn reviews_are_scored_against_the_journal_rubric() {
+    let mut bank = Bank::new();
+
+    let journal = new_journal(&mut bank);
+
+    let first = new_paper(&mut bank, 0, false);
+    let second = with_reviewer(&mut bank, &first);
+
+    submit_to_journal(&mut bank, &journal, &first);
+
+    invite_reviewer(&mut bank, &journal, &first);
+    invite_reviewer(&mut bank, &journal, &second);
+
+    let research_paper: ResearchPaper = bank.state(&first.paper);
+
+    assert_eq!(research_paper.rubric_pubkey, journal.rubric);
+    assert_eq!(research_paper.rubric_dimension_count, 3);
+
+    assert_eq!(
+        try_add_review(&mut bank, &first, &[90, 90, 90, 90]),
+        Err(DeResearcherError::InvalidRubricScores.into())
+    );
+    assert_eq!(
+        try_add_review(&mut bank, &first, &[90, 90, 101]),
+        Err(DeResearcherError::InvalidRubricScores.into())
+    );
+
+    // Unweighted the reviews average 70 and 60, novelty counting double makes them 60 and 70
+
+    add_review(&mut bank, &first, &[30, 90, 90]);
+
+    let peer_review: PeerReview = bank.state(&first.peer_review);
+
+    assert!(!peer_review.is_approval);
+
+    add_review(&mut bank, &second, &[100, 40, 40]);
+
+    let peer_review: PeerReview = bank.state(&second.peer_review);
+
+    assert!(peer_review.is_approval);
+
+    let research_paper: ResearchPaper = bank.state(&first.paper);
+
+    assert_eq!(research_paper.total_reviews, 2);
+    assert_eq!(research_paper.total_approvals, 1);
+    assert_eq!(research_paper.state, PaperState::ApprovedToPublish);
+}
+
+#[test]
+fn one_sided_conflict_only_recuses_the_declarer() {
+    let mut bank = Bank::new();
+
+    let paper = new_paper(&mut bank, 0, false);
+
+    // The author can't keep the reviewer away on their own
+
+    declare_conflict(&mut bank, &paper.author, &paper.reviewer, false).unwrap();
+
+    let conflict_of_interest = conflict_of_interest_pda(&paper.author, &paper.reviewer);
+
+    assert!(
+        !bank
+            .state::<ConflictOfInterest>(&conflict_of_interest)
+            .is_mutual
+    );
+
+    assert_eq!(
+        declare_conflict(&mut bank, &paper.author, &paper.reviewer, false),
+        Err(DeResearcherError::ConflictOfInterestAlreadyExists.into())
+    );
+
+    add_review(&mut bank, &paper, &[20, 20, 20, 20]);
+
+    // The reviewer recusing themselves makes the conflict mutual
+
+    let second = with_reviewer(&mut bank, &paper);
+
+    declare_conflict(&mut bank, &second.reviewer, &paper.author, false).unwrap();
+
+    assert_eq!(
+        try_add_review(&mut bank, &second, &[90, 90, 90, 90]),
+        Err(DeResearcherError::ConflictOfInterest.into())
+    );
+
+    declare_conflict(&mut bank, &paper.author, &second.reviewer, false).unwrap();
+
+    let conflict_of_interest = conflict_of_interest_pda(&paper.author, &second.reviewer);
+
+    assert!(
+        bank.state::<ConflictOfInterest>(&conflict_of_interest)
+            .is_mutual
+    );
+
+    assert_eq!(
+        declare_conflict(&mut bank, &second.reviewer, &paper.author, true),
+        Err(DeResearcherError::ConflictOfInterestAlreadyExists.into())
+    );
+}
+
+#[test]
+fn amended_review_moves_the_approval_tally_once() {
+    let mut bank = Bank::new();
+
+    let paper = reviewed_paper(&mut bank, 0);
+
+    assert_eq!(
+        amend_review(&mut bank, &paper, &[20, 20, 20, 20]),
+        Err(DeResearcherError::AuthorResponseRequired.into())
+    );
+
+    // Only the paper's author can respond to its reviews
+
+    let stranger = Pubkey::new_unique();
+
+    bank.fund(&stranger);
+
+    assert_eq!(
+        respond_to_review(&mut bank, &stranger, &paper),
+        Err(DeResearcherError::PdaPubekyMismatch.into())
+    );
+
+    respond_to_review(&mut bank, &paper.author, &paper).unwrap();
+
+    amend_review(&mut bank, &paper, &[20, 20, 20, 20]).unwrap();
+
+    let research_paper: ResearchPaper = bank.state(&paper.paper);
+
+    assert_eq!(research_paper.total_reviews, 1);
+    assert_eq!(research_paper.total_approvals, 0);
+    assert_eq!(research_paper.score_sums[..4], [20, 20, 20, 20]);
+    assert_eq!(research_paper.state, PaperState::InPeerReview);
+
+    let peer_review: PeerReview = bank.state(&paper.peer_review);
+
+    assert!(peer_review.is_amended);
+    assert!(!peer_review.is_approval);
+
+    assert_eq!(
+        amend_review(&mut bank, &paper, &[90, 90, 90, 90]),
+        Err(DeResearcherError::PeerReviewAlreadyAmended.into())
+    );
+}
+
+#[test]
+fn review_stake_is_held_until_the_paper_is_final() {
+    let mut bank = Bank::new();
+
+    let paper = reviewed_paper(&mut bank, 0);
+
+    let review_stake: ReviewStake = bank.state(&paper.review_stake);
+
+    assert_eq!(review_stake.amount, REVIEW_STAKE_LAMPORTS);
+    assert_eq!(
+        bank.lamports(&paper.review_stake),
+        rent(ReviewStake::size()) + REVIEW_STAKE_LAMPORTS
+    );
+
+    assert_eq!(
+        release_review_stake(&mut bank, &paper),
+        Err(DeResearcherError::PaperNotFinalized.into())
+    );
+
+    publish(&mut bank, &paper);
+
+    // Governance gets until FinalizeReviews to slash an outlier
+
+    assert_eq!(
+        release_review_stake(&mut bank, &paper),
+        Err(DeResearcherError::ReviewsNotFinalized.into())
+    );
+
+    finalize_reviews(&mut bank, &[&paper]);
+
+    let reviewer_lamports = bank.lamports(&paper.reviewer);
+
+    release_review_stake(&mut bank, &paper).unwrap();
+
+    assert_eq!(
+        bank.lamports(&paper.reviewer),
+        reviewer_lamports + rent(ReviewStake::size()) + REVIEW_STAKE_LAMPORTS
+    );
+    assert!(bank.account(&paper.review_stake).data.is_empty());
+    assert_eq!(bank.account(&paper.review_stake).owner, system_program::ID);
+}
+
+#[test]
+fn slashed_stake_moves_to_the_treasury_once() {
+    let mut bank = Bank::new();
+
+    let paper = reviewed_paper(&mut bank, 0);
+
+    let slash =
+        |bank: &mut Bank| slash_review_stake(bank, &GOVERNANCE_AUTHORITY_PUBKEY, &paper, 2_500);
+
+    slash(&mut bank).unwrap();
+
+    assert_eq!(bank.lamports(&TREASURY_PUBKEY), REVIEW_STAKE_LAMPORTS / 4);
+
+    let review_stake: ReviewStake = bank.state(&paper.review_stake);
+
+    assert_eq!(review_stake.amount, REVIEW_STAKE_LAMPORTS * 3 / 4);
+    assert_eq!(review_stake.slashed_amount, REVIEW_STAKE_LAMPORTS / 4);
+
+    assert_eq!(
+        slash(&mut bank),
+        Err(DeResearcherError::ReviewStakeAlreadySlashed.into())
+    );
+
+    publish(&mut bank, &paper);
+
+    finalize_reviews(&mut bank, &[&paper]);
+
+    let reviewer_lamports = bank.lamports(&paper.reviewer);
+
+    release_review_stake(&mut bank, &paper).unwrap();
+
+    assert_eq!(
+        bank.lamports(&paper.reviewer),
+        reviewer_lamports + rent(ReviewStake::size()) + REVIEW_STAKE_LAMPORTS * 3 / 4
+    );
+}
+
+#[test]
+fn stakes_of_a_paper_awaiting_revision_unlock_after_the_lock_period() {
+    let mut bank = Bank::new();
+
+    let paper = new_paper(&mut bank, 0, false);
+
+    add_review(&mut bank, &paper, &[20, 20, 20, 20]);
+
+    set_now(review_deadline(&bank, &paper));
+
+    crank_paper_deadline(&mut bank, &paper);
+
+    assert_eq!(
+        bank.state::<ResearchPaper>(&paper.paper).state,
+        PaperState::RequiresRevision
+    );
+    assert_eq!(
+        release_review_stake(&mut bank, &paper),
+        Err(DeResearcherError::PaperNotFinalized.into())
+    );
+
+    set_now(review_deadline(&bank, &paper) + REVISION_STAKE_LOCK_SECS);
+
+    release_review_stake(&mut bank, &paper).unwrap();
+
+    assert!(bank.account(&paper.review_stake).data.is_empty());
+}
+
+#[test]
+fn stakes_of_a_superseded_version_are_released_right_away() {
+    let mut bank = Bank::new();
+
+    let paper = new_paper(&mut bank, 0, false);
+
+    add_review(&mut bank, &paper, &[20, 20, 20, 20]);
+
+    set_now(review_deadline(&bank, &paper));
+
+    crank_paper_deadline(&mut bank, &paper);
+
+    submit_revision(&mut bank, &paper).unwrap();
+
+    let reviewer_lamports = bank.lamports(&paper.reviewer);
+
+    release_review_stake(&mut bank, &paper).unwrap();
+
+    assert_eq!(
+        bank.lamports(&paper.reviewer),
+        reviewer_lamports + rent(ReviewStake::size()) + REVIEW_STAKE_LAMPORTS
+    );
+}
+
+#[test]
+fn arbiters_can_slash_a_review_stake() {
+    let mut bank = Bank::new();
+
+    let paper = reviewed_paper(&mut bank, 0);
+
+    let arbiter = set_arbiter(&mut bank);
+
+    assert_eq!(
+        slash_review_stake(&mut bank, &Pubkey::new_unique(), &paper, 2_500),
+        Err(DeResearcherError::InvalidArbiter.into())
+    );
+
+    let treasury_lamports = bank.lamports(&TREASURY_PUBKEY);
+
+    slash_review_stake(&mut bank, &arbiter, &paper, 2_500).unwrap();
+
+    assert_eq!(
+        bank.lamports(&TREASURY_PUBKEY),
+        treasury_lamports + REVIEW_STAKE_LAMPORTS / 4
+    );
+}
+
+#[test]
+fn upheld_dispute_forfeits_the_review_stake() {
+    let mut bank = Bank::new();
+
+    let paper = reviewed_paper(&mut bank, 0);
+
+    let arbiter = set_arbiter(&mut bank);
+
+    open_dispute(&mut bank, &paper).unwrap();
+
+    assert_eq!(
+        release_review_stake(&mut bank, &paper),
+        Err(DeResearcherError::DisputeOpen.into())
+    );
+
+    let author_lamports = bank.lamports(&paper.author);
+    let reviewer_lamports = bank.lamports(&paper.reviewer);
+    let treasury_lamports = bank.lamports(&TREASURY_PUBKEY);
+
+    resolve_dispute(&mut bank, &arbiter, &paper, true).unwrap();
+
+    assert_eq!(
+        bank.lamports(&TREASURY_PUBKEY),
+        treasury_lamports + REVIEW_STAKE_LAMPORTS
+    );
+    assert_eq!(
+        bank.lamports(&paper.reviewer),
+        reviewer_lamports + rent(ReviewStake::size())
+    );
+    assert_eq!(
+        bank.lamports(&paper.author),
+        author_lamports + DISPUTE_BOND_LAMPORTS
+    );
+    assert!(bank.account(&paper.review_stake).data.is_empty());
+
+    // The only approval was invalidated, so the paper is back in review
+
+    let research_paper: ResearchPaper = bank.state(&paper.paper);
+
+    assert_eq!(research_paper.total_approvals, 0);
+    assert_eq!(research_paper.state, PaperState::InPeerReview);
+}
+
+#[test]
+fn rejected_dispute_pays_the_bond_to_the_reviewer() {
+    let mut bank = Bank::new();
+
+    let paper = reviewed_paper(&mut bank, 0);
+
+    let arbiter = set_arbiter(&mut bank);
+
+    open_dispute(&mut bank, &paper).unwrap();
+
+    let reviewer_lamports = bank.lamports(&paper.reviewer);
+
+    resolve_dispute(&mut bank, &arbiter, &paper, false).unwrap();
+
+    assert_eq!(
+        bank.lamports(&paper.reviewer),
+        reviewer_lamports + DISPUTE_BOND_LAMPORTS
+    );
+    assert_eq!(
+        resolve_dispute(&mut bank, &arbiter, &paper, true),
+        Err(DeResearcherError::DisputeAlreadyResolved.into())
+    );
+
+    let review_stake: ReviewStake = bank.state(&paper.review_stake);
+
+    assert_eq!(review_stake.amount, REVIEW_STAKE_LAMPORTS);
+
+    publish(&mut bank, &paper);
+
+    finalize_reviews(&mut bank, &[&paper]);
+
+    release_review_stake(&mut bank, &paper).unwrap();
+}
+
+#[test]
+fn unresolved_dispute_refunds_the_bond_once_it_expires() {
+    let mut bank = Bank::new();
+
+    let paper = reviewed_paper(&mut bank, 0);
+
+    let arbiter = set_arbiter(&mut bank);
+
+    open_dispute(&mut bank, &paper).unwrap();
+
+    assert_eq!(
+        open_dispute(&mut bank, &paper),
+        Err(DeResearcherError::DisputeAlreadyExists.into())
+    );
+
+    let expire_dispute = |bank: &mut Bank| {
+        bank.process(
+            vec![
+                AccountMeta::new(dispute_pda(&paper).0, false),
+                AccountMeta::new(paper.author, false),
+            ],
+            DeResearcherInstruction::ExpireDispute,
+        )
+    };
+
+    assert_eq!(
+        expire_dispute(&mut bank),
+        Err(DeResearcherError::DisputeWindowOpen.into())
+    );
+
+    set_now(NOW.with(Cell::get) + DISPUTE_RESOLUTION_WINDOW_SECS);
+
+    assert_eq!(
+        resolve_dispute(&mut bank, &arbiter, &paper, true),
+        Err(DeResearcherError::DisputeExpired.into())
+    );
+
+    let author_lamports = bank.lamports(&paper.author);
+
+    expire_dispute(&mut bank).unwrap();
+
+    assert_eq!(
+        bank.lamports(&paper.author),
+        author_lamports + DISPUTE_BOND_LAMPORTS
+    );
+
+    // The review stands, so the stake is released as usual
+
+    publish(&mut bank, &paper);
+
+    finalize_reviews(&mut bank, &[&paper]);
+
+    release_review_stake(&mut bank, &paper).unwrap();
+}
+
+#[test]
+fn revision_records_the_superseded_version_and_opens_a_new_round() {
+    let mut bank = Bank::new();
+
+    let paper = new_paper(&mut bank, 0, false);
+
+    add_review(&mut bank, &paper, &[20, 20, 20, 20]);
+
+    assert_eq!(
+        submit_revision(&mut bank, &paper),
+        Err(DeResearcherError::InvalidState.into())
+    );
+
+    let revised_at = review_deadline(&bank, &paper);
+
+    set_now(revised_at);
+
+    crank_paper_deadline(&mut bank, &paper);
+
+    submit_revision(&mut bank, &paper).unwrap();
+
+    let research_paper: ResearchPaper = bank.state(&paper.paper);
+
+    assert_eq!(research_paper.version, 1);
+    assert_eq!(research_paper.state, PaperState::AwaitingPeerReview);
+    assert_eq!(research_paper.submitted_at, revised_at);
+    assert_eq!(research_paper.total_reviews, 0);
+    assert_eq!(
+        research_paper.latest_content_hash,
+        REVISED_CONTENT_HASH.as_bytes()
+    );
+
+    let superseded: PaperVersion = bank.state(&paper_version_pda(&paper, 0).0);
+
+    assert_eq!(superseded.content_hash, CONTENT_HASH.as_bytes());
+    assert_eq!(superseded.total_reviews, 1);
+    assert_eq!(superseded.total_approvals, 0);
+    assert_eq!(superseded.superseded_at, revised_at);
+
+    let revised: PaperVersion = bank.state(&paper_version_pda(&paper, 1).0);
+
+    assert_eq!(revised.version, 1);
+    assert_eq!(revised.content_hash, REVISED_CONTENT_HASH.as_bytes());
+    assert_eq!(revised.submitted_at, revised_at);
+
+    // The old review stays bound to version 0, the reviewer reviews the revision afresh
+
+    let rereview = for_version(&paper, 1);
+
+    add_review(&mut bank, &rereview, &[90, 90, 90, 90]);
+
+    assert_eq!(
+        bank.state::<PeerReview>(&paper.peer_review).paper_version,
+        0
+    );
+    assert_eq!(
+        bank.state::<PeerReview>(&rereview.peer_review)
+            .paper_version,
+        1
+    );
+    assert_eq!(
+        bank.state::<ResearchPaper>(&paper.paper).state,
+        PaperState::ApprovedToPublish
+    );
+}
+
+#[test]
+fn final_state_records_the_approvals_of_the_last_version() {
+    let mut bank = Bank::new();
+
+    let published = reviewed_paper(&mut bank, 0);
+
+    publish(&mut bank, &published);
+
+    let paper_version: PaperVersion = bank.state(&paper_version_pda(&published, 0).0);
+
+    assert_eq!(paper_version.total_approvals, 1);
+    assert_eq!(paper_version.total_reviews, 1);
+    assert_eq!(paper_version.superseded_at, 0);
+
+    // Content hashes are unique, so the withdrawn paper lives in a bank of its own
+
+    let mut bank = Bank::new();
+
+    let withdrawn = new_paper(&mut bank, 0, false);
+
+    add_review(&mut bank, &withdrawn, &[20, 20, 20, 20]);
+
+    withdraw(&mut bank, &withdrawn).unwrap();
+
+    let paper_version: PaperVersion = bank.state(&paper_version_pda(&withdrawn, 0).0);
+
+    assert_eq!(paper_version.total_approvals, 0);
+    assert_eq!(paper_version.total_reviews, 1);
+}
+
+#[test]
+fn refund_returns_the_escrowed_payment_within_the_window() {
+    let mut bank = Bank::new();
+
+    set_now(1_000_000);
+
+    let paper = reviewed_paper(&mut bank, REFUND_WINDOW_SECS);
+
+    publish(&mut bank, &paper);
+
+    let author_lamports = bank.lamports(&paper.author);
+
+    let mint = mint(&mut bank, &paper);
+
+    let mint_escrow: MintEscrow = bank.state(&mint.mint_escrow);
+
+    assert_eq!(mint_escrow.amount, ACCESS_FEE as u64);
+    assert_eq!(mint_escrow.refund_deadline, 1_000_000 + REFUND_WINDOW_SECS);
+    assert_eq!(bank.lamports(&paper.author), author_lamports);
+
+    let paper_state: ResearchPaper = bank.state(&paper.paper);
+
+    assert_eq!(paper_state.total_mints, 1);
+
+    let buyer_lamports = bank.lamports(&mint.buyer);
+
+    let token_lamports = bank.lamports(&mint.research_token);
+
+    set_now(1_000_000 + REFUND_WINDOW_SECS - 1);
+
+    request_refund(&mut bank, &mint, &paper).unwrap();
+
+    assert_eq!(
+        bank.lamports(&mint.buyer),
+        buyer_lamports + rent(MintEscrow::size()) + ACCESS_FEE as u64 + token_lamports
+    );
+    assert!(bank.account(&mint.mint_escrow).data.is_empty());
+    assert!(bank.account(&mint.research_token).data.is_empty());
+
+    let paper_state: ResearchPaper = bank.state(&paper.paper);
+
+    assert_eq!(paper_state.total_mints, 0);
+}
+
+#[test]
+fn escrow_is_released_to_the_author_and_reviewers_after_the_window() {
+    let mut bank = Bank::new();
+
+    set_now(1_000_000);
+
+    let paper = reviewed_paper(&mut bank, REFUND_WINDOW_SECS);
+
+    publish(&mut bank, &paper);
+
+    let mint = mint(&mut bank, &paper);
+
+    assert_eq!(
+        release_escrow(&mut bank, &mint, &paper),
+        Err(DeResearcherError::RefundWindowOpen.into())
+    );
+
+    set_now(1_000_000 + REFUND_WINDOW_SECS);
+
+    assert_eq!(
+        request_refund(&mut bank, &mint, &paper),
+        Err(DeResearcherError::RefundWindowClosed.into())
+    );
+
+    let author_lamports = bank.lamports(&paper.author);
+
+    let buyer_lamports = bank.lamports(&mint.buyer);
+
+    let pool_lamports = bank.lamports(&paper.reviewer_pool);
+
+    release_escrow(&mut bank, &mint, &paper).unwrap();
+
+    let reviewer_share = ACCESS_FEE as u64 * REVIEWER_SHARE_BPS as u64 / 10_000;
+
+    assert_eq!(
+        bank.lamports(&paper.author),
+        author_lamports + ACCESS_FEE as u64 - reviewer_share
+    );
+    assert_eq!(
+        bank.lamports(&paper.reviewer_pool),
+        pool_lamports + reviewer_share
+    );
+    assert_eq!(
+        bank.lamports(&mint.buyer),
+        buyer_lamports + rent(MintEscrow::size())
+    );
+    assert!(bank.account(&mint.mint_escrow).data.is_empty());
+
+    let reviewer_pool: ReviewerPool = bank.state(&paper.reviewer_pool);
+
+    assert_eq!(reviewer_pool.total_deposited, reviewer_share);
+
+    assert!(!bank.account(&mint.research_token).data.is_empty());
+}
+
+fn review_deadline(bank: &Bank, paper: &Paper) -> i64 {
+    bank.state::<ResearchPaper>(&paper.paper).review_deadline()
+}
+
+fn reveal_deadline(bank: &Bank, paper: &Paper) -> i64 {
+    bank.state::<ResearchPaper>(&paper.paper).reveal_deadline()
+}
+
+#[test]
+fn committed_review_is_revealed_once_the_commit_window_closes() {
+    let mut bank = Bank::new();
+
+    let paper = new_paper(&mut bank, 0, true);
+
+    let salt = [7; 32];
+
+    commit_review(&mut bank, &paper, &[90, 90, 90, 90], &salt);
+
+    assert_eq!(
+        bank.state::<ResearchPaper>(&paper.paper)
+            .pending_commitments,
+        1
+    );
+    assert_eq!(
+        reveal_review(&mut bank, &paper, &[90, 90, 90, 90], &salt),
+        Err(DeResearcherError::CommitWindowOpen.into())
+    );
+
+    set_now(review_deadline(&bank, &paper));
+
+    assert_eq!(
+        reveal_review(&mut bank, &paper, &[90, 90, 90, 80], &salt),
+        Err(DeResearcherError::CommitmentMismatch.into())
+    );
+
+    reveal_review(&mut bank, &paper, &[90, 90, 90, 90], &salt).unwrap();
+
+    let research_paper: ResearchPaper = bank.state(&paper.paper);
+
+    assert_eq!(research_paper.pending_commitments, 0);
+    assert_eq!(research_paper.total_approvals, 1);
+    assert_eq!(research_paper.state, PaperState::ApprovedToPublish);
+
+    set_now(reveal_deadline(&bank, &paper));
+
+    assert_eq!(
+        expire_review_commit(&mut bank, &paper),
+        Err(DeResearcherError::InvalidState.into())
+    );
+}
+
+#[test]
+fn reveals_are_accepted_after_the_paper_is_approved() {
+    let mut bank = Bank::new();
+
+    let first = new_paper(&mut bank, 0, true);
+    let second = with_reviewer(&mut bank, &first);
+
+    let salt = [7; 32];
+
+    commit_review(&mut bank, &first, &[90, 90, 90, 90], &salt);
+    commit_review(&mut bank, &second, &[20, 20, 20, 20], &salt);
+
+    set_now(review_deadline(&bank, &first));
+
+    reveal_review(&mut bank, &first, &[90, 90, 90, 90], &salt).unwrap();
+
+    assert_eq!(
+        bank.state::<ResearchPaper>(&first.paper).state,
+        PaperState::ApprovedToPublish
+    );
+
+    // The author can neither publish nor withdraw while the second reviewer hasn't revealed
+
+    assert_eq!(
+        try_publish(&mut bank, &first),
+        Err(DeResearcherError::CommitmentsPending.into())
+    );
+    assert_eq!(
+        withdraw(&mut bank, &first),
+        Err(DeResearcherError::CommitmentsPending.into())
+    );
+
+    reveal_review(&mut bank, &second, &[20, 20, 20, 20], &salt).unwrap();
+
+    let research_paper: ResearchPaper = bank.state(&first.paper);
+
+    assert_eq!(research_paper.total_reviews, 2);
+    assert_eq!(research_paper.total_approvals, 1);
+
+    publish(&mut bank, &first);
+}
+
+#[test]
+fn unrevealed_commitment_forfeits_its_stake_after_the_reveal_window() {
+    let mut bank = Bank::new();
+
+    let paper = new_paper(&mut bank, 0, true);
+
+    commit_review(&mut bank, &paper, &[90, 90, 90, 90], &[7; 32]);
+
+    set_now(review_deadline(&bank, &paper));
+
+    assert_eq!(
+        expire_review_commit(&mut bank, &paper),
+        Err(DeResearcherError::RevealWindowOpen.into())
+    );
+
+    set_now(reveal_deadline(&bank, &paper));
+
+    let reviewer_lamports = bank.lamports(&paper.reviewer);
+    let treasury_lamports = bank.lamports(&TREASURY_PUBKEY);
+
+    expire_review_commit(&mut bank, &paper).unwrap();
+
+    assert_eq!(
+        bank.lamports(&TREASURY_PUBKEY),
+        treasury_lamports + REVIEW_STAKE_LAMPORTS
+    );
+    assert_eq!(
+        bank.lamports(&paper.reviewer),
+        reviewer_lamports + rent(ReviewStake::size()) + rent(PeerReview::size())
+    );
+    assert_eq!(
+        bank.state::<ResearchPaper>(&paper.paper)
+            .pending_commitments,
+        0
+    );
+    assert!(bank.account(&paper.peer_review).data.is_empty());
+}
+
+#[test]
+fn commitment_is_refunded_when_the_paper_leaves_review_before_the_reveal() {
+    let mut bank = Bank::new();
+
+    let journal = new_journal(&mut bank);
+
+    let paper = new_paper(&mut bank, 0, true);
+
+    submit_to_journal(&mut bank, &journal, &paper);
+
+    invite_reviewer(&mut bank, &journal, &paper);
+
+    commit_review(&mut bank, &paper, &[90, 90, 90], &[7; 32]);
+
+    desk_reject(&mut bank, &journal, &paper).unwrap();
+
+    let reviewer_lamports = bank.lamports(&paper.reviewer);
+    let treasury_lamports = bank.lamports(&TREASURY_PUBKEY);
+
+    // The reviewer never had the chance to reveal, so the stake doesn't wait for the deadline
+
+    expire_review_commit(&mut bank, &paper).unwrap();
+
+    assert_eq!(bank.lamports(&TREASURY_PUBKEY), treasury_lamports);
+    assert_eq!(
+        bank.lamports(&paper.reviewer),
+        reviewer_lamports
+            + rent(ReviewStake::size())
+            + REVIEW_STAKE_LAMPORTS
+            + rent(PeerReview::size())
+    );
+}
+
+#[test]
+fn licensed_claims_are_not_counted_as_mints() {
+    let mut bank = Bank::new();
+
+    let paper = reviewed_paper(&mut bank, 0);
+
+    publish(&mut bank, &paper);
+
+    let expires_at = NOW.with(Cell::get) + 3_600;
+
+    let research_token = claim_licensed_paper(&mut bank, &paper, expires_at);
+
+    let research_token: ResearchTokenAccount = bank.state(&research_token);
+
+    assert_eq!(research_token.kind, AccessKind::Licensed);
+    assert_eq!(research_token.expires_at, expires_at);
+
+    // The claim takes no edition, leaves the price where it was and isn't a citation
+
+    let research_paper: ResearchPaper = bank.state(&paper.paper);
+
+    assert_eq!(research_paper.total_mints, 0);
+    assert_eq!(research_paper.total_citations, 1);
+
+    mint(&mut bank, &paper);
+
+    assert_eq!(bank.state::<ResearchPaper>(&paper.paper).total_mints, 1);
+}
+
+#[test]
+fn author_subscription_grants_access_until_it_lapses() {
+    let mut bank = Bank::new();
+
+    let paper = reviewed_paper(&mut bank, 0);
+
+    let period_secs = 3_600;
+
+    create_author_plan(&mut bank, &paper, 1_000, period_secs);
+
+    let subscriber = Pubkey::new_unique();
+
+    bank.fund(&subscriber);
+
+    let author_lamports = bank.lamports(&paper.author);
+
+    subscribe(&mut bank, &subscriber, &paper, 1);
+
+    assert_eq!(bank.lamports(&paper.author), author_lamports + 1_000);
+
+    // The subscription only covers what the author has published
+
+    assert_eq!(
+        verify_subscription(&mut bank, &subscriber, &paper),
+        Err(DeResearcherError::AccessDenied.into())
+    );
+
+    publish(&mut bank, &paper);
+
+    verify_subscription(&mut bank, &subscriber, &paper).unwrap();
+
+    let stranger = Pubkey::new_unique();
+
+    assert_eq!(
+        verify_subscription(&mut bank, &stranger, &paper),
+        Err(DeResearcherError::AccessDenied.into())
+    );
+
+    let (subscription, _) = subscription_pda(&subscriber, &paper.author);
+
+    let expires_at = bank.state::<Subscription>(&subscription).expires_at;
+
+    assert_eq!(expires_at, NOW.with(Cell::get) + period_secs);
+
+    set_now(expires_at + 100);
+
+    assert_eq!(
+        verify_subscription(&mut bank, &subscriber, &paper),
+        Err(DeResearcherError::SubscriptionExpired.into())
+    );
+
+    // A lapsed subscription restarts from the renewal instead of back-filling the gap
+
+    subscribe(&mut bank, &subscriber, &paper, 2);
+
+    assert_eq!(
+        bank.state::<Subscription>(&subscription).expires_at,
+        expires_at + 100 + 2 * period_secs
+    );
+
+    verify_subscription(&mut bank, &subscriber, &paper).unwrap();
+
+    let subscription_plan: SubscriptionPlan = bank.state(&subscription_plan_pda(&paper.author).0);
+
+    assert_eq!(subscription_plan.total_subscriptions, 2);
+}
+
+#[test]
+fn legacy_paper_and_review_are_migrated() {
+    let mut bank = Bank::new();
+
+    let author = Pubkey::new_unique();
+    let reviewer = Pubkey::new_unique();
+
+    bank.fund(&author);
+    bank.fund(&reviewer);
+
+    create_profile(&mut bank, &reviewer);
+
+    let (paper_pda, paper_bump) = pda(&[
+        b"deres_research_paper",
+        &CONTENT_HASH.as_bytes()[..32],
+        author.as_ref(),
+    ]);
+
+    let content_hash: [u8; 64] = CONTENT_HASH.as_bytes().try_into().unwrap();
+
+    let legacy_paper = borsh::to_vec(&(
+        paper_pda,
+        author,
+        PaperState::ApprovedToPublish,
+        ACCESS_FEE,
+        0u8,
+        content_hash,
+        1u8,
+        1u64,
+        0u64,
+        content_hash,
+        paper_bump,
+    ))
+    .unwrap();
+
+    assert_eq!(legacy_paper.len(), ResearchPaper::legacy_size());
+
+    bank.accounts.insert(
+        paper_pda,
+        Account {
+            lamports: rent(legacy_paper.len()),
+            data: legacy_paper,
+            owner: deresearcher_program::id(),
+        },
+    );
+
+    let (peer_review, peer_review_bump) =
+        pda(&[b"deres_peer_review", paper_pda.as_ref(), reviewer.as_ref()]);
+
+    let legacy_review = borsh::to_vec(&(
+        peer_review,
+        reviewer,
+        paper_pda,
+        [90u8, 90, 90, 90],
+        content_hash,
+        peer_review_bump,
+    ))
+    .unwrap();
+
+    bank.accounts.insert(
+        peer_review,
+        Account {
+            lamports: rent(legacy_review.len()),
+            data: legacy_review,
+            owner: deresearcher_program::id(),
+        },
+    );
+
+    let (reviewer_pool, reviewer_pool_bump) = pda(&[b"deres_reviewer_pool", paper_pda.as_ref()]);
+    let (review_stake, review_stake_bump) = pda(&[b"deres_review_stake", peer_review.as_ref()]);
+
+    let paper = Paper {
+        author,
+        reviewer,
+        paper: paper_pda,
+        paper_bump,
+        reviewer_pool,
+        peer_review,
+        peer_review_bump,
+        review_stake,
+        review_stake_bump,
+    };
+
+    let (paper_version, paper_version_bump) = paper_version_pda(&paper, 0);
+
+    let migrate_review = |bank: &mut Bank| {
+        bank.process(
+            vec![
+                AccountMeta::new(reviewer, true),
+                AccountMeta::new(paper_pda, false),
+                AccountMeta::new(peer_review, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            DeResearcherInstruction::MigratePeerReview,
+        )
+    };
+
+    // Reviews are counted into the paper's new layout, so the paper goes first
+
+    assert!(migrate_review(&mut bank).is_err());
+
+    bank.process(
+        vec![
+            AccountMeta::new(author, true),
+            AccountMeta::new(paper_pda, false),
+            AccountMeta::new(reviewer_pool, false),
+            AccountMeta::new(paper_version, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        DeResearcherInstruction::MigrateResearchPaper(MigrateResearchPaper {
+            reviewer_pool_pda_bump: reviewer_pool_bump,
+            paper_version_pda_bump: paper_version_bump,
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(bank.lamports(&paper_pda), rent(ResearchPaper::size()));
+
+    migrate_review(&mut bank).unwrap();
+
+    assert_eq!(
+        migrate_review(&mut bank),
+        Err(DeResearcherError::AccountAlreadyMigrated.into())
+    );
+
+    let research_paper: ResearchPaper = bank.state(&paper_pda);
+
+    assert_eq!(research_paper.state, PaperState::ApprovedToPublish);
+    assert_eq!(research_paper.total_approvals, 1);
+    assert_eq!(research_paper.total_reviews, 1);
+    assert_eq!(research_paper.scored_reviews, 1);
+
+    let migrated_review: PeerReview = bank.state(&peer_review);
+
+    assert!(migrated_review.is_approval);
+    assert_eq!(migrated_review.dimension_scores(), &[90, 90, 90, 90]);
+
+    // The migrated review keeps its old address and takes part in finalization
+
+    publish(&mut bank, &paper);
+
+    finalize_reviews(&mut bank, &[&paper]);
+
+    let reviewer_pool: ReviewerPool = bank.state(&reviewer_pool);
+
+    assert!(reviewer_pool.is_finalized);
+    assert_eq!(reviewer_pool.total_consensus_weight, 100);
+}